@@ -0,0 +1,383 @@
+//! Handle the `gpui-app://` URL scheme activations.
+//!
+//! The URLs are routed by the host to the registered handlers, e.g.: `gpui-app://story/Buttons`
+//! will call the handler registered for `story` with the path `["Buttons"]`.
+//!
+//! Only one instance of the app will be running, the second instance will forward
+//! its URLs to the running one and exit.
+//!
+//! The running instance listens on an ephemeral local port, and writes the port and a random
+//! token to a lockfile in a per-user directory that only the user can read. The other instances
+//! must send the token in the handshake, the connections without it are dropped.
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher as _, Hasher as _},
+    io::{BufRead as _, BufReader, Write as _},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    path::PathBuf,
+    rc::Rc,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use gpui::{App, AppContext, Global};
+
+pub const SCHEME: &str = "gpui-app";
+
+/// The first line of a connection, followed by the token of the lockfile.
+const HANDSHAKE: &str = "gpui-app/1";
+/// The reply of the running instance to an accepted handshake.
+const HANDSHAKE_OK: &str = "ok";
+/// The line sent to the running instance to activate it without URLs.
+const ACTIVATE: &str = "activate";
+/// The timeout to connect and to read the handshake, the lines of a stale or a foreign peer
+/// will not block the listener.
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A parsed deep link URL, e.g.: `gpui-app://story/Buttons?focus=true`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeepLink {
+    pub host: String,
+    pub path: Vec<String>,
+    pub query: Vec<(String, String)>,
+}
+
+impl DeepLink {
+    pub fn parse(url: &str) -> Option<Self> {
+        let rest = url.strip_prefix(SCHEME)?.strip_prefix("://")?;
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, query),
+            None => (rest, ""),
+        };
+
+        // Decode after splitting, so the encoded `/`, `&` and `=` are kept in the segments.
+        let mut segments = rest.split('/').filter(|s| !s.is_empty());
+        let host = percent_decode(segments.next()?);
+        let path = segments.map(percent_decode).collect();
+        let query = query
+            .split('&')
+            .filter(|s| !s.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) => (percent_decode(key), percent_decode(value)),
+                None => (percent_decode(pair), String::new()),
+            })
+            .collect();
+
+        Some(Self { host, path, query })
+    }
+
+    /// Return the value of the first `key` in the query.
+    pub fn query_value(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Return false if the link asks to keep the app in the background with `focus=false`.
+    pub fn focus(&self) -> bool {
+        self.query_value("focus") != Some("false")
+    }
+}
+
+/// Decode the `%XX` escapes of a URL component, the invalid escapes are kept as is.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut ix = 0;
+    while ix < bytes.len() {
+        let escaped = (bytes[ix] == b'%')
+            .then(|| s.get(ix + 1..ix + 3))
+            .flatten()
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                ix += 3;
+            }
+            None => {
+                decoded.push(bytes[ix]);
+                ix += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+type Handler = Rc<dyn Fn(&DeepLink, &mut AppContext)>;
+
+#[derive(Default)]
+struct DeepLinks {
+    handlers: HashMap<String, Handler>,
+}
+
+impl Global for DeepLinks {}
+
+/// Register a handler for the deep links with the `host`.
+pub fn register(
+    host: impl Into<String>,
+    handler: impl Fn(&DeepLink, &mut AppContext) + 'static,
+    cx: &mut AppContext,
+) {
+    cx.default_global::<DeepLinks>()
+        .handlers
+        .insert(host.into(), Rc::new(handler));
+}
+
+/// Activate the app and route the URLs to the registered handlers,
+/// the app is not activated by the links with `focus=false`.
+pub fn open_urls(urls: Vec<String>, cx: &mut AppContext) {
+    if urls.is_empty() {
+        cx.activate(true);
+    }
+
+    for url in urls {
+        let Some(link) = DeepLink::parse(&url) else {
+            log::warn!("invalid deep link: {}", url);
+            continue;
+        };
+        if link.focus() {
+            cx.activate(true);
+        }
+
+        let handler = cx
+            .try_global::<DeepLinks>()
+            .and_then(|links| links.handlers.get(&link.host).cloned());
+        match handler {
+            Some(handler) => handler(&link, cx),
+            None => log::warn!("no handler for deep link: {}", url),
+        }
+    }
+}
+
+/// Return the deep link URLs in the command line arguments.
+fn urls_from_args() -> Vec<String> {
+    std::env::args()
+        .skip(1)
+        .filter(|arg| DeepLink::parse(arg).is_some())
+        .collect()
+}
+
+/// Return the per-user directory of the lockfile and the app state, it is created only
+/// readable by the user.
+pub fn instance_dir() -> Result<PathBuf> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt as _, PermissionsExt as _};
+
+        let dir = match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) => PathBuf::from(dir).join(SCHEME),
+            None => {
+                let user = std::env::var("USER").unwrap_or_default();
+                std::env::temp_dir().join(format!("{}-{}", SCHEME, user))
+            }
+        };
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&dir)?;
+
+        // The directory may be created by another user in the shared temp dir.
+        let metadata = std::fs::symlink_metadata(&dir)?;
+        if !metadata.is_dir() || metadata.permissions().mode() & 0o077 != 0 {
+            return Err(anyhow!("{} is accessible by other users", dir.display()));
+        }
+        Ok(dir)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let dir = std::env::var_os("LOCALAPPDATA")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir)
+            .join(SCHEME);
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+}
+
+fn lockfile_path() -> Result<PathBuf> {
+    Ok(instance_dir()?.join("instance.lock"))
+}
+
+/// Return a random token for the handshake, the `RandomState` is seeded by the OS.
+fn random_token() -> String {
+    (0..2)
+        .map(|_| {
+            let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+            hasher.write_u32(std::process::id());
+            format!("{:016x}", hasher.finish())
+        })
+        .collect()
+}
+
+/// Write the port and the token of the running instance to the lockfile.
+fn write_lockfile(port: u16, token: &str) -> Result<()> {
+    let path = lockfile_path()?;
+    _ = std::fs::remove_file(&path);
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&path)?;
+    writeln!(file, "{}\n{}", port, token)?;
+    Ok(())
+}
+
+/// Return the port and the token in the lockfile of the running instance.
+fn read_lockfile() -> Option<(u16, String)> {
+    let content = std::fs::read_to_string(lockfile_path().ok()?).ok()?;
+    let mut lines = content.lines();
+    let port = lines.next()?.parse().ok()?;
+    let token = lines.next()?.to_string();
+    Some((port, token))
+}
+
+/// Forward the URLs to the running instance, return true if it is running and accepted them.
+pub fn forward_to_running_instance() -> bool {
+    let Some((port, token)) = read_lockfile() else {
+        return false;
+    };
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, TIMEOUT) else {
+        return false;
+    };
+
+    // The port of a stale lockfile may be used by another program, only exit if it replies.
+    _ = stream.set_read_timeout(Some(TIMEOUT));
+    if writeln!(stream, "{} {}", HANDSHAKE, token).is_err() {
+        return false;
+    }
+    let mut reply = String::new();
+    if BufReader::new(&stream).read_line(&mut reply).is_err() || reply.trim() != HANDSHAKE_OK {
+        return false;
+    }
+
+    let urls = urls_from_args();
+    let lines = if urls.is_empty() {
+        vec![ACTIVATE.to_string()]
+    } else {
+        urls
+    };
+
+    for line in lines {
+        if writeln!(stream, "{}", line).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Read the URLs of a connection, return `None` if the peer does not send the token.
+fn read_urls(mut stream: TcpStream, token: &str) -> Option<Vec<String>> {
+    stream.set_read_timeout(Some(TIMEOUT)).ok()?;
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    if line.trim_end() != format!("{} {}", HANDSHAKE, token) {
+        log::warn!("reject the deep link connection without the token");
+        return None;
+    }
+    writeln!(stream, "{}", HANDSHAKE_OK).ok()?;
+
+    Some(
+        reader
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| line != ACTIVATE)
+            .collect(),
+    )
+}
+
+/// Listen the URLs from the OS and the other instances, must be called before `app.run`.
+pub fn listen(app: &App) -> Result<smol::channel::Receiver<Vec<String>>> {
+    let (tx, rx) = smol::channel::unbounded();
+
+    let os_tx = tx.clone();
+    app.on_open_urls(move |urls| {
+        _ = os_tx.try_send(urls);
+    });
+
+    let urls = urls_from_args();
+    if !urls.is_empty() {
+        _ = tx.try_send(urls);
+    }
+
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))?;
+    let token = random_token();
+    write_lockfile(listener.local_addr()?.port(), &token)
+        .context("failed to write the instance lockfile")?;
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let Some(urls) = read_urls(stream, &token) else {
+                continue;
+            };
+
+            if tx.send_blocking(urls).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+/// Route the received URLs in the app.
+pub fn init(rx: smol::channel::Receiver<Vec<String>>, cx: &mut AppContext) {
+    cx.spawn(|cx| async move {
+        while let Ok(urls) = rx.recv().await {
+            if cx.update(|cx| open_urls(urls, cx)).is_err() {
+                break;
+            }
+        }
+    })
+    .detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeepLink;
+
+    #[test]
+    fn test_parse() {
+        let link = DeepLink::parse("gpui-app://story/Buttons?focus=true&raw").unwrap();
+        assert_eq!(link.host, "story");
+        assert_eq!(link.path, vec!["Buttons"]);
+        assert_eq!(
+            link.query,
+            vec![
+                ("focus".to_string(), "true".to_string()),
+                ("raw".to_string(), String::new())
+            ]
+        );
+
+        assert_eq!(link.query_value("focus"), Some("true"));
+        assert_eq!(link.query_value("raw"), Some(""));
+        assert_eq!(link.query_value("other"), None);
+        assert!(link.focus());
+        assert!(!DeepLink::parse("gpui-app://story/Buttons?focus=false")
+            .unwrap()
+            .focus());
+
+        let link =
+            DeepLink::parse("gpui-app://story/Text%20Input?q=a%26b%3Dc&bad=%2x%+1%").unwrap();
+        assert_eq!(link.path, vec!["Text Input"]);
+        assert_eq!(link.query_value("q"), Some("a&b=c"));
+        assert_eq!(link.query_value("bad"), Some("%2x%+1%"));
+
+        let link = DeepLink::parse("gpui-app://settings/").unwrap();
+        assert_eq!(link.host, "settings");
+        assert!(link.path.is_empty());
+        assert!(link.query.is_empty());
+
+        assert_eq!(DeepLink::parse("gpui-app://"), None);
+        assert_eq!(DeepLink::parse("https://story/Buttons"), None);
+    }
+}
@@ -8,7 +8,10 @@ use ui::input::{Copy, Cut, Paste, Redo, Undo};
 
 mod app_state;
 mod assets;
+mod deep_link;
 mod story_workspace;
+#[cfg(feature = "tray")]
+mod tray;
 
 actions!(main_menu, [Quit]);
 
@@ -21,9 +24,21 @@ fn init(app_state: Arc<AppState>, cx: &mut AppContext) -> Result<()> {
 }
 
 fn main() {
+    // Only keep one instance running, forward the deep links to it.
+    if deep_link::forward_to_running_instance() {
+        return;
+    }
+
     let app_state = Arc::new(AppState {});
 
     let app = App::new().with_assets(Assets);
+    let deep_links = match deep_link::listen(&app) {
+        Ok(rx) => Some(rx),
+        Err(e) => {
+            log::error!("failed to listen deep links: {}", e);
+            None
+        }
+    };
 
     app.run(move |cx| {
         AppState::set_global(Arc::downgrade(&app_state), cx);
@@ -54,8 +69,29 @@ fn main() {
         ]);
         cx.activate(true);
 
-        story_workspace::open_new(app_state.clone(), cx, |_workspace, _cx| {
-            // do something
+        #[cfg(feature = "tray")]
+        {
+            let result = tray::init(cx).and_then(|_| {
+                // Open the story by its deep link, the same as `gpui-app://story/Buttons`.
+                tray::register_item(
+                    "Open Buttons Story",
+                    |cx| {
+                        let url = format!("{}://story/Buttons", deep_link::SCHEME);
+                        deep_link::open_urls(vec![url], cx)
+                    },
+                    cx,
+                )
+            });
+            if let Err(e) = result {
+                log::error!("failed to init tray: {}", e);
+            }
+        }
+
+        // Route the deep links after the workspace registered its handlers, e.g.: `story`.
+        story_workspace::open_new(app_state.clone(), cx, |_workspace, cx| {
+            if let Some(rx) = deep_links {
+                deep_link::init(rx, cx);
+            }
         })
         .detach();
     });
@@ -4,23 +4,24 @@ use private::serde::Deserialize;
 use story::{
     ButtonStory, CalendarStory, DropdownStory, IconStory, ImageStory, InputStory, ListStory,
     ModalStory, PopupStory, ProgressStory, ResizableStory, ScrollableStory, StoryContainer,
-    SwitchStory, TableStory, TextStory, TooltipStory,
+    SwitchStory, TableStory, TextStory, ThemeEditorStory, TooltipStory,
 };
 use workspace::TitleBar;
 
 use std::sync::Arc;
 use ui::{
     button::Button,
-    dock::{DockArea, StackPanel, TabPanel},
+    dock::{DockArea, DockEvent, DockLayout, DockPosition, StackPanel, TabPanel},
     drawer::Drawer,
     h_flex,
     modal::Modal,
     popup_menu::PopupMenuExt,
+    task_list::{TaskStatus, TasksPanel},
     theme::{ActiveTheme, Theme},
     ContextModal, IconName, Root, Sizable,
 };
 
-use crate::app_state::AppState;
+use crate::{app_state::AppState, deep_link};
 
 #[derive(Clone, PartialEq, Eq, Deserialize)]
 struct SelectLocale(SharedString);
@@ -40,6 +41,7 @@ pub fn init(_app_state: Arc<AppState>, cx: &mut AppContext) {
 pub struct StoryWorkspace {
     locale_selector: View<LocaleSelector>,
     dock_area: View<DockArea>,
+    task_status: View<TaskStatus>,
 }
 
 impl StoryWorkspace {
@@ -52,6 +54,10 @@ impl StoryWorkspace {
         let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Horizontal, cx));
         let dock_area = cx.new_view(|cx| DockArea::new(stack_panel.clone(), cx));
         let weak_dock_area = dock_area.downgrade();
+        cx.subscribe(&dock_area, |_, view, event: &DockEvent, cx| match event {
+            DockEvent::LayoutChanged(_) => save_layout(&view.read(cx).dump_layout(cx)),
+        })
+        .detach();
 
         let tab_panel = cx.new_view(|cx| TabPanel::new(weak_dock_area.clone(), cx));
         let right_tab_panel = cx.new_view(|cx| TabPanel::new(weak_dock_area.clone(), cx));
@@ -173,18 +179,19 @@ impl StoryWorkspace {
         )
         .detach();
 
-        // StoryContainer::add_panel(
-        //     WebViewStory::view(cx).into(),
-        //     stack_panel.clone(),
-        //     DockPosition::Right,
-        //     px(450.),
-        //     cx,
-        // );
-
         StoryContainer::add_pane(
+            "Theme Editor",
+            "Edit the color tokens of the theme live, and export them as a theme file.",
+            ThemeEditorStory::view(cx).into(),
+            right_tab_panel.clone(),
+            cx,
+        )
+        .detach();
+
+        StoryContainer::add_story_pane(
             "Table",
             "Powerful table and datagrids built.",
-            TableStory::view(cx).into(),
+            TableStory::view(cx),
             tab_panel.clone(),
             cx,
         )
@@ -226,11 +233,58 @@ impl StoryWorkspace {
         )
         .detach();
 
+        // The bottom dock is toggled by `cmd-j` (`ctrl-j`).
+        let tasks_panel = cx.new_view(TasksPanel::new);
+        dock_area.update(cx, |view, cx| {
+            view.set_dock(DockPosition::Bottom, Arc::new(tasks_panel), px(200.), cx)
+        });
+
+        // Restore the saved layout, after the panes above are added.
+        cx.spawn(|this, mut cx| async move {
+            let Some(layout) = load_layout() else {
+                return;
+            };
+            _ = this.update(&mut cx, |this, cx| {
+                this.dock_area.update(cx, |dock_area, cx| {
+                    dock_area.load_layout(&layout, |_, _| None, cx)
+                })
+            });
+        })
+        .detach();
+
+        // Open the story of a `gpui-app://story/<name>` link, e.g.: `gpui-app://story/Buttons`,
+        // the window is not raised with `?focus=false`.
+        let window = cx.window_handle();
+        let weak_dock_area = dock_area.downgrade();
+        deep_link::register(
+            "story",
+            move |link, cx| {
+                let name = link.path.first().cloned().unwrap_or_default();
+                let focus = link.focus();
+                _ = window.update(cx, |_, cx| {
+                    if focus {
+                        cx.activate_window();
+                    }
+                    let found = weak_dock_area
+                        .update(cx, |dock_area, cx| {
+                            dock_area.activate_panel_by_key(&name, cx)
+                        })
+                        .unwrap_or(false);
+                    if !found {
+                        log::warn!("story not found: {}", name);
+                    }
+                });
+            },
+            cx,
+        );
+
         let locale_selector = cx.new_view(LocaleSelector::new);
+        let task_status = cx.new_view(TaskStatus::new);
 
         Self {
             dock_area,
             locale_selector,
+            task_status,
         }
     }
 
@@ -278,6 +332,27 @@ impl StoryWorkspace {
     }
 }
 
+fn layout_path() -> anyhow::Result<std::path::PathBuf> {
+    Ok(deep_link::instance_dir()?.join("layout.json"))
+}
+
+/// Autosave the dock layout, it is restored on the next start.
+fn save_layout(layout: &DockLayout) {
+    let result = serde_json::to_string_pretty(layout)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| Ok(std::fs::write(layout_path()?, json)?));
+    if let Err(err) = result {
+        log::error!("failed to save layout: {:?}", err);
+    }
+}
+
+fn load_layout() -> Option<DockLayout> {
+    let json = std::fs::read_to_string(layout_path().ok()?).ok()?;
+    serde_json::from_str(&json)
+        .map_err(|err| log::warn!("failed to load layout: {:?}", err))
+        .ok()
+}
+
 pub fn open_new(
     app_state: Arc<AppState>,
     cx: &mut AppContext,
@@ -387,6 +462,15 @@ impl Render for StoryWorkspace {
                     ),
             )
             .child(self.dock_area.clone())
+            .child(
+                h_flex()
+                    .h_7()
+                    .px_3()
+                    .justify_end()
+                    .border_t_1()
+                    .border_color(cx.theme().border)
+                    .child(self.task_status.clone()),
+            )
             .when(!has_active_modal, |this| {
                 this.when_some(active_drawer, |this, builder| {
                     let drawer = Drawer::new(cx);
@@ -0,0 +1,147 @@
+//! The optional system tray icon, enabled by the `tray` feature.
+//!
+//! The tray menu has the `Show/Hide Window` and `Quit` items by default,
+//! and the application can register more items by [`register_item`].
+use std::{collections::HashMap, rc::Rc, time::Duration};
+
+use anyhow::Result;
+use gpui::{AppContext, Global};
+use tray_icon::{
+    menu::{Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem},
+    Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+};
+
+use crate::Quit;
+
+/// The interval to poll the tray events.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const ICON_SIZE: u32 = 32;
+
+struct Tray {
+    _tray_icon: TrayIcon,
+    menu: Menu,
+    toggle_item: MenuItem,
+    quit_item: MenuItem,
+    /// The number of the items registered by the application, they are inserted on the top.
+    registered_count: usize,
+    handlers: HashMap<MenuId, Rc<dyn Fn(&mut AppContext)>>,
+    visible: bool,
+}
+
+impl Global for Tray {}
+
+/// A simple round icon, to avoid to depend on an image decoder.
+fn build_icon() -> Result<Icon> {
+    let center = ICON_SIZE as f32 / 2.;
+    let mut rgba = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..ICON_SIZE {
+        for x in 0..ICON_SIZE {
+            let distance =
+                ((x as f32 + 0.5 - center).powi(2) + (y as f32 + 0.5 - center).powi(2)).sqrt();
+            let alpha = if distance <= center - 2. { 255 } else { 0 };
+            rgba.extend_from_slice(&[40, 40, 40, alpha]);
+        }
+    }
+
+    Ok(Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE)?)
+}
+
+pub fn init(cx: &mut AppContext) -> Result<()> {
+    let menu = Menu::new();
+    let toggle_item = MenuItem::new("Hide Window", true, None);
+    let quit_item = MenuItem::new("Quit", true, None);
+    menu.append_items(&[&toggle_item, &PredefinedMenuItem::separator(), &quit_item])?;
+
+    let tray_icon = TrayIconBuilder::new()
+        .with_tooltip("GPUI App")
+        .with_icon(build_icon()?)
+        .with_menu(Box::new(menu.clone()))
+        .with_menu_on_left_click(false)
+        .build()?;
+
+    cx.set_global(Tray {
+        _tray_icon: tray_icon,
+        menu,
+        toggle_item,
+        quit_item,
+        registered_count: 0,
+        handlers: HashMap::new(),
+        visible: true,
+    });
+
+    cx.spawn(|cx| async move {
+        loop {
+            cx.background_executor().timer(POLL_INTERVAL).await;
+            if cx.update(poll_events).is_err() {
+                break;
+            }
+        }
+    })
+    .detach();
+
+    Ok(())
+}
+
+/// Register a menu item to the tray menu, the `handler` is called when the item clicked.
+pub fn register_item(
+    label: &str,
+    handler: impl Fn(&mut AppContext) + 'static,
+    cx: &mut AppContext,
+) -> Result<()> {
+    let Some(tray) = cx.try_global::<Tray>() else {
+        return Ok(());
+    };
+
+    let item = MenuItem::new(label, true, None);
+    tray.menu.insert(&item, tray.registered_count)?;
+
+    let tray = cx.global_mut::<Tray>();
+    tray.registered_count += 1;
+    tray.handlers.insert(item.id().clone(), Rc::new(handler));
+    Ok(())
+}
+
+fn poll_events(cx: &mut AppContext) {
+    while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+        // Click the tray icon to restore the hidden window.
+        if let TrayIconEvent::Click {
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            ..
+        } = event
+        {
+            set_window_visible(true, cx);
+        }
+    }
+
+    while let Ok(event) = MenuEvent::receiver().try_recv() {
+        let tray = cx.global::<Tray>();
+        if event.id == *tray.toggle_item.id() {
+            let visible = !tray.visible;
+            set_window_visible(visible, cx);
+        } else if event.id == *tray.quit_item.id() {
+            cx.dispatch_action(&Quit);
+        } else if let Some(handler) = tray.handlers.get(&event.id).cloned() {
+            handler(cx);
+        }
+    }
+}
+
+fn set_window_visible(visible: bool, cx: &mut AppContext) {
+    if visible {
+        cx.activate(true);
+        for window in cx.windows() {
+            _ = window.update(cx, |_, cx| cx.activate_window());
+        }
+    } else {
+        cx.hide();
+    }
+
+    let tray = cx.global_mut::<Tray>();
+    tray.visible = visible;
+    tray.toggle_item.set_text(if visible {
+        "Hide Window"
+    } else {
+        "Show Window"
+    });
+}
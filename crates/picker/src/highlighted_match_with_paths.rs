@@ -1,4 +1,4 @@
-use ui::{prelude::*, HighlightedLabel};
+use ui::{label::HighlightedLabel, prelude::*};
 
 #[derive(Clone)]
 pub struct HighlightedMatchWithPaths {
@@ -50,7 +50,7 @@ impl HighlightedText {
 }
 impl RenderOnce for HighlightedText {
     fn render(self, _: &mut WindowContext) -> impl IntoElement {
-        HighlightedLabel::new(self.text, self.highlight_positions).color(self.color)
+        HighlightedLabel::new(self.text, self.highlight_positions)
     }
 }
 
@@ -58,8 +58,8 @@ impl HighlightedMatchWithPaths {
     pub fn render_paths_children(&mut self, element: Div) -> Div {
         element.children(self.paths.clone().into_iter().map(|path| {
             HighlightedLabel::new(path.text, path.highlight_positions)
-                .size(LabelSize::Small)
-                .color(Color::Muted)
+                .text_sm()
+                .opacity(0.6)
         }))
     }
 }
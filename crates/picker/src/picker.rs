@@ -1,19 +1,24 @@
 use anyhow::Result;
 use gpui::{
-    actions, div, impl_actions, prelude::*, uniform_list, AnyElement, AppContext, ClickEvent,
-    DismissEvent, EventEmitter, FocusHandle, FocusableView, Length, ListSizingBehavior,
-    MouseButton, MouseUpEvent, Render, Task, UniformListScrollHandle, ViewContext, WindowContext,
+    actions, div, impl_actions, list, prelude::*, uniform_list, AnyElement, AppContext, ClickEvent,
+    DismissEvent, EventEmitter, FocusHandle, FocusableView, FontWeight, Length, ListAlignment,
+    ListSizingBehavior, ListState, MouseButton, MouseUpEvent, Render, Task,
+    UniformListScrollHandle, ViewContext, WindowContext,
 };
 use head::Head;
 use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
-use ui::{prelude::*, v_flex, Color, Label, ListItem, ListItemSpacing};
+use ui::{
+    cancellation::DropGuard, h_flex, prelude::*, theme::ActiveTheme as _, v_flex, Color, Icon,
+    IconName, Label, ListItem, ListItemSpacing, Sizable as _,
+};
 use workspace::ModalView;
 
 mod head;
 pub mod highlighted_match_with_paths;
 
 enum ElementContainer {
+    List(ListState),
     UniformList(UniformListScrollHandle),
 }
 
@@ -31,6 +36,8 @@ impl_actions!(picker, [ConfirmInput]);
 struct PendingUpdateMatches {
     delegate_update_matches: Option<Task<()>>,
     _task: Task<Result<()>>,
+    /// Cancel the matching synchronously when the pending update is dropped.
+    cancel: DropGuard,
 }
 
 pub struct Picker<D: PickerDelegate> {
@@ -65,6 +72,11 @@ pub trait PickerDelegate: Sized + 'static {
     fn separators_after_indices(&self) -> Vec<usize> {
         Vec::new()
     }
+    /// Return the title to render the match at the given index as a section header,
+    /// e.g.: "Recently used", it can't be selected and will be skipped by the keyboard navigation.
+    fn section_header(&self, _ix: usize) -> Option<SharedString> {
+        None
+    }
     fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Picker<Self>>);
     // Allows binding some optional effect to when the selection changes.
     fn selected_index_changed(
@@ -119,6 +131,18 @@ pub trait PickerDelegate: Sized + 'static {
         selected: bool,
         cx: &mut ViewContext<Picker<Self>>,
     ) -> Option<Self::ListItem>;
+    /// The leading icon of the match, e.g.: the file type icon.
+    fn match_icon(&self, _ix: usize) -> Option<IconName> {
+        None
+    }
+    /// The annotation at the end of the match, e.g.: the path, the keystroke.
+    fn match_annotation(&self, _ix: usize) -> Option<SharedString> {
+        None
+    }
+    /// The muted description line below the match.
+    fn match_description(&self, _ix: usize) -> Option<SharedString> {
+        None
+    }
     fn render_header(&self, _: &mut ViewContext<Picker<Self>>) -> Option<AnyElement> {
         None
     }
@@ -137,19 +161,28 @@ impl<D: PickerDelegate> FocusableView for Picker<D> {
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 enum ContainerKind {
-    //List,
+    List,
     UniformList,
 }
 
 impl<D: PickerDelegate> Picker<D> {
     /// A picker, which displays its matches using `gpui::uniform_list`, all matches should have the same height.
-    /// If `PickerDelegate::render_match` can return items with different heights, use `Picker::list`.
+    /// If `PickerDelegate::render_match` can return items with different heights, use `Picker::nonsearchable_list`.
+    ///
+    /// It falls back to `gpui::list` when the matches have section headers or descriptions.
     pub fn nonsearchable_uniform_list(delegate: D, cx: &mut ViewContext<Self>) -> Self {
         let head = Head::empty(Self::on_empty_head_blur, cx);
 
         Self::new(delegate, ContainerKind::UniformList, head, cx)
     }
 
+    /// A picker, which displays its matches using `gpui::list`, matches can have different heights.
+    pub fn nonsearchable_list(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let head = Head::empty(Self::on_empty_head_blur, cx);
+
+        Self::new(delegate, ContainerKind::List, head, cx)
+    }
+
     fn new(delegate: D, container: ContainerKind, head: Head, cx: &mut ViewContext<Self>) -> Self {
         let mut this = Self {
             delegate,
@@ -170,9 +203,22 @@ impl<D: PickerDelegate> Picker<D> {
 
     fn create_element_container(
         container: ContainerKind,
-        _cx: &mut ViewContext<Self>,
+        cx: &mut ViewContext<Self>,
     ) -> ElementContainer {
         match container {
+            ContainerKind::List => {
+                let view = cx.view().downgrade();
+                ElementContainer::List(ListState::new(
+                    0,
+                    ListAlignment::Top,
+                    px(1000.),
+                    move |ix, cx| {
+                        view.upgrade()
+                            .map(|view| view.update(cx, |this, cx| this.render_element(cx, ix)))
+                            .unwrap_or_else(|| div().into_any_element())
+                    },
+                ))
+            }
             ContainerKind::UniformList => {
                 ElementContainer::UniformList(UniformListScrollHandle::new())
             }
@@ -222,12 +268,32 @@ impl<D: PickerDelegate> Picker<D> {
         }
     }
 
+    fn is_selectable(&self, ix: usize) -> bool {
+        self.delegate.section_header(ix).is_none()
+    }
+
+    /// Find the selectable index from `ix` (inclusive) in the direction, wrap around the ends.
+    fn find_selectable(&self, ix: usize, forward: bool) -> Option<usize> {
+        let count = self.delegate.match_count();
+        (0..count)
+            .map(|offset| {
+                if forward {
+                    (ix + offset) % count
+                } else {
+                    (ix + count - offset) % count
+                }
+            })
+            .find(|&ix| self.is_selectable(ix))
+    }
+
     pub fn select_next(&mut self, _: &menu::SelectNext, cx: &mut ViewContext<Self>) {
         let count = self.delegate.match_count();
         if count > 0 {
             let index = self.delegate.selected_index();
             let ix = if index == count - 1 { 0 } else { index + 1 };
-            self.set_selected_index(ix, true, cx);
+            if let Some(ix) = self.find_selectable(ix, true) {
+                self.set_selected_index(ix, true, cx);
+            }
             cx.notify();
         }
     }
@@ -237,7 +303,9 @@ impl<D: PickerDelegate> Picker<D> {
         if count > 0 {
             let index = self.delegate.selected_index();
             let ix = if index == 0 { count - 1 } else { index - 1 };
-            self.set_selected_index(ix, true, cx);
+            if let Some(ix) = self.find_selectable(ix, false) {
+                self.set_selected_index(ix, true, cx);
+            }
             cx.notify();
         }
     }
@@ -245,7 +313,9 @@ impl<D: PickerDelegate> Picker<D> {
     fn select_first(&mut self, _: &menu::SelectFirst, cx: &mut ViewContext<Self>) {
         let count = self.delegate.match_count();
         if count > 0 {
-            self.set_selected_index(0, true, cx);
+            if let Some(ix) = self.find_selectable(0, true) {
+                self.set_selected_index(ix, true, cx);
+            }
             cx.notify();
         }
     }
@@ -253,16 +323,23 @@ impl<D: PickerDelegate> Picker<D> {
     fn select_last(&mut self, _: &menu::SelectLast, cx: &mut ViewContext<Self>) {
         let count = self.delegate.match_count();
         if count > 0 {
-            self.set_selected_index(count - 1, true, cx);
+            if let Some(ix) = self.find_selectable(count - 1, false) {
+                self.set_selected_index(ix, true, cx);
+            }
             cx.notify();
         }
     }
 
     pub fn cycle_selection(&mut self, cx: &mut ViewContext<Self>) {
         let count = self.delegate.match_count();
+        if count == 0 {
+            return;
+        }
         let index = self.delegate.selected_index();
         let new_index = if index + 1 == count { 0 } else { index + 1 };
-        self.set_selected_index(new_index, true, cx);
+        if let Some(new_index) = self.find_selectable(new_index, true) {
+            self.set_selected_index(new_index, true, cx);
+        }
         cx.notify();
     }
 
@@ -342,8 +419,11 @@ impl<D: PickerDelegate> Picker<D> {
         // task gets synchronously dropped, the delegate's task would keep running until
         // the picker's task has a chance of being scheduled, because dropping a task happens
         // asynchronously.
+        let cancel = DropGuard::default();
+        let token = cancel.token();
         self.pending_update_matches = Some(PendingUpdateMatches {
             delegate_update_matches: Some(delegate_pending_update_matches),
+            cancel,
             _task: cx.spawn(|this, mut cx| async move {
                 let delegate_pending_update_matches = this.update(&mut cx, |this, _| {
                     this.pending_update_matches
@@ -353,7 +433,9 @@ impl<D: PickerDelegate> Picker<D> {
                         .take()
                         .unwrap()
                 })?;
-                delegate_pending_update_matches.await;
+                if token.run(delegate_pending_update_matches).await.is_none() {
+                    return Ok(());
+                }
                 this.update(&mut cx, |this, cx| {
                     this.matches_updated(cx);
                 })
@@ -362,7 +444,28 @@ impl<D: PickerDelegate> Picker<D> {
     }
 
     fn matches_updated(&mut self, cx: &mut ViewContext<Self>) {
-        let index = self.delegate.selected_index();
+        let match_count = self.delegate.match_count();
+        // The section headers and the descriptions are taller than the matches,
+        // they can't be measured by the uniform list.
+        if matches!(self.element_container, ElementContainer::UniformList(_))
+            && (0..match_count).any(|ix| {
+                self.delegate.section_header(ix).is_some()
+                    || self.delegate.match_description(ix).is_some()
+            })
+        {
+            self.element_container = Self::create_element_container(ContainerKind::List, cx);
+        }
+        if let ElementContainer::List(state) = &mut self.element_container {
+            state.reset(match_count);
+        }
+
+        let mut index = self.delegate.selected_index();
+        if index < match_count && !self.is_selectable(index) {
+            if let Some(ix) = self.find_selectable(index, true) {
+                self.delegate.set_selected_index(ix, cx);
+                index = ix;
+            }
+        }
         self.scroll_to_item_index(index);
         self.pending_update_matches = None;
         if let Some(secondary) = self.confirm_on_update.take() {
@@ -380,11 +483,18 @@ impl<D: PickerDelegate> Picker<D> {
 
     fn scroll_to_item_index(&mut self, ix: usize) {
         match &mut self.element_container {
+            ElementContainer::List(state) => state.scroll_to_reveal_item(ix),
             ElementContainer::UniformList(scroll_handle) => scroll_handle.scroll_to_item(ix),
         }
     }
 
-    fn render_element(&self, cx: &mut ViewContext<Self>, ix: usize) -> impl IntoElement {
+    fn render_element(&self, cx: &mut ViewContext<Self>, ix: usize) -> AnyElement {
+        if let Some(header) = self.delegate.section_header(ix) {
+            return self
+                .render_section_header(ix, header, cx)
+                .into_any_element();
+        }
+
         div()
             .id(("item", ix))
             .cursor_pointer()
@@ -403,10 +513,7 @@ impl<D: PickerDelegate> Picker<D> {
                     this.handle_click(ix, event.modifiers.platform, cx)
                 }),
             )
-            .children(
-                self.delegate
-                    .render_match(ix, ix == self.delegate.selected_index(), cx),
-            )
+            .child(self.render_match(ix, cx))
             .when(
                 self.delegate.separators_after_indices().contains(&ix),
                 |picker| {
@@ -416,6 +523,84 @@ impl<D: PickerDelegate> Picker<D> {
                         .py(px(-1.0))
                 },
             )
+            .into_any_element()
+    }
+
+    fn render_section_header(
+        &self,
+        ix: usize,
+        header: SharedString,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        h_flex()
+            .id(("section-header", ix))
+            .w_full()
+            .px_2()
+            .pt_2()
+            .pb_1()
+            .text_xs()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(cx.theme().muted_foreground)
+            .when(ix > 0, |this| {
+                this.border_t_1().border_color(cx.theme().border)
+            })
+            .child(header)
+    }
+
+    /// Render the match with its icon, annotation and description.
+    fn render_match(&self, ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        let item = self
+            .delegate
+            .render_match(ix, ix == self.delegate.selected_index(), cx);
+        let icon = self.delegate.match_icon(ix);
+        let annotation = self.delegate.match_annotation(ix);
+        let description = self.delegate.match_description(ix);
+        if icon.is_none() && annotation.is_none() && description.is_none() {
+            return div().children(item).into_any_element();
+        }
+
+        let muted_color = cx.theme().muted_foreground;
+        h_flex()
+            .w_full()
+            .gap_2()
+            .items_start()
+            .when_some(icon, |this, icon| {
+                this.child(
+                    div()
+                        .flex_shrink_0()
+                        .pt_0p5()
+                        .child(Icon::new(icon).small().text_color(muted_color)),
+                )
+            })
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .children(item)
+                    .when_some(description, |this, description| {
+                        this.child(
+                            div()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_xs()
+                                .text_color(muted_color)
+                                .child(description),
+                        )
+                    }),
+            )
+            .when_some(annotation, |this, annotation| {
+                this.child(
+                    div()
+                        .flex_shrink_0()
+                        .whitespace_nowrap()
+                        .text_xs()
+                        .text_color(muted_color)
+                        .child(annotation),
+                )
+            })
+            .into_any_element()
     }
 
     fn render_element_container(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
@@ -425,6 +610,11 @@ impl<D: PickerDelegate> Picker<D> {
             ListSizingBehavior::Auto
         };
         match &self.element_container {
+            ElementContainer::List(state) => list(state.clone())
+                .with_sizing_behavior(sizing_behavior)
+                .flex_grow()
+                .py_1()
+                .into_any_element(),
             ElementContainer::UniformList(scroll_handle) => uniform_list(
                 cx.view().clone(),
                 "candidates",
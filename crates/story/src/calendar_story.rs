@@ -5,6 +5,7 @@ use gpui::{
 };
 use ui::{
     date_picker::{DatePicker, DatePickerEvent},
+    popover::PopupWidth,
     v_flex, Sizable as _,
 };
 
@@ -34,7 +35,8 @@ impl CalendarStory {
             DatePicker::new("date_picker_large", cx)
                 .large()
                 .date_format("%Y-%m-%d")
-                .width(px(300.))
+                .width(px(320.))
+                .popup_width(PopupWidth::MatchTrigger)
         });
         let date_picker_small = cx.new_view(|cx| {
             let mut picker = DatePicker::new("date_picker_small", cx)
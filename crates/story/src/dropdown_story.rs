@@ -7,7 +7,7 @@ use ui::{
     dropdown::{Dropdown, DropdownEvent, DropdownItem, SearchableVec},
     h_flex,
     theme::ActiveTheme,
-    v_flex, FocusableCycle, IconName, Sizable,
+    v_flex, Disableable as _, FocusableCycle, IconName, Sizable,
 };
 
 actions!(dropdown_story, [Tab, TabPrev]);
@@ -139,6 +139,7 @@ impl DropdownStory {
                     Dropdown::new("disabled-dropdown", Vec::<SharedString>::new(), None, cx)
                         .small()
                         .disabled(true)
+                        .disabled_reason("No options are available.")
                 }),
             }
         })
@@ -1,5 +1,11 @@
-use gpui::{px, ParentElement as _, Render, Styled, View, VisualContext as _, WindowContext};
-use ui::{h_flex, svg_img, v_flex, SvgImg};
+use gpui::{
+    div, px, ParentElement as _, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    drop_zone::{DropZone, DropZoneEvent},
+    h_flex, svg_img, v_flex, SvgImg,
+};
 
 const GOOGLE_LOGO: &str = include_str!("./fixtures/google.svg");
 const PIE_JSON: &str = include_str!("./fixtures/pie.json");
@@ -8,21 +14,40 @@ pub struct ImageStory {
     google_logo: SvgImg,
     pie_chart: SvgImg,
     inbox_img: SvgImg,
+    drop_zone: View<DropZone>,
+    /// The summary of the last pasted image or dropped files.
+    dropped: Option<SharedString>,
 }
 
 impl ImageStory {
-    pub fn new(_: &WindowContext) -> Self {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
         let chart = charts_rs::PieChart::from_json(PIE_JSON).unwrap();
+        let drop_zone = cx.new_view(DropZone::new);
+        cx.subscribe(&drop_zone, |this, _, event: &DropZoneEvent, cx| {
+            this.dropped = Some(match event {
+                DropZoneEvent::PasteImage(image) => format!(
+                    "Pasted {:?} image, {} bytes",
+                    image.format,
+                    image.bytes.len()
+                )
+                .into(),
+                DropZoneEvent::DropFiles(paths) => format!("Dropped {} files", paths.len()).into(),
+            });
+            cx.notify();
+        })
+        .detach();
 
         Self {
             google_logo: svg_img().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.)),
             pie_chart: svg_img().source(chart.svg().unwrap().as_bytes(), px(400.), px(400.)),
             inbox_img: svg_img().source("icons/inbox.svg", px(300.), px(300.)),
+            drop_zone,
+            dropped: None,
         }
     }
 
     pub fn view(cx: &mut WindowContext) -> View<Self> {
-        cx.new_view(|cx| Self::new(cx))
+        cx.new_view(Self::new)
     }
 }
 
@@ -43,5 +68,16 @@ impl Render for ImageStory {
             )
             .child(self.inbox_img.clone().w(px(80.)).h(px(80.)))
             .child(self.pie_chart.clone().size_full())
+            .child(
+                v_flex()
+                    .w(px(400.))
+                    .gap_2()
+                    .child(self.drop_zone.clone())
+                    .children(
+                        self.dropped
+                            .clone()
+                            .map(|dropped| div().text_sm().child(dropped)),
+                    ),
+            )
     }
 }
@@ -1,5 +1,5 @@
 use gpui::{
-    actions, div, px, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
+    actions, px, AppContext, ClickEvent, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
     ParentElement as _, Render, SharedString, Styled, View, ViewContext, VisualContext,
     WindowContext,
 };
@@ -8,15 +8,24 @@ use ui::{
     button::Button,
     checkbox::Checkbox,
     color_picker::{ColorPicker, ColorPickerEvent},
+    file_dialog::{self, FileFilter},
     h_flex,
-    input::{InputEvent, OtpInput, TextInput},
+    input::{InputEvent, MaskPattern, OtpInput, TextDecoration, TextInput},
+    label::FieldLabel,
     prelude::FluentBuilder as _,
-    theme::{Colorize, Theme},
+    theme::{ActiveTheme as _, Colorize, Theme},
     v_flex, FocusableCycle, IconName, Sizable,
 };
 
 use crate::section;
 
+/// The misspelled words with their suggestions, to demo the decorations of the input.
+const MISSPELLINGS: [(&str, &str); 3] = [
+    ("teh", "the"),
+    ("recieve", "receive"),
+    ("seperate", "separate"),
+];
+
 actions!(input_story, [Tab, TabPrev]);
 
 const CONTEXT: &str = "InputStory";
@@ -38,6 +47,9 @@ pub struct InputStory {
     both_input1: View<TextInput>,
     large_input: View<TextInput>,
     small_input: View<TextInput>,
+    currency_input: View<TextInput>,
+    phone_input: View<TextInput>,
+    spelling_input: View<TextInput>,
     otp_masked: bool,
     otp_input: View<OtpInput>,
     otp_value: Option<SharedString>,
@@ -45,6 +57,7 @@ pub struct InputStory {
     otp_input_large: View<OtpInput>,
     opt_input_sized: View<OtpInput>,
     color_picker: View<ColorPicker>,
+    selected_path: Option<SharedString>,
 }
 
 impl InputStory {
@@ -77,25 +90,38 @@ impl InputStory {
 
         let prefix_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .prefix(|_| div().child(IconName::Search).ml_3())
+                .prefix(|_| IconName::Search)
                 .placeholder("Search some thing...")
                 .cleanable()
         });
         let suffix_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .suffix(|_| div().child(IconName::Info).mr_3())
+                .suffix(|_| IconName::Info)
                 .placeholder("This input only support [a-zA-Z0-9] characters.")
                 .pattern(regex::Regex::new(r"^[a-zA-Z0-9]*$").unwrap())
                 .cleanable()
         });
         let both_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .prefix(|_| div().child(IconName::Search).ml_3())
-                .suffix(|_| div().child(IconName::Info).mr_3())
+                .prefix(|_| IconName::Search)
+                .suffix(|_| IconName::Info)
                 .cleanable()
                 .placeholder("This input have prefix and suffix.")
         });
 
+        let spelling_input = cx.new_view(|cx| {
+            let mut input = TextInput::new(cx);
+            input.set_text("Teh letter will recieve a seperate reply.", cx);
+            Self::check_spelling(&mut input, cx);
+            input
+        });
+        cx.subscribe(&spelling_input, |_, input, ev: &InputEvent, cx| {
+            if let InputEvent::Change(_) = ev {
+                input.update(cx, Self::check_spelling);
+            }
+        })
+        .detach();
+
         let otp_input = cx.new_view(|cx| OtpInput::new(6, cx).masked(true));
         cx.subscribe(&otp_input, |this, _, ev: &InputEvent, cx| match ev {
             InputEvent::Change(text) => {
@@ -143,6 +169,21 @@ impl InputStory {
             prefix_input1,
             suffix_input1,
             both_input1,
+            currency_input: cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .mask_pattern(MaskPattern::Number {
+                        separator: Some(','),
+                        fraction: Some(2),
+                        prefix: Some("$".into()),
+                    })
+                    .placeholder("Amount, e.g.: $1,234.50")
+            }),
+            phone_input: cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .mask_pattern(MaskPattern::Pattern("(999) 999-9999".into()))
+                    .placeholder("Phone, e.g.: (555) 123-4567")
+            }),
+            spelling_input,
             otp_masked: true,
             otp_input,
             otp_value: None,
@@ -168,6 +209,7 @@ impl InputStory {
                     .with_size(px(55.))
             }),
             color_picker,
+            selected_path: None,
         }
     }
 
@@ -193,6 +235,59 @@ impl InputStory {
         };
     }
 
+    fn open_file(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        let task =
+            file_dialog::open_file(vec![FileFilter::new("Images", ["png", "jpg", "svg"])], cx);
+        self.update_selected_path(task, cx);
+    }
+
+    fn open_folder(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        let task = file_dialog::open_folder(cx);
+        self.update_selected_path(task, cx);
+    }
+
+    fn update_selected_path(
+        &mut self,
+        task: gpui::Task<Option<std::path::PathBuf>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        cx.spawn(|view, mut cx| async move {
+            let path = task.await;
+            _ = view.update(&mut cx, |this, cx| {
+                this.selected_path = path.map(|path| path.display().to_string().into());
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    /// Underline the misspelled words, with the suggestion on hover.
+    fn check_spelling(input: &mut TextInput, cx: &mut ViewContext<TextInput>) {
+        let text = input.text();
+        let mut decorations = vec![];
+        let mut word_start = None;
+        for (ix, c) in text.char_indices().chain([(text.len(), ' ')]) {
+            match (c.is_alphabetic(), word_start) {
+                (true, None) => word_start = Some(ix),
+                (false, Some(start)) => {
+                    word_start = None;
+                    let word = text[start..ix].to_lowercase();
+                    if let Some((_, suggestion)) =
+                        MISSPELLINGS.iter().find(|(typo, _)| *typo == word)
+                    {
+                        decorations.push(
+                            TextDecoration::new(start..ix)
+                                .color(cx.theme().destructive)
+                                .message(format!("Did you mean: {}", suggestion)),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        input.set_decorations(decorations, cx);
+    }
+
     fn toggle_opt_masked(&mut self, _: &bool, cx: &mut ViewContext<Self>) {
         self.otp_masked = !self.otp_masked;
         self.otp_input
@@ -241,6 +336,12 @@ impl Render for InputStory {
                     .items_start()
                     .child(
                         section("Normal Input", cx)
+                            .child(
+                                FieldLabel::new("input1-label", "Name")
+                                    .required(true)
+                                    .info("Click the label to focus the input.")
+                                    .for_field(&self.input1.focus_handle(cx)),
+                            )
                             .child(self.input1.clone())
                             .child(self.input2.clone()),
                     )
@@ -266,6 +367,17 @@ impl Render for InputStory {
                             .child(self.small_input.clone()),
                     ),
             )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(
+                        section("Input Mask", cx)
+                            .child(self.currency_input.clone())
+                            .child(self.phone_input.clone()),
+                    )
+                    .child(section("Decorations", cx).child(self.spelling_input.clone())),
+            )
             .child(section("Color Picker", cx).child(self.color_picker.clone()))
             .child(
                 section(
@@ -293,6 +405,24 @@ impl Render for InputStory {
                         .child(self.opt_input_sized.clone()),
                 ),
             )
+            .child(
+                section("File Dialog", cx).child(
+                    h_flex()
+                        .gap_3()
+                        .items_center()
+                        .child(
+                            Button::new("btn-open-file", cx)
+                                .label("Open File...")
+                                .on_click(cx.listener(Self::open_file)),
+                        )
+                        .child(
+                            Button::new("btn-open-folder", cx)
+                                .label("Open Folder...")
+                                .on_click(cx.listener(Self::open_folder)),
+                        )
+                        .when_some(self.selected_path.clone(), |this, path| this.child(path)),
+                ),
+            )
             .child(
                 h_flex()
                     .items_center()
@@ -13,9 +13,10 @@ mod scrollable_story;
 mod switch_story;
 mod table_story;
 mod text_story;
+mod theme_editor_story;
 mod tooltip_story;
 
-use std::sync::Arc;
+use std::{rc::Rc, sync::Arc};
 
 pub use button_story::ButtonStory;
 pub use calendar_story::CalendarStory;
@@ -32,18 +33,19 @@ pub use scrollable_story::ScrollableStory;
 pub use switch_story::SwitchStory;
 pub use table_story::TableStory;
 pub use text_story::TextStory;
+pub use theme_editor_story::ThemeEditorStory;
 pub use tooltip_story::TooltipStory;
 
 use gpui::{
-    div, prelude::FluentBuilder as _, px, AnyView, AppContext, Div, EventEmitter, FocusableView,
-    InteractiveElement, IntoElement, ParentElement, Render, SharedString,
+    div, prelude::FluentBuilder as _, px, AnyElement, AnyView, AppContext, Div, EventEmitter,
+    FocusableView, InteractiveElement, IntoElement, ParentElement, Render, SharedString,
     StatefulInteractiveElement, Styled as _, Task, View, ViewContext, VisualContext, WindowContext,
 };
 
 use anyhow::Result;
 use ui::{
     divider::Divider,
-    dock::{Panel, PanelEvent, TabPanel},
+    dock::{Panel, PanelEvent, PanelHeader, TabPanel},
     h_flex,
     label::Label,
     v_flex,
@@ -72,6 +74,43 @@ pub fn section(title: impl IntoElement, cx: &WindowContext) -> Div {
         .child(div().flex_none().w_full().child(title))
 }
 
+/// A story that has the nested navigation, its [`StoryContainer`] shows the breadcrumb
+/// and the actions in the header.
+pub trait Story: Render {
+    /// The breadcrumb path after the story name, e.g.: the selected row of a table.
+    fn breadcrumb(&self, _cx: &WindowContext) -> Vec<SharedString> {
+        vec![]
+    }
+
+    /// Navigate back to the breadcrumb at the `depth`, `0` is the story root.
+    fn navigate(&mut self, _depth: usize, _cx: &mut ViewContext<Self>) {}
+
+    /// The actions rendered at the right of the header.
+    fn render_actions(&mut self, _cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        None
+    }
+}
+
+trait StoryHandle: 'static {
+    fn breadcrumb(&self, cx: &WindowContext) -> Vec<SharedString>;
+    fn navigate(&self, depth: usize, cx: &mut WindowContext);
+    fn render_actions(&self, cx: &mut WindowContext) -> Option<AnyElement>;
+}
+
+impl<S: Story> StoryHandle for View<S> {
+    fn breadcrumb(&self, cx: &WindowContext) -> Vec<SharedString> {
+        self.read(cx).breadcrumb(cx)
+    }
+
+    fn navigate(&self, depth: usize, cx: &mut WindowContext) {
+        self.update(cx, |story, cx| story.navigate(depth, cx))
+    }
+
+    fn render_actions(&self, cx: &mut WindowContext) -> Option<AnyElement> {
+        self.update(cx, |story, cx| story.render_actions(cx))
+    }
+}
+
 pub struct StoryContainer {
     focus_handle: gpui::FocusHandle,
     name: SharedString,
@@ -79,6 +118,8 @@ pub struct StoryContainer {
     width: Option<gpui::Pixels>,
     height: Option<gpui::Pixels>,
     story: Option<AnyView>,
+    story_handle: Option<Rc<dyn StoryHandle>>,
+    collapsed: bool,
 }
 
 impl FocusableView for StoryContainer {
@@ -109,6 +150,8 @@ impl StoryContainer {
             width: None,
             height: None,
             story: None,
+            story_handle: None,
+            collapsed: false,
         }
     }
 
@@ -131,6 +174,26 @@ impl StoryContainer {
         })
     }
 
+    /// Add the pane of the [`Story`], that shows its breadcrumb and actions in the header.
+    pub fn add_story_pane<S: Story>(
+        name: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+        story: View<S>,
+        tab_panel: View<TabPanel>,
+        cx: &mut WindowContext,
+    ) -> Task<Result<View<Self>>> {
+        let name = name.into();
+        let description = description.into();
+
+        cx.spawn(|mut cx| async move {
+            tab_panel.update(&mut cx, |panel, cx| {
+                let view = cx.new_view(|cx| Self::new(name, description, cx).nested_story(story));
+                panel.add_panel(Arc::new(view.clone()), cx);
+                view
+            })
+        })
+    }
+
     pub fn width(mut self, width: gpui::Pixels) -> Self {
         self.width = Some(width);
         self
@@ -145,39 +208,76 @@ impl StoryContainer {
         self.story = Some(story);
         self
     }
+
+    pub fn nested_story<S: Story>(mut self, story: View<S>) -> Self {
+        self.story = Some(story.clone().into());
+        self.story_handle = Some(Rc::new(story));
+        self
+    }
 }
 
 impl Panel for StoryContainer {
     fn title(&self, _cx: &WindowContext) -> SharedString {
         self.name.clone()
     }
+
+    fn panel_key(&self, _cx: &WindowContext) -> Option<SharedString> {
+        Some(self.name.clone())
+    }
 }
 
 impl EventEmitter<PanelEvent> for StoryContainer {}
 impl Render for StoryContainer {
-    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let story_handle = self.story_handle.clone();
+        let breadcrumb = story_handle
+            .as_ref()
+            .map(|story| story.breadcrumb(cx))
+            .unwrap_or_default();
+        let actions = story_handle
+            .as_ref()
+            .and_then(|story| story.render_actions(cx));
+
         v_flex()
             .id("story-container")
             .size_full()
             .overflow_scroll()
             .child(
-                div()
-                    .flex()
-                    .flex_col()
-                    .gap_4()
-                    .p_4()
-                    .child(Label::new(self.description.clone()).text_size(px(16.0)))
-                    .child(Divider::horizontal().label("This is a divider")),
+                PanelHeader::new("story-header", self.name.clone())
+                    .collapsed(self.collapsed)
+                    .on_toggle(cx.listener(|this, collapsed: &bool, cx| {
+                        this.collapsed = *collapsed;
+                        cx.notify();
+                    }))
+                    .breadcrumb(breadcrumb)
+                    .when_some(story_handle, |this, story| {
+                        this.on_navigate(move |depth, cx| story.navigate(*depth, cx))
+                    })
+                    .when_some(actions, |this, actions| this.action(actions)),
             )
-            .when_some(self.story.clone(), |this, story| {
+            .when(!self.collapsed, |this| {
                 this.child(
-                    v_flex()
-                        .id("story-children")
-                        .overflow_scroll()
-                        .size_full()
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_4()
                         .p_4()
-                        .child(story),
+                        .child(Label::new(self.description.clone()).text_size(px(16.0)))
+                        .child(Divider::horizontal().label("This is a divider")),
                 )
             })
+            .when_some(
+                self.story.clone().filter(|_| !self.collapsed),
+                |this, story| {
+                    this.child(
+                        v_flex()
+                            .id("story-children")
+                            .overflow_scroll()
+                            .size_full()
+                            .p_4()
+                            .child(story),
+                    )
+                },
+            )
     }
 }
@@ -1,4 +1,5 @@
 use core::time;
+use std::collections::BTreeSet;
 
 use fake::Fake;
 use gpui::{
@@ -8,12 +9,13 @@ use gpui::{
 };
 
 use ui::{
+    button::Button,
     h_flex,
-    label::Label,
+    label::{HighlightedLabel, Label},
     list::ListItem,
     list::{List, ListDelegate},
     theme::{hsl, ActiveTheme, Colorize as _},
-    v_flex,
+    v_flex, IconName, Sizable as _,
 };
 
 actions!(list_story, [SelectedCompany]);
@@ -42,13 +44,21 @@ struct CompanyListItem {
     base: ListItem,
     ix: usize,
     company: Company,
+    query: String,
     selected: bool,
 }
 
 impl CompanyListItem {
-    pub fn new(id: impl Into<ElementId>, company: Company, ix: usize, selected: bool) -> Self {
+    pub fn new(
+        id: impl Into<ElementId>,
+        company: Company,
+        query: String,
+        ix: usize,
+        selected: bool,
+    ) -> Self {
         CompanyListItem {
             company,
+            query,
             ix,
             base: ListItem::new(id),
             selected,
@@ -95,7 +105,13 @@ impl RenderOnce for CompanyListItem {
                             .max_w(px(500.))
                             .overflow_x_hidden()
                             .flex_nowrap()
-                            .child(Label::new(self.company.name.clone()).whitespace_nowrap())
+                            .child(
+                                HighlightedLabel::with_query(
+                                    self.company.name.clone(),
+                                    &self.query,
+                                )
+                                .whitespace_nowrap(),
+                            )
                             .child(
                                 div().text_sm().overflow_x_hidden().child(
                                     Label::new(self.company.industry.clone())
@@ -134,7 +150,9 @@ impl RenderOnce for CompanyListItem {
 struct CompanyListDelegate {
     companies: Vec<Company>,
     matched_companies: Vec<Company>,
+    query: String,
     selected_index: usize,
+    selected_indexes: BTreeSet<usize>,
     confirmed_index: Option<usize>,
 }
 
@@ -150,6 +168,7 @@ impl ListDelegate for CompanyListDelegate {
     }
 
     fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.query = query.to_string();
         self.matched_companies = self
             .companies
             .iter()
@@ -174,14 +193,53 @@ impl ListDelegate for CompanyListDelegate {
         }
     }
 
+    fn set_selected_indexes(
+        &mut self,
+        indexes: &BTreeSet<usize>,
+        cx: &mut ViewContext<List<Self>>,
+    ) {
+        self.selected_indexes = indexes.clone();
+        cx.notify();
+    }
+
     fn render_item(&self, ix: usize, _cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
-        let selected = ix == self.selected_index || Some(ix) == self.confirmed_index;
+        let selected = ix == self.selected_index
+            || Some(ix) == self.confirmed_index
+            || self.selected_indexes.contains(&ix);
         if let Some(company) = self.matched_companies.get(ix) {
-            return Some(CompanyListItem::new(ix, company.clone(), ix, selected));
+            return Some(CompanyListItem::new(
+                ix,
+                company.clone(),
+                self.query.clone(),
+                ix,
+                selected,
+            ));
         }
 
         None
     }
+
+    fn render_selection_actions(
+        &self,
+        _: &BTreeSet<usize>,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> impl IntoElement {
+        Button::new("remove-selected", cx)
+            .icon(IconName::Delete)
+            .label("Remove")
+            .ghost()
+            .xsmall()
+            .on_click(cx.listener(|list, _, cx| {
+                let indexes = list.selected_indexes().clone();
+                let delegate = list.delegate_mut();
+                for ix in indexes.iter().rev() {
+                    if *ix < delegate.matched_companies.len() {
+                        delegate.matched_companies.remove(*ix);
+                    }
+                }
+                list.clear_selection(cx);
+            }))
+    }
 }
 
 impl CompanyListDelegate {
@@ -211,11 +269,14 @@ impl ListStory {
                 CompanyListDelegate {
                     matched_companies: companies.clone(),
                     companies,
+                    query: String::new(),
                     selected_index: 0,
+                    selected_indexes: BTreeSet::new(),
                     confirmed_index: None,
                 },
                 cx,
             )
+            .checkable()
         });
 
         // Spawn a background to random refresh the list
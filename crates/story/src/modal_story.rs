@@ -2,9 +2,9 @@ use std::{sync::Arc, time::Duration};
 
 use fake::Fake;
 use gpui::{
-    div, prelude::FluentBuilder as _, px, FocusHandle, FocusableView, IntoElement, ParentElement,
-    Render, SharedString, Styled, Task, Timer, View, ViewContext, VisualContext as _, WeakView,
-    WindowContext,
+    div, prelude::FluentBuilder as _, px, AnchorCorner, AnyElement, FocusHandle, FocusableView,
+    IntoElement, ParentElement, Render, SharedString, Styled, Task, Timer, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
 };
 
 use ui::{
@@ -16,7 +16,7 @@ use ui::{
     list::{List, ListDelegate, ListItem},
     notification::{Notification, NotificationType},
     theme::ActiveTheme as _,
-    v_flex, ContextModal as _, Icon, IconName, Placement,
+    v_flex, ContextModal as _, Icon, IconName, Placement, Root,
 };
 
 pub struct ListItemDeletegate {
@@ -64,18 +64,21 @@ impl ListDelegate for ListItemDeletegate {
         let selected = Some(ix) == self.selected_index;
 
         if let Some(item) = self.matches.get(ix) {
+            // Show the food and its country in two lines, e.g.: "Baguette (France)".
+            let (name, country) = match item.split_once(" (") {
+                Some((name, country)) => (name, country.trim_end_matches(')')),
+                None => (item.as_str(), ""),
+            };
             let list_item = ListItem::new(("item", ix))
                 .check_icon(ui::IconName::Check)
                 .confirmed(confirmed)
                 .selected(selected)
                 .py_1()
                 .px_3()
-                .child(
-                    h_flex()
-                        .items_center()
-                        .justify_between()
-                        .child(item.to_string()),
-                )
+                .two_line()
+                .leading(Icon::new(IconName::Inbox))
+                .title(name.to_string())
+                .subtitle(country.to_string())
                 .suffix(|cx| {
                     Button::new("like", cx)
                         .icon(IconName::Heart)
@@ -139,6 +142,23 @@ impl ListDelegate for ListItemDeletegate {
     }
 }
 
+/// Render the key-value rows of a build, to show in the notification.
+fn render_build_summary(cx: &mut WindowContext) -> AnyElement {
+    let row = |key: &'static str, value: &'static str, cx: &mut WindowContext| {
+        h_flex()
+            .justify_between()
+            .child(div().text_color(cx.theme().muted_foreground).child(key))
+            .child(value)
+    };
+
+    v_flex()
+        .gap_1()
+        .child(row("Target", "x86_64-apple-darwin", cx))
+        .child(row("Duration", "1m 23s", cx))
+        .child(row("Warnings", "3", cx))
+        .into_any_element()
+}
+
 pub struct ModalStory {
     focus_handle: FocusHandle,
     drawer_placement: Option<Placement>,
@@ -149,6 +169,7 @@ pub struct ModalStory {
     modal_overlay: bool,
     model_show_close: bool,
     model_padding: bool,
+    notification_placement: AnchorCorner,
 }
 
 impl ModalStory {
@@ -240,6 +261,7 @@ impl ModalStory {
             modal_overlay: true,
             model_show_close: true,
             model_padding: true,
+            notification_placement: AnchorCorner::TopRight,
         }
     }
 
@@ -512,6 +534,52 @@ impl Render for ModalStory {
                                         ),
                                     )
                                 })),
+                        )
+                        .child(
+                            Button::new("show-notify-actions", cx)
+                                .label("Notification with Actions")
+                                .on_click(cx.listener(|_, _, cx| {
+                                    cx.push_notification(
+                                        Notification::new("File deleted.")
+                                            .duration(Duration::from_secs(10))
+                                            .action("Undo", |_, _| println!("Undo delete"))
+                                            .action("View", |_, _| println!("View trash")),
+                                    )
+                                })),
+                        )
+                        .child(
+                            Button::new("show-notify-content", cx)
+                                .label("Notification with Content")
+                                .on_click(cx.listener(|_, _, cx| {
+                                    cx.push_notification(
+                                        Notification::success("")
+                                            .title("Build finished")
+                                            .content(render_build_summary),
+                                    )
+                                })),
+                        )
+                        .child(
+                            Button::new("move-notifications", cx)
+                                .label(format!(
+                                    "Notifications at {:?}",
+                                    self.notification_placement
+                                ))
+                                .on_click(cx.listener(|this, _, cx| {
+                                    this.notification_placement = match this.notification_placement
+                                    {
+                                        AnchorCorner::TopRight => AnchorCorner::BottomRight,
+                                        AnchorCorner::BottomRight => AnchorCorner::BottomLeft,
+                                        AnchorCorner::BottomLeft => AnchorCorner::TopLeft,
+                                        AnchorCorner::TopLeft => AnchorCorner::TopRight,
+                                    };
+                                    let placement = this.notification_placement;
+                                    Root::update(cx, |root, cx| {
+                                        root.notification.update(cx, |list, cx| {
+                                            list.set_placement(placement, cx);
+                                            list.set_max_visible(3, cx);
+                                        })
+                                    });
+                                })),
                         ),
                 ),
         )
@@ -13,6 +13,7 @@ use ui::{
     input::TextInput,
     popover::{Popover, PopoverContent},
     popup_menu::PopupMenuExt,
+    position::Placement,
     switch::Switch,
     v_flex, IconName, Sizable,
 };
@@ -260,6 +261,25 @@ impl Render for PopupStory {
                                     })
                             }),
                     )
+                    .child(
+                        Popover::new("info-arrow")
+                            .placement(Placement::Bottom)
+                            .arrow()
+                            .trigger(Button::new("info-arrow", cx).label("With Arrow"))
+                            .content(|cx| {
+                                cx.new_view(|cx| {
+                                    PopoverContent::new(cx, |_| {
+                                        div()
+                                            .w_64()
+                                            .child(
+                                                "The popover will flip to the top \
+                                                if there is not enough space.",
+                                            )
+                                            .into_any()
+                                    })
+                                })
+                            }),
+                    )
                     .child(self.message.clone()),
             )
             .child("Right click to open ContextMenu")
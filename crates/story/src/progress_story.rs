@@ -1,5 +1,7 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
 use gpui::{
-    div, px, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext,
+    div, px, IntoElement, ParentElement, Render, Styled, Timer, View, ViewContext, VisualContext,
     WindowContext,
 };
 use ui::{
@@ -10,6 +12,7 @@ use ui::{
     progress::Progress,
     skeleton::Skeleton,
     slider::{Slider, SliderEvent},
+    task_list::{TaskItem, TaskList},
     v_flex, IconName, Sizable,
 };
 
@@ -19,6 +22,7 @@ pub struct ProgressStory {
     slider1_value: f32,
     slider2: View<Slider>,
     slider2_value: f32,
+    task_count: usize,
 }
 
 impl ProgressStory {
@@ -57,9 +61,40 @@ impl ProgressStory {
             slider2_value: 1.,
             slider1,
             slider2,
+            task_count: 0,
         }
     }
 
+    /// Start a fake background task that is registered to the [`TaskList`].
+    fn start_task(&mut self, cx: &mut ViewContext<Self>) {
+        self.task_count += 1;
+        let cancelled = Rc::new(Cell::new(false));
+        let handle = TaskList::add(
+            TaskItem::new(format!("Background Task {}", self.task_count)).on_cancel({
+                let cancelled = cancelled.clone();
+                move |_| cancelled.set(true)
+            }),
+            cx,
+        );
+
+        cx.spawn(|_, mut cx| async move {
+            for step in 1..=20 {
+                Timer::after(Duration::from_millis(250)).await;
+                if cancelled.get() {
+                    return;
+                }
+
+                _ = cx.update(|cx| {
+                    handle.set_progress(step as f32 * 5., cx);
+                    handle.set_message(format!("Processing {} of 20", step), cx);
+                });
+            }
+
+            _ = cx.update(|cx| handle.finish(cx));
+        })
+        .detach();
+    }
+
     pub fn set_value(&mut self, value: f32) {
         self.value = value;
     }
@@ -103,6 +138,11 @@ impl Render for ProgressStory {
                     ),
             )
             .child(div().w_1_2().child(Progress::new().value(self.value)))
+            .child(
+                Button::new("start-task", cx)
+                    .label("Start Background Task")
+                    .on_click(cx.listener(|this, _, cx| this.start_task(cx))),
+            )
             .child(
                 h_flex()
                     .gap_x_2()
@@ -37,7 +37,9 @@ impl ResizableStory {
                         .child(
                             resizable_panel()
                                 .size(px(300.))
-                                .content(|cx| panel_box("Left 1 (Min 120px)", cx)),
+                                .min_size(px(120.))
+                                .collapsible(true)
+                                .content(|cx| panel_box("Left 1 (Min 120px, Collapsible)", cx)),
                             cx,
                         )
                         .child(
@@ -70,10 +72,12 @@ impl ResizableStory {
 
         let group2 = cx.new_view(|cx| {
             h_resizable(cx)
+                .on_resize(|sizes, _| println!("Resized: {:?}", sizes))
                 .child(
                     resizable_panel()
                         .size(px(300.))
-                        .content(|cx| panel_box("Left 2", cx)),
+                        .max_size(px(500.))
+                        .content(|cx| panel_box("Left 2 (Max 500px)", cx)),
                     cx,
                 )
                 .child(
@@ -196,6 +196,7 @@ impl Render for ScrollableStory {
                         v_flex()
                             .id("test-1")
                             .scrollable(cx.view().entity_id(), ScrollbarAxis::Vertical)
+                            .scroll_shadow(true)
                             .focusable()
                             .p_3()
                             .w(test_width)
@@ -1,21 +1,27 @@
-use std::time::Duration;
+use std::{rc::Rc, time::Duration};
 
 use fake::Fake;
 use gpui::{
-    div, img, IntoElement, ParentElement, Pixels, Render, SharedString, Styled, Timer, View,
-    ViewContext, VisualContext as _, WindowContext,
+    div, img, px, AnyElement, FocusHandle, InteractiveElement as _, IntoElement, ParentElement,
+    Render, SharedString, Styled, Timer, View, ViewContext, VisualContext as _, WindowContext,
 };
 use ui::{
+    button::Button,
     checkbox::Checkbox,
     h_flex,
     indicator::Indicator,
-    label::Label,
     prelude::FluentBuilder as _,
-    table::{ColSort, Table, TableDelegate, TableEvent},
+    search_overlay::{Find, SearchOverlay, Searchable},
+    table::{
+        CellRenderer, CellValue, ColAggregate, ColFilter, ColSort, FilterPredicate, Table,
+        TableColumn, TableDelegate, TableEvent,
+    },
     theme::ActiveTheme as _,
-    v_flex, Icon, IconName, Selectable,
+    v_flex, Icon, IconName, Selectable, Sizable as _,
 };
 
+use crate::Story;
+
 struct Customer {
     id: usize,
     login: String,
@@ -30,14 +36,55 @@ struct Customer {
     age: usize,
     verified: bool,
     confirmed: bool,
+    /// The completeness of the profile, in `0..=100`.
+    profile: usize,
+    /// The visits of the last 12 months.
+    activity: Vec<f64>,
 }
 
 impl Customer {
+    /// Return the value of the column of the `id`.
+    fn value(&self, id: &str) -> CellValue {
+        match id {
+            "id" => self.id.into(),
+            "login" => self.login.clone().into(),
+            "first_name" => self.first_name.clone().into(),
+            "last_name" => self.last_name.clone().into(),
+            "company" => self.company.clone().into(),
+            "city" => self.city.clone().into(),
+            "country" => self.country.clone().into(),
+            "email" => self.email.clone().into(),
+            "phone" => self.phone.clone().into(),
+            "gender" => self.gender.into(),
+            "age" => self.age.into(),
+            "verified" => self.verified.into(),
+            "confirmed" => self.confirmed.into(),
+            "profile" => self.profile.into(),
+            "activity" => self.activity.clone().into(),
+            _ => CellValue::Empty,
+        }
+    }
+
     fn render_avatar(&self, _: &mut WindowContext) -> impl IntoElement {
         let image_id = self.id % 70 + 1;
         let avatar_url = format!("https://i.pravatar.cc/40?image={}", image_id);
         img(avatar_url).size_5().rounded_full()
     }
+
+    fn contains(&self, query: &str) -> bool {
+        [
+            &self.login,
+            &self.first_name,
+            &self.last_name,
+            &self.company,
+            &self.city,
+            &self.country,
+            &self.email,
+            &self.phone,
+        ]
+        .iter()
+        .any(|value| value.to_lowercase().contains(query))
+    }
 }
 
 fn randome_customers(size: usize) -> Vec<Customer> {
@@ -56,38 +103,27 @@ fn randome_customers(size: usize) -> Vec<Customer> {
             age: (18..80).fake(),
             verified: (0..=1).fake::<u8>() == 1,
             confirmed: (0..=1).fake::<u8>() == 1,
+            profile: (0..=100).fake(),
+            activity: (0..12).map(|_| (0..50).fake::<u8>() as f64).collect(),
         })
         .collect()
 }
 
-struct Column {
-    id: SharedString,
-    name: SharedString,
-    sort: Option<ColSort>,
-}
-
-impl Column {
-    fn new(
-        id: impl Into<SharedString>,
-        name: impl Into<SharedString>,
-        sort: Option<ColSort>,
-    ) -> Self {
-        Self {
-            id: id.into(),
-            name: name.into(),
-            sort,
-        }
-    }
-}
-
 struct CustomerTableDelegate {
     customers: Vec<Customer>,
-    columns: Vec<Column>,
+    /// The customers that are not matched by the filters.
+    hidden_customers: Vec<Customer>,
+    columns: Vec<TableColumn>,
     loop_selection: bool,
     col_resize: bool,
     col_order: bool,
     col_sort: bool,
     col_selection: bool,
+    group_by_age: bool,
+    cell_focus: bool,
+    expand_rows: bool,
+    row_order: bool,
+    tree_rows: bool,
     loading: bool,
     is_eof: bool,
 }
@@ -96,27 +132,96 @@ impl CustomerTableDelegate {
     fn new(size: usize) -> Self {
         Self {
             customers: randome_customers(size),
+            hidden_customers: vec![],
             columns: vec![
-                Column::new("id", "ID", Some(ColSort::Ascending)),
-                Column::new("login", "Login", Some(ColSort::Default)),
-                Column::new("first_name", "First Name", Some(ColSort::Default)),
-                Column::new("last_name", "Last Name", Some(ColSort::Default)),
-                Column::new("company", "Company", Some(ColSort::Default)),
-                Column::new("city", "City", Some(ColSort::Default)),
-                Column::new("country", "Country", Some(ColSort::Default)),
-                Column::new("email", "Email", Some(ColSort::Default)),
-                Column::new("phone", "Phone", None),
-                Column::new("gender", "Gender", None),
-                Column::new("age", "Age", Some(ColSort::Default)),
-                Column::new("verified", "Verified", None),
-                Column::new("confirmed", "Confirmed", None),
-                Column::new("twitter", "Twitter", None),
+                TableColumn::new("id", "ID")
+                    .width(px(100.))
+                    .sort(ColSort::Ascending),
+                TableColumn::new("login", "Login")
+                    .width(px(220.))
+                    .sort(ColSort::Default),
+                TableColumn::new("first_name", "First Name")
+                    .width(px(150.))
+                    .sort(ColSort::Default),
+                TableColumn::new("last_name", "Last Name")
+                    .width(px(150.))
+                    .sort(ColSort::Default),
+                TableColumn::new("company", "Company")
+                    .width(px(300.))
+                    .sort(ColSort::Default)
+                    .filter(ColFilter::Text)
+                    .renderer(CellRenderer::Custom(Rc::new(|_, value, _| {
+                        h_flex()
+                            .items_center()
+                            .justify_between()
+                            .gap_1()
+                            .child(value.to_string())
+                            .child(IconName::Info)
+                            .into_any_element()
+                    }))),
+                TableColumn::new("city", "City")
+                    .width(px(200.))
+                    .sort(ColSort::Default)
+                    .filter(ColFilter::Text),
+                TableColumn::new("country", "Country")
+                    .width(px(200.))
+                    .sort(ColSort::Default)
+                    .filter(ColFilter::Text),
+                TableColumn::new("email", "Email")
+                    .width(px(350.))
+                    .sort(ColSort::Default),
+                TableColumn::new("phone", "Phone").width(px(240.)),
+                TableColumn::new("gender", "Gender")
+                    .width(px(80.))
+                    .filter(ColFilter::Set(vec!["Male".into(), "Female".into()]))
+                    .formatter(|value| match value.as_f64() {
+                        Some(gender) if gender == 0. => "Male".into(),
+                        Some(_) => "Female".into(),
+                        None => "".into(),
+                    }),
+                TableColumn::new("age", "Age")
+                    .width(px(90.))
+                    .sort(ColSort::Default)
+                    .filter(ColFilter::NumberRange),
+                TableColumn::new("verified", "Verified")
+                    .width(px(90.))
+                    .filter(ColFilter::Set(vec!["Yes".into(), "No".into()]))
+                    .renderer(CellRenderer::Badge)
+                    .formatter(|value| match value {
+                        CellValue::Bool(true) => "Yes".into(),
+                        _ => "No".into(),
+                    }),
+                TableColumn::new("confirmed", "Confirmed")
+                    .width(px(90.))
+                    .renderer(CellRenderer::Custom(Rc::new(|_, value, _| match value {
+                        CellValue::Bool(true) => {
+                            Icon::new(IconName::Check).size_4().into_any_element()
+                        }
+                        _ => div().into_any_element(),
+                    }))),
+                TableColumn::new("profile", "Profile")
+                    .width(px(120.))
+                    .renderer(CellRenderer::Progress),
+                TableColumn::new("activity", "Activity")
+                    .width(px(120.))
+                    .renderer(CellRenderer::Sparkline),
+                TableColumn::new("twitter", "Twitter")
+                    .width(px(90.))
+                    .renderer(CellRenderer::Button(Rc::new(|row_ix, _| {
+                        println!("Follow the customer at row: {}", row_ix)
+                    })))
+                    .formatter(|_| "Follow".into()),
             ],
             loop_selection: true,
             col_resize: true,
             col_order: true,
             col_sort: true,
             col_selection: true,
+            group_by_age: false,
+            cell_focus: false,
+            expand_rows: false,
+            row_order: false,
+            tree_rows: false,
             loading: false,
             is_eof: false,
         }
@@ -132,39 +237,35 @@ impl TableDelegate for CustomerTableDelegate {
         self.customers.len()
     }
 
-    fn col_name(&self, col_ix: usize) -> SharedString {
-        if let Some(col) = self.columns.get(col_ix) {
-            col.name.clone()
-        } else {
-            "--".into()
-        }
+    fn column(&self, col_ix: usize) -> Option<&TableColumn> {
+        self.columns.get(col_ix)
     }
 
-    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
-        if let Some(col) = self.columns.get(col_ix) {
-            Some(
-                match col.id.as_ref() {
-                    "id" => 100.0,
-                    "login" => 220.0,
-                    "first_name" => 150.0,
-                    "last_name" => 150.0,
-                    "company" => 300.0,
-                    "city" => 200.0,
-                    "country" => 200.0,
-                    "email" => 350.0,
-                    "phone" => 240.0,
-                    "gender" => 80.0,
-                    "age" => 90.0,
-                    "verified" => 90.0,
-                    "confirmed" => 90.0,
-                    "twitter" => 90.0,
-                    _ => 200.0,
-                }
-                .into(),
-            )
-        } else {
-            None
-        }
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> CellValue {
+        let (Some(customer), Some(col)) = (self.customers.get(row_ix), self.columns.get(col_ix))
+        else {
+            return CellValue::Empty;
+        };
+
+        customer.value(&col.id)
+    }
+
+    fn filter(&mut self, predicates: &[FilterPredicate], _: &mut ViewContext<Table<Self>>) {
+        let mut customers = std::mem::take(&mut self.customers);
+        customers.append(&mut self.hidden_customers);
+
+        let (customers, hidden_customers): (Vec<_>, Vec<_>) =
+            customers.into_iter().partition(|customer| {
+                predicates.iter().all(|predicate| {
+                    let Some(col) = self.columns.get(predicate.col_ix()) else {
+                        return true;
+                    };
+                    let value = customer.value(&col.id);
+                    predicate.matches(&col.format(&value), &value)
+                })
+            });
+        self.customers = customers;
+        self.hidden_customers = hidden_customers;
     }
 
     fn can_resize_col(&self, col_ix: usize) -> bool {
@@ -175,59 +276,75 @@ impl TableDelegate for CustomerTableDelegate {
         return self.col_selection;
     }
 
-    fn render_td(
+    fn can_loop_select(&self) -> bool {
+        self.loop_selection
+    }
+
+    fn can_focus_cell(&self) -> bool {
+        self.cell_focus
+    }
+
+    fn col_aggregate(&self, col_ix: usize) -> Option<ColAggregate> {
+        match self.columns.get(col_ix)?.id.as_ref() {
+            "id" => Some(ColAggregate::Count),
+            "age" => Some(ColAggregate::Avg),
+            _ => None,
+        }
+    }
+
+    fn can_group(&self) -> bool {
+        self.group_by_age
+    }
+
+    fn group_key(&self, row_ix: usize) -> Option<SharedString> {
+        let age = self.customers.get(row_ix)?.age;
+        Some(format!("Age {}-{}", age / 10 * 10, age / 10 * 10 + 9).into())
+    }
+
+    fn can_nest_rows(&self) -> bool {
+        self.tree_rows
+    }
+
+    fn row_depth(&self, row_ix: usize) -> usize {
+        // Nest every customer under the previous one, three levels deep.
+        self.customers
+            .get(row_ix)
+            .map_or(0, |customer| customer.id % 3)
+    }
+
+    fn can_expand_rows(&self) -> bool {
+        self.expand_rows
+    }
+
+    fn render_row_detail(
         &self,
         row_ix: usize,
-        col_ix: usize,
         cx: &mut ViewContext<Table<Self>>,
     ) -> impl IntoElement {
-        let customer = self.customers.get(row_ix).unwrap();
-
-        let col = self.columns.get(col_ix).unwrap();
-        match col.id.as_ref() {
-            "id" => customer.id.to_string().into_any_element(),
-            "login" => h_flex()
-                .items_center()
-                .gap_2()
-                .child(customer.render_avatar(cx))
-                .child(customer.login.clone())
-                .into_any_element(),
-            "first_name" => customer.first_name.clone().into_any_element(),
-            "last_name" => customer.last_name.clone().into_any_element(),
-            "company" => h_flex()
-                .items_center()
-                .justify_between()
-                .gap_1()
-                .child(customer.company.clone())
-                .child(IconName::Info)
-                .into_any_element(),
-            "city" => customer.city.clone().into_any_element(),
-            "country" => customer.country.clone().into_any_element(),
-            "email" => customer.email.clone().into_any_element(),
-            "phone" => customer.phone.clone().into_any_element(),
-            "gender" => match customer.gender {
-                0 => "Male",
-                1 => "Famale",
-                _ => "",
-            }
-            .into_any_element(),
-            "age" => customer.age.to_string().into_any_element(),
-            "verified" => match customer.verified {
-                true => "Yes".to_string().into_any_element(),
-                false => "No".to_string().into_any_element(),
-            },
-            "confirmed" => match customer.confirmed {
-                true => Icon::new(IconName::Check).size_4().into_any_element(),
-                false => div().into_any_element(),
-            },
-            _ => Label::new("--")
-                .text_color(cx.theme().muted_foreground)
-                .into_any_element(),
-        }
-    }
-
-    fn can_loop_select(&self) -> bool {
-        self.loop_selection
+        let Some(customer) = self.customers.get(row_ix) else {
+            return div();
+        };
+
+        div().child(
+            h_flex().gap_3().child(customer.render_avatar(cx)).child(
+                v_flex()
+                    .gap_1()
+                    .child(format!(
+                        "{} {} ({})",
+                        customer.first_name, customer.last_name, customer.login
+                    ))
+                    .child(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{}, {}", customer.city, customer.country)),
+                    )
+                    .child(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{} · {}", customer.email, customer.phone)),
+                    ),
+            ),
+        )
     }
 
     fn can_move_col(&self, _: usize) -> bool {
@@ -239,6 +356,15 @@ impl TableDelegate for CustomerTableDelegate {
         self.columns.insert(to_ix, col);
     }
 
+    fn can_move_row(&self, _: usize) -> bool {
+        self.row_order
+    }
+
+    fn move_row(&mut self, row_ix: usize, to_ix: usize) {
+        let customer = self.customers.remove(row_ix);
+        self.customers.insert(to_ix, customer);
+    }
+
     fn col_sort(&self, col_ix: usize) -> Option<ColSort> {
         if !self.col_sort {
             return None;
@@ -359,7 +485,14 @@ impl TableDelegate for CustomerTableDelegate {
 }
 
 pub struct TableStory {
+    focus_handle: FocusHandle,
     table: View<Table<CustomerTableDelegate>>,
+    search: View<SearchOverlay>,
+    /// The row indices of the customers that match the search query.
+    search_matches: Vec<usize>,
+    selected_row: Option<usize>,
+    /// The row to show the detail, it is a nested navigation of the story.
+    detail_row: Option<usize>,
 }
 
 impl TableStory {
@@ -373,7 +506,17 @@ impl TableStory {
 
         cx.subscribe(&table, Self::on_table_event).detach();
 
-        Self { table }
+        let view = cx.view().clone();
+        let search = cx.new_view(|cx| SearchOverlay::new(&view, cx));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            table,
+            search,
+            search_matches: vec![],
+            selected_row: None,
+            detail_row: None,
+        }
     }
 
     fn toggle_loop_selection(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
@@ -416,27 +559,182 @@ impl TableStory {
         });
     }
 
+    fn toggle_group_by_age(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().group_by_age = *checked;
+            cx.notify();
+        });
+    }
+
+    fn toggle_cell_focus(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().cell_focus = *checked;
+            cx.notify();
+        });
+    }
+
+    fn toggle_expand_rows(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().expand_rows = *checked;
+            cx.notify();
+        });
+    }
+
+    fn toggle_row_order(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().row_order = *checked;
+            cx.notify();
+        });
+    }
+
+    fn toggle_tree_rows(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().tree_rows = *checked;
+            cx.notify();
+        });
+    }
+
     fn on_table_event(
         &mut self,
         _: View<Table<CustomerTableDelegate>>,
         event: &TableEvent,
-        _cx: &mut ViewContext<Self>,
+        cx: &mut ViewContext<Self>,
     ) {
         match event {
             TableEvent::ColWidthsChanged(col_widths) => {
                 println!("Col widths changed: {:?}", col_widths)
             }
+            TableEvent::RowMoved(row_ix, to_ix) => println!("Row moved: {} -> {}", row_ix, to_ix),
             TableEvent::SelectCol(ix) => println!("Select col: {}", ix),
-            TableEvent::SelectRow(ix) => println!("Select row: {}", ix),
+            TableEvent::FocusCell(row_ix, col_ix) => {
+                println!("Focus cell: {}, {}", row_ix, col_ix);
+                self.selected_row = Some(*row_ix);
+                cx.notify();
+            }
+            TableEvent::SelectRow(ix) => {
+                println!("Select row: {}", ix);
+                self.selected_row = Some(*ix);
+                cx.notify();
+            }
         }
     }
+
+    fn render_detail(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let delegate = self.table.read(cx).delegate();
+        let Some(customer) = delegate.customers.get(row_ix) else {
+            return v_flex();
+        };
+
+        let fields = [
+            ("ID", customer.id.to_string()),
+            ("Login", customer.login.clone()),
+            ("First Name", customer.first_name.clone()),
+            ("Last Name", customer.last_name.clone()),
+            ("Company", customer.company.clone()),
+            ("City", customer.city.clone()),
+            ("Country", customer.country.clone()),
+            ("Email", customer.email.clone()),
+            ("Phone", customer.phone.clone()),
+            ("Age", customer.age.to_string()),
+        ];
+
+        v_flex()
+            .gap_2()
+            .child(customer.render_avatar(cx))
+            .children(fields.into_iter().map(|(name, value)| {
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w_24()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(name),
+                    )
+                    .child(value)
+            }))
+    }
+}
+
+impl Searchable for TableStory {
+    fn search(&mut self, query: &str, cx: &mut ViewContext<Self>) -> usize {
+        let query = query.to_lowercase();
+        self.search_matches = if query.is_empty() {
+            vec![]
+        } else {
+            self.table
+                .read(cx)
+                .delegate()
+                .customers
+                .iter()
+                .enumerate()
+                .filter(|(_, customer)| customer.contains(&query))
+                .map(|(ix, _)| ix)
+                .collect()
+        };
+        self.search_matches.len()
+    }
+
+    fn activate_match(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if let Some(&row_ix) = self.search_matches.get(ix) {
+            self.table
+                .update(cx, |table, cx| table.set_selected_row(row_ix, cx));
+        }
+    }
+}
+
+impl Story for TableStory {
+    fn breadcrumb(&self, cx: &WindowContext) -> Vec<SharedString> {
+        self.detail_row
+            .and_then(|ix| self.table.read(cx).delegate().customers.get(ix))
+            .map(|customer| vec![format!("{} {}", customer.first_name, customer.last_name).into()])
+            .unwrap_or_default()
+    }
+
+    fn navigate(&mut self, depth: usize, cx: &mut ViewContext<Self>) {
+        if depth == 0 {
+            self.detail_row = None;
+            cx.notify();
+        }
+    }
+
+    fn render_actions(&mut self, cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        if self.detail_row.is_some() || self.selected_row.is_none() {
+            return None;
+        }
+
+        Some(
+            Button::new("show-detail", cx)
+                .ghost()
+                .xsmall()
+                .label("Show Detail")
+                .on_click(cx.listener(|this, _, cx| {
+                    this.detail_row = this.selected_row;
+                    cx.notify();
+                }))
+                .into_any_element(),
+        )
+    }
 }
 
 impl Render for TableStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        if let Some(row_ix) = self.detail_row {
+            return self.render_detail(row_ix, cx).into_any_element();
+        }
+
         let delegate = self.table.read(cx).delegate();
 
         v_flex()
+            .track_focus(&self.focus_handle)
+            .relative()
+            .on_action(cx.listener(|this, _: &Find, cx| {
+                this.search.update(cx, |search, cx| search.show(cx))
+            }))
             .size_full()
             .gap_2()
             .child(
@@ -473,6 +771,36 @@ impl Render for TableStory {
                             .selected(delegate.col_selection)
                             .on_click(cx.listener(Self::toggle_col_selection)),
                     )
+                    .child(
+                        Checkbox::new("group-by-age")
+                            .label("Group by Age")
+                            .selected(delegate.group_by_age)
+                            .on_click(cx.listener(Self::toggle_group_by_age)),
+                    )
+                    .child(
+                        Checkbox::new("cell-focus")
+                            .label("Cell Focus")
+                            .selected(delegate.cell_focus)
+                            .on_click(cx.listener(Self::toggle_cell_focus)),
+                    )
+                    .child(
+                        Checkbox::new("expand-rows")
+                            .label("Expand Rows")
+                            .selected(delegate.expand_rows)
+                            .on_click(cx.listener(Self::toggle_expand_rows)),
+                    )
+                    .child(
+                        Checkbox::new("row-order")
+                            .label("Row Order")
+                            .selected(delegate.row_order)
+                            .on_click(cx.listener(Self::toggle_row_order)),
+                    )
+                    .child(
+                        Checkbox::new("tree-rows")
+                            .label("Tree Rows")
+                            .selected(delegate.tree_rows)
+                            .on_click(cx.listener(Self::toggle_tree_rows)),
+                    )
                     .when(delegate.loading, |this| {
                         this.child(h_flex().gap_1().child(Indicator::new()).child("Loading..."))
                     })
@@ -480,5 +808,7 @@ impl Render for TableStory {
                     .when(delegate.is_eof, |this| this.child("Is loaded all data.")),
             )
             .child(self.table.clone())
+            .child(self.search.clone())
+            .into_any_element()
     }
 }
@@ -1,19 +1,30 @@
 use gpui::{
-    div, px, rems, IntoElement, ParentElement, Render, Styled, View, ViewContext,
-    VisualContext as _, WindowContext,
+    div, prelude::FluentBuilder as _, px, rems, FocusHandle, InteractiveElement as _, IntoElement,
+    ParentElement, Render, SharedString, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
 };
 
 use ui::{
     button::{Button, ButtonStyle},
     checkbox::Checkbox,
     clipboard::Clipboard,
+    editable_label::{EditableLabel, EditableLabelEvent},
     h_flex,
-    label::Label,
+    label::{HighlightedLabel, Label},
     link::Link,
-    radio::Radio,
-    v_flex, Disableable as _, IconName, StyledExt,
+    radio::{Radio, RadioGroup},
+    search_overlay::{Find, SearchOverlay, Searchable},
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, FocusCycle, IconName, StyledExt,
 };
 
+const PARAGRAPHS: [&str; 4] = [
+    "GPUI is a hybrid immediate and retained mode, GPU accelerated, UI framework for Rust.",
+    "Press cmd-f (ctrl-f) to search the text of this panel, enter and shift-enter to navigate the matches.",
+    "The search overlay is hosted by the panel, and the panel highlights its own matches.",
+    "Any panel can implement the Searchable trait to be searched by the overlay.",
+];
+
 use crate::section;
 
 pub struct TextStory {
@@ -22,23 +33,49 @@ pub struct TextStory {
     check3: bool,
     radio_check1: bool,
     radio_check2: bool,
+    radio_group_ix: Option<usize>,
+    radio_group_focus: FocusCycle,
     masked: bool,
+    focus_handle: FocusHandle,
+    search: View<SearchOverlay>,
+    search_query: SharedString,
+    /// The indices of the paragraphs that match the search query.
+    search_matches: Vec<usize>,
+    active_match: Option<usize>,
+    editable_label: View<EditableLabel>,
 }
 
 impl TextStory {
-    pub(crate) fn new(_cx: &mut WindowContext) -> Self {
+    pub(crate) fn new(cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().clone();
+        let search = cx.new_view(|cx| SearchOverlay::new(&view, cx));
+        let editable_label = cx.new_view(|cx| EditableLabel::new("Untitled", cx));
+        cx.subscribe(&editable_label, |_, _, event: &EditableLabelEvent, _| {
+            let EditableLabelEvent::Rename(text) = event;
+            println!("Renamed to: {}", text);
+        })
+        .detach();
+
         Self {
             check1: false,
             check2: false,
             check3: true,
             radio_check1: false,
             radio_check2: true,
+            radio_group_ix: Some(0),
+            radio_group_focus: FocusCycle::new(3, cx),
             masked: false,
+            focus_handle: cx.focus_handle(),
+            search,
+            search_query: SharedString::default(),
+            search_matches: vec![],
+            active_match: None,
+            editable_label,
         }
     }
 
     pub fn view(cx: &mut WindowContext) -> View<Self> {
-        cx.new_view(|cx| Self::new(cx))
+        cx.new_view(Self::new)
     }
 
     #[allow(unused)]
@@ -47,10 +84,54 @@ impl TextStory {
     }
 }
 
+impl Searchable for TextStory {
+    fn search(&mut self, query: &str, cx: &mut ViewContext<Self>) -> usize {
+        let query_lower = query.to_lowercase();
+        self.search_query = query.to_string().into();
+        self.search_matches = if query.is_empty() {
+            vec![]
+        } else {
+            PARAGRAPHS
+                .iter()
+                .enumerate()
+                .filter(|(_, text)| text.to_lowercase().contains(&query_lower))
+                .map(|(ix, _)| ix)
+                .collect()
+        };
+        self.active_match = None;
+        cx.notify();
+        self.search_matches.len()
+    }
+
+    fn activate_match(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.active_match = self.search_matches.get(ix).copied();
+        cx.notify();
+    }
+}
+
 impl Render for TextStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         v_flex()
+            .track_focus(&self.focus_handle)
+            .relative()
+            .on_action(cx.listener(|this, _: &Find, cx| {
+                this.search.update(cx, |search, cx| search.show(cx))
+            }))
             .gap_6()
+            .child(
+                section("Find in Panel", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_2()
+                        .children(PARAGRAPHS.iter().enumerate().map(|(ix, text)| {
+                            let active = self.active_match == Some(ix);
+                            HighlightedLabel::with_query(*text, &self.search_query)
+                                .px_1()
+                                .rounded_md()
+                                .when(active, |this| this.bg(cx.theme().selection))
+                        })),
+                ),
+            )
             .child(
                 section("Label", cx)
                     .items_start()
@@ -75,6 +156,12 @@ impl Render for TextStory {
                                 .line_height(rems(1.8)),
                         ),
                     )
+                    .child(
+                        div().w(px(200.)).child(
+                            Label::new("This label is selectable, drag to select the text and press cmd-c to copy it.")
+                                .selectable("selectable-label"),
+                        ),
+                    )
 
             )
             .child(
@@ -88,6 +175,10 @@ impl Render for TextStory {
                         .child(div().w(px(250.)).child(Link::new("link4").child("https://github.com/huacnlee/gpui-component").href("https://github.com/huacnlee/gpui-component")))
                 )
             )
+            .child(
+                section("Editable Label", cx)
+                    .child(div().w(px(300.)).child(self.editable_label.clone())),
+            )
             .child(
                 section("Maksed Label", cx).child(
                     v_flex()
@@ -206,6 +297,20 @@ impl Render for TextStory {
                         )
                 ),
             )
+            .child(
+                section("Radio Group", cx).child(
+                    RadioGroup::new("radio_group")
+                        .focus_cycle(&self.radio_group_focus)
+                        .selected_index(self.radio_group_ix)
+                        .child(Radio::new("small").label("Small"))
+                        .child(Radio::new("medium").label("Medium"))
+                        .child(Radio::new("large").label("Large"))
+                        .on_change(cx.listener(|this, ix, cx| {
+                            this.radio_group_ix = Some(*ix);
+                            cx.notify();
+                        })),
+                ),
+            )
             .child(
                 section("Clipboard", cx).child(
                     h_flex()
@@ -225,5 +330,6 @@ impl Render for TextStory {
                         )
                 ),
             )
+            .child(self.search.clone())
     }
 }
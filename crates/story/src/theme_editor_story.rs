@@ -0,0 +1,139 @@
+use std::path::Path;
+
+use gpui::{
+    IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+
+use ui::{
+    button::Button,
+    color_picker::{ColorPicker, ColorPickerEvent},
+    file_dialog::{self, FileFilter},
+    h_flex,
+    label::Label,
+    notification::Notification,
+    theme::{ActiveTheme, Theme},
+    v_flex, ContextModal as _, Sizable as _,
+};
+
+/// Edit the color tokens of the current theme, and export them as a theme JSON file.
+pub struct ThemeEditorStory {
+    pickers: Vec<(&'static str, View<ColorPicker>)>,
+}
+
+impl ThemeEditorStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self { pickers: vec![] };
+        this.reload_pickers(cx);
+        this
+    }
+
+    /// Create the pickers with the colors of the current theme.
+    fn reload_pickers(&mut self, cx: &mut ViewContext<Self>) {
+        self.pickers = cx
+            .theme()
+            .colors()
+            .into_iter()
+            .map(|(name, color)| {
+                let picker = cx.new_view(|cx| ColorPicker::new(name, cx).value(color));
+                cx.subscribe(&picker, move |_, _, ev: &ColorPickerEvent, cx| match ev {
+                    ColorPickerEvent::Change(Some(color)) => {
+                        cx.global_mut::<Theme>().set_color(name, *color);
+                        cx.refresh();
+                    }
+                    ColorPickerEvent::Change(None) => {}
+                })
+                .detach();
+                (name, picker)
+            })
+            .collect();
+        cx.notify();
+    }
+
+    fn export(&mut self, cx: &mut ViewContext<Self>) {
+        let json = format!("{:#}", cx.theme().to_json());
+        let path =
+            file_dialog::save_file(Path::new("."), vec![FileFilter::new("Theme", ["json"])], cx);
+
+        cx.spawn(|_, mut cx| async move {
+            let Some(path) = path.await else {
+                return;
+            };
+
+            let note = match std::fs::write(&path, json) {
+                Ok(_) => Notification::success(format!("Exported to {}", path.display())),
+                Err(err) => Notification::error(format!("Failed to export: {}", err)),
+            };
+            let _ = cx.update(|cx| cx.push_notification(note));
+        })
+        .detach();
+    }
+
+    fn import(&mut self, cx: &mut ViewContext<Self>) {
+        let path = file_dialog::open_file(vec![FileFilter::new("Theme", ["json"])], cx);
+
+        cx.spawn(|this, mut cx| async move {
+            let Some(path) = path.await else {
+                return;
+            };
+
+            let _ = this.update(&mut cx, |this, cx| {
+                let result = std::fs::read_to_string(&path)
+                    .map_err(Into::into)
+                    .and_then(|json| Theme::load_json(&json, cx));
+
+                match result {
+                    Ok(_) => this.reload_pickers(cx),
+                    Err(err) => {
+                        cx.push_notification(Notification::error(format!(
+                            "Failed to import: {}",
+                            err
+                        )));
+                    }
+                }
+            });
+        })
+        .detach();
+    }
+}
+
+impl Render for ThemeEditorStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("import", cx)
+                            .small()
+                            .label("Import...")
+                            .on_click(cx.listener(|this, _, cx| this.import(cx))),
+                    )
+                    .child(
+                        Button::new("export", cx)
+                            .small()
+                            .label("Export...")
+                            .on_click(cx.listener(|this, _, cx| this.export(cx))),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .gap_y_2()
+                    .children(self.pickers.iter().map(|(name, picker)| {
+                        h_flex()
+                            .w_1_2()
+                            .pr_6()
+                            .gap_2()
+                            .justify_between()
+                            .child(Label::new(*name).text_sm())
+                            .child(picker.clone())
+                    })),
+            )
+    }
+}
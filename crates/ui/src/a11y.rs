@@ -0,0 +1,120 @@
+//! The accessibility metadata of the components, the role, the name and the state of an element
+//! to describe it to the assistive technologies, e.g.: the screen readers.
+//!
+//! The platform accessibility APIs are not connected yet, the metadata can be asserted in the tests:
+//!
+//! ```ignore
+//! let checkbox = Checkbox::new("remember").label("Remember me").checked(true);
+//! assert_eq!(
+//!     checkbox.a11y_node(),
+//!     A11yNode::new(Role::Checkbox).name("Remember me").checked(true)
+//! );
+//! ```
+use gpui::SharedString;
+
+/// The role of an element, what kind of the control it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Button,
+    Tab,
+    ListItem,
+    Checkbox,
+    Slider,
+}
+
+/// The value of a range control, e.g.: a [`crate::slider::Slider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct A11yValue {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// The accessibility metadata of an element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct A11yNode {
+    pub role: Role,
+    /// The name to announce, e.g.: the label of a button, `None` if the element has no text.
+    pub name: Option<SharedString>,
+    pub disabled: bool,
+    /// Whether the element is selected, `None` if it can not be selected.
+    pub selected: Option<bool>,
+    /// Whether the element is checked, `None` if it can not be checked.
+    pub checked: Option<bool>,
+    pub value: Option<A11yValue>,
+}
+
+impl A11yNode {
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            disabled: false,
+            selected: None,
+            checked: None,
+            value: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the optional name, e.g.: the optional label of a component.
+    pub(crate) fn name_opt(mut self, name: Option<SharedString>) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn value(mut self, value: f32, min: f32, max: f32) -> Self {
+        self.value = Some(A11yValue { value, min, max });
+        self
+    }
+}
+
+/// A component that describes itself to the assistive technologies.
+pub trait Accessible {
+    /// Return the accessibility metadata of the element.
+    fn a11y_node(&self) -> A11yNode;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{A11yNode, Accessible as _, Role};
+    use crate::{checkbox::Checkbox, tab::Tab, Selectable as _};
+
+    #[test]
+    fn test_a11y_node() {
+        let checkbox = Checkbox::new("remember").label("Remember me").checked(true);
+        assert_eq!(
+            checkbox.a11y_node(),
+            A11yNode::new(Role::Checkbox)
+                .name("Remember me")
+                .checked(true)
+        );
+
+        let tab = Tab::new("tab", "General")
+            .a11y_name("General")
+            .selected(true);
+        assert_eq!(
+            tab.a11y_node(),
+            A11yNode::new(Role::Tab).name("General").selected(true)
+        );
+    }
+}
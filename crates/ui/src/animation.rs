@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use gpui::{AppContext, Global};
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -17,3 +21,100 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
         y
     }
 }
+
+/// The global reduced motion preference.
+struct ReducedMotion {
+    reduced: bool,
+    /// Whether it is set by [`set_reduced_motion`], the detected preference will not override it.
+    explicit: bool,
+}
+
+impl Global for ReducedMotion {}
+
+pub(crate) fn init(cx: &mut AppContext) {
+    if !cx.has_global::<ReducedMotion>() {
+        cx.set_global(ReducedMotion {
+            reduced: false,
+            explicit: false,
+        });
+    }
+
+    // Detect it in the background, it runs a process that may be slow to start.
+    let detect = cx
+        .background_executor()
+        .spawn(async { system_prefers_reduced_motion() });
+    cx.spawn(|mut cx| async move {
+        let reduced = detect.await;
+        _ = cx.update(|cx| {
+            if !reduced || cx.global::<ReducedMotion>().explicit {
+                return;
+            }
+
+            cx.set_global(ReducedMotion {
+                reduced,
+                explicit: false,
+            });
+            cx.refresh();
+        });
+    })
+    .detach();
+}
+
+/// Set the reduced motion preference, when enabled the animations will be skipped.
+///
+/// Default is detected from the system accessibility settings where possible,
+/// it is detected in the background, the animations are not reduced until it is detected.
+pub fn set_reduced_motion(reduced: bool, cx: &mut AppContext) {
+    cx.set_global(ReducedMotion {
+        reduced,
+        explicit: true,
+    });
+    cx.refresh();
+}
+
+/// Returns true if the user prefers reduced motion.
+pub fn is_reduced_motion(cx: &AppContext) -> bool {
+    cx.try_global::<ReducedMotion>()
+        .map_or(false, |reduced| reduced.reduced)
+}
+
+/// Returns the animation duration respecting the reduced motion preference.
+///
+/// When reduced motion is enabled, this returns a tiny duration to jump to the final state.
+pub fn motion_duration(duration: Duration, cx: &AppContext) -> Duration {
+    if is_reduced_motion(cx) {
+        Duration::from_millis(1)
+    } else {
+        duration
+    }
+}
+
+/// Detect the reduced motion preference from the system accessibility settings.
+fn system_prefers_reduced_motion() -> bool {
+    let read = |program: &str, args: &[&str]| -> Option<String> {
+        let output = std::process::Command::new(program)
+            .args(args)
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    if cfg!(target_os = "macos") {
+        read(
+            "defaults",
+            &["read", "com.apple.universalaccess", "reduceMotion"],
+        )
+        .map_or(false, |value| value == "1")
+    } else if cfg!(target_os = "linux") {
+        read(
+            "gsettings",
+            &["get", "org.gnome.desktop.interface", "enable-animations"],
+        )
+        .map_or(false, |value| value == "false")
+    } else {
+        false
+    }
+}
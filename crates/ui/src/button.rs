@@ -1,9 +1,10 @@
 use crate::{
+    a11y::{A11yNode, Accessible, Role},
     h_flex,
     indicator::Indicator,
     theme::{ActiveTheme, Colorize as _},
     tooltip::Tooltip,
-    Disableable, Icon, Selectable, Sizable, Size,
+    Disableable, Icon, Selectable, Sizable, Size, StyledExt as _,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, relative, AnyElement, ClickEvent, Div, ElementId,
@@ -105,7 +106,7 @@ pub struct Button {
     icon: Option<Icon>,
     label: Option<SharedString>,
     children: Vec<AnyElement>,
-    disabled: bool,
+    pub(crate) disabled: bool,
     selected: bool,
     style: ButtonStyle,
     rounded: ButtonRounded,
@@ -233,6 +234,16 @@ impl Button {
     }
 }
 
+impl Accessible for Button {
+    /// The name is the label of the button, or the tooltip of the icon button.
+    fn a11y_node(&self) -> A11yNode {
+        A11yNode::new(Role::Button)
+            .name_opt(self.label.clone().or_else(|| self.tooltip.clone()))
+            .disabled(self.disabled)
+            .selected(self.selected)
+    }
+}
+
 impl Disableable for Button {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -270,7 +281,6 @@ impl RenderOnce for Button {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let style: ButtonStyle = self.style;
         let normal_style = style.normal(cx);
-        let focused = self.focus_handle.is_focused(cx);
         let icon_size = match self.size {
             Size::Size(v) => Size::Size(v * 0.75),
             _ => self.size,
@@ -341,7 +351,7 @@ impl RenderOnce for Button {
                             .text_color(active_style.fg)
                     })
             })
-            .when(focused, |this| this.border_color(cx.theme().ring))
+            .focus_ring(&self.focus_handle, cx)
             .when_some(
                 self.on_click.filter(|_| !self.disabled && !self.loading),
                 |this, on_click| {
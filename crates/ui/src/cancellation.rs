@@ -0,0 +1,190 @@
+//! A token to cancel the async work of a view, e.g.: the timers and the futures it spawned.
+//!
+//! Keep the [`DropGuard`] in the view, then the outstanding work is cancelled when the view is released.
+//!
+//! ```ignore
+//! struct MyView {
+//!     cancel: DropGuard,
+//! }
+//!
+//! let token = self.cancel.token();
+//! cx.spawn(|view, mut cx| async move {
+//!     if token.run(Timer::after(Duration::from_secs(5))).await.is_none() {
+//!         return;
+//!     }
+//!     ...
+//! })
+//! .detach();
+//! ```
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct Waiters {
+    next_id: usize,
+    /// The wakers of the pending [`Cancelled`] futures by their id, removed when the future is dropped.
+    wakers: HashMap<usize, Waker>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    waiters: Mutex<Waiters>,
+}
+
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the token, and wake up all the futures that are waiting for it.
+    pub fn cancel(&self) {
+        if self.0.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let wakers = std::mem::take(&mut self.0.waiters.lock().unwrap().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Return a future that is resolved when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            id: None,
+        }
+    }
+
+    /// Run the `future` until it is done or the token is cancelled, return `None` if cancelled.
+    pub async fn run<F: Future>(&self, future: F) -> Option<F::Output> {
+        smol::future::or(async { Some(future.await) }, async {
+            self.cancelled().await;
+            None
+        })
+        .await
+    }
+
+    /// Return a guard that cancels the token when it is dropped.
+    pub fn drop_guard(self) -> DropGuard {
+        DropGuard(self)
+    }
+}
+
+/// The future returned by [`CancellationToken::cancelled`].
+pub struct Cancelled {
+    token: CancellationToken,
+    /// The id of the registered waker, see [`Waiters`].
+    id: Option<usize>,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let inner = &this.token.0;
+        if inner.cancelled.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+
+        // Keep one waker for each future, it is replaced when polled again.
+        let mut waiters = inner.waiters.lock().unwrap();
+        let id = *this.id.get_or_insert_with(|| {
+            let id = waiters.next_id;
+            waiters.next_id += 1;
+            id
+        });
+        waiters.wakers.insert(id, cx.waker().clone());
+        drop(waiters);
+
+        // Check again, the token may be cancelled before the waker is registered.
+        if inner.cancelled.load(Ordering::SeqCst) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.token.0.waiters.lock().unwrap().wakers.remove(&id);
+        }
+    }
+}
+
+/// Cancel the token when it is dropped, e.g.: when the view that holds it is released.
+#[derive(Default)]
+pub struct DropGuard(CancellationToken);
+
+impl DropGuard {
+    pub fn token(&self) -> CancellationToken {
+        self.0.clone()
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancellationToken;
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+        assert_eq!(smol::block_on(token.run(async { 1 })), Some(1));
+
+        let guard = token.clone().drop_guard();
+        drop(guard);
+        assert!(token.is_cancelled());
+        assert_eq!(
+            smol::block_on(token.run(smol::future::pending::<()>())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_release_the_wakers() {
+        let token = CancellationToken::new();
+        // Each future is pending once, so the `cancelled` future registers its waker.
+        for _ in 0..100 {
+            assert_eq!(
+                smol::block_on(token.run(smol::future::yield_now())),
+                Some(())
+            );
+        }
+        assert!(token.0.waiters.lock().unwrap().wakers.is_empty());
+
+        let mut cancelled = Box::pin(token.cancelled());
+        for _ in 0..10 {
+            let poll = smol::future::poll_once(cancelled.as_mut());
+            assert_eq!(smol::block_on(poll), None);
+        }
+        assert_eq!(token.0.waiters.lock().unwrap().wakers.len(), 1);
+        drop(cancelled);
+        assert!(token.0.waiters.lock().unwrap().wakers.is_empty());
+    }
+}
@@ -1,13 +1,14 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, svg, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled as _,
-    WindowContext,
+    div, prelude::FluentBuilder as _, relative, svg, ElementId, FocusHandle, InteractiveElement,
+    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    Styled as _, WindowContext,
 };
 
 use crate::{
+    a11y::{A11yNode, Accessible, Role},
     h_flex,
     theme::{ActiveTheme, Colorize as _},
-    v_flex, Disableable, IconName, Selectable,
+    v_flex, Disableable, IconName, Selectable, StyledExt as _,
 };
 
 #[derive(IntoElement)]
@@ -16,6 +17,7 @@ pub struct Checkbox {
     checked: bool,
     disabled: bool,
     label: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
@@ -26,6 +28,7 @@ impl Checkbox {
             checked: false,
             disabled: false,
             label: None,
+            focus_handle: None,
             on_click: None,
         }
     }
@@ -40,12 +43,27 @@ impl Checkbox {
         self
     }
 
+    /// Set the focus handle to track the focus, and show the focus ring when it focused by keyboard.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
         self.on_click = Some(Box::new(handler));
         self
     }
 }
 
+impl Accessible for Checkbox {
+    fn a11y_node(&self) -> A11yNode {
+        A11yNode::new(Role::Checkbox)
+            .name_opt(self.label.clone())
+            .disabled(self.disabled)
+            .checked(self.checked)
+    }
+}
+
 impl Disableable for Checkbox {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -74,7 +92,7 @@ impl RenderOnce for Checkbox {
             (theme.primary, theme.primary_foreground)
         };
 
-        h_flex()
+        let checkbox = h_flex()
             .id(self.id)
             .group(group_id.clone())
             .gap_2()
@@ -87,6 +105,9 @@ impl RenderOnce for Checkbox {
                     .rounded_sm()
                     .size_4()
                     .flex_shrink_0()
+                    .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                        this.focus_ring(focus_handle, cx)
+                    })
                     .map(|this| match self.checked {
                         false => this.bg(theme.transparent),
                         _ => this.bg(color),
@@ -133,6 +154,11 @@ impl RenderOnce for Checkbox {
                         cx.refresh()
                     })
                 },
-            )
+            );
+
+        match self.focus_handle {
+            Some(focus_handle) => checkbox.track_focus(&focus_handle).into_any_element(),
+            None => checkbox.into_any_element(),
+        }
     }
 }
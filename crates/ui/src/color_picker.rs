@@ -1,8 +1,7 @@
 use gpui::{
     anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
     FocusHandle, FocusableView, Hsla, InteractiveElement as _, IntoElement, KeyBinding, Length,
-    MouseButton, ParentElement, Render, SharedString, StatefulInteractiveElement as _, Styled,
-    ViewContext,
+    ParentElement, Render, SharedString, StatefulInteractiveElement as _, Styled, ViewContext,
 };
 
 use crate::{
@@ -10,6 +9,7 @@ use crate::{
     divider::Divider,
     h_flex,
     input::ClearButton,
+    overlay::OverlayExt as _,
     popover::Escape,
     theme::{ActiveTheme as _, Colorize},
     v_flex, ColorExt as _, Icon, IconName, Size, StyleSized as _, StyledExt as _,
@@ -291,10 +291,7 @@ impl Render for ColorPicker {
                                 .shadow_lg()
                                 .rounded_lg()
                                 .bg(cx.theme().background)
-                                .on_mouse_up_out(
-                                    MouseButton::Left,
-                                    cx.listener(|view, _, cx| view.escape(&Escape, cx)),
-                                )
+                                .on_dismiss(cx.listener(|view, _, cx| view.escape(&Escape, cx)))
                                 .child(self.render_colors(cx)),
                         ),
                     )
@@ -208,7 +208,15 @@ impl Element for ContextMenu {
                             PopupMenu::build(cx, |menu, cx| (builder)(menu, cx)).into_element();
 
                         let open = open.clone();
-                        cx.subscribe(&menu, move |_, _: &DismissEvent, cx| {
+                        let previous_focus_handle = cx.focused();
+                        cx.subscribe(&menu, move |menu, _: &DismissEvent, cx| {
+                            // Focus back when the menu is dismissed by itself, not by the blur.
+                            if menu.focus_handle(cx).contains_focused(cx) {
+                                if let Some(previous_focus_handle) = previous_focus_handle.as_ref()
+                                {
+                                    cx.focus(previous_focus_handle);
+                                }
+                            }
                             *open.borrow_mut() = false;
                             cx.refresh();
                         })
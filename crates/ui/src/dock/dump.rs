@@ -0,0 +1,258 @@
+use std::fmt;
+
+use gpui::{Axis, Pixels, SharedString};
+use serde_json::json;
+
+/// A deterministic snapshot of the dock layout tree, see [`super::DockArea::dump`].
+///
+/// Use the `Display` (text) or [`DockNode::to_json`] output to snapshot-verify
+/// the split and merge operations in tests.
+///
+/// It is also converted to the [`super::DockLayout`] to persist the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DockNode {
+    /// A StackPanel with the children and their sizes along the axis.
+    Stack {
+        axis: Axis,
+        children: Vec<(Pixels, DockNode)>,
+    },
+    /// A TabPanel with the tab titles.
+    Tabs {
+        active_ix: usize,
+        titles: Vec<SharedString>,
+        /// The keys of the panels to persist them, see [`super::Panel::panel_key`].
+        keys: Vec<Option<SharedString>>,
+        zoomed: bool,
+        /// The number of the pinned panels at the start of the tabs.
+        pinned_count: usize,
+    },
+    /// Any other panel.
+    Panel { title: SharedString },
+}
+
+fn axis_name(axis: Axis) -> &'static str {
+    match axis {
+        Axis::Horizontal => "horizontal",
+        Axis::Vertical => "vertical",
+    }
+}
+
+impl DockNode {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            DockNode::Stack { axis, children } => json!({
+                "type": "stack",
+                "axis": axis_name(*axis),
+                "children": children
+                    .iter()
+                    .map(|(size, node)| {
+                        let mut value = node.to_json();
+                        value["size"] = json!(size.0.round());
+                        value
+                    })
+                    .collect::<Vec<_>>(),
+            }),
+            DockNode::Tabs {
+                active_ix, titles, ..
+            } => json!({
+                "type": "tabs",
+                "active_ix": active_ix,
+                "titles": titles.iter().map(|title| title.to_string()).collect::<Vec<_>>(),
+            }),
+            DockNode::Panel { title } => json!({
+                "type": "panel",
+                "title": title.to_string(),
+            }),
+        }
+    }
+
+    fn fmt_with_indent(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = "  ".repeat(indent);
+        match self {
+            DockNode::Stack { axis, children } => {
+                writeln!(f, "{}Stack({})", pad, axis_name(*axis))?;
+                for (size, node) in children {
+                    writeln!(f, "{}  size: {}", pad, size.0.round())?;
+                    node.fmt_with_indent(f, indent + 1)?;
+                }
+                Ok(())
+            }
+            DockNode::Tabs {
+                active_ix, titles, ..
+            } => {
+                writeln!(f, "{}Tabs", pad)?;
+                for (ix, title) in titles.iter().enumerate() {
+                    let marker = if ix == *active_ix { "*" } else { "-" };
+                    writeln!(f, "{}  {} {}", pad, marker, title)?;
+                }
+                Ok(())
+            }
+            DockNode::Panel { title } => writeln!(f, "{}Panel({})", pad, title),
+        }
+    }
+}
+
+impl fmt::Display for DockNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_indent(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gpui::{
+        div, px, AppContext, Axis, EventEmitter, FocusHandle, FocusableView, IntoElement, Render,
+        SharedString, TestAppContext, View, ViewContext, VisualContext as _, VisualTestContext,
+        WindowContext,
+    };
+
+    use super::DockNode;
+    use crate::{
+        dock::{
+            tab_panel::{DragPanel, TabPanel},
+            DockArea, Panel, PanelEvent, PanelView, StackPanel,
+        },
+        Placement,
+    };
+
+    struct TestPanel {
+        title: SharedString,
+        focus_handle: FocusHandle,
+    }
+
+    impl EventEmitter<PanelEvent> for TestPanel {}
+
+    impl FocusableView for TestPanel {
+        fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+            self.focus_handle.clone()
+        }
+    }
+
+    impl Panel for TestPanel {
+        fn title(&self, _: &WindowContext) -> SharedString {
+            self.title.clone()
+        }
+    }
+
+    impl Render for TestPanel {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div()
+        }
+    }
+
+    fn new_panel(title: &'static str, cx: &mut WindowContext) -> Arc<dyn PanelView> {
+        Arc::new(cx.new_view(|cx| TestPanel {
+            title: title.into(),
+            focus_handle: cx.focus_handle(),
+        }))
+    }
+
+    /// Return the text dump of the dock area without the sizes, they depend on the window size.
+    fn dump(dock_area: &View<DockArea>, cx: &mut VisualTestContext) -> String {
+        cx.run_until_parked();
+        let text = dock_area.update(cx, |view, cx| view.dump(cx).to_string());
+        text.lines()
+            .filter(|line| !line.trim_start().starts_with("size:"))
+            .map(|line| format!("{}\n", line))
+            .collect()
+    }
+
+    fn tree() -> DockNode {
+        DockNode::Stack {
+            axis: Axis::Horizontal,
+            children: vec![
+                (
+                    px(200.),
+                    DockNode::Tabs {
+                        active_ix: 1,
+                        titles: vec!["A".into(), "B".into()],
+                        keys: vec![None, None],
+                        zoomed: false,
+                        pinned_count: 0,
+                    },
+                ),
+                (
+                    px(300.4),
+                    DockNode::Stack {
+                        axis: Axis::Vertical,
+                        children: vec![(px(100.), DockNode::Panel { title: "C".into() })],
+                    },
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_dump_text() {
+        assert_eq!(
+            tree().to_string(),
+            "Stack(horizontal)\n  size: 200\n  Tabs\n    - A\n    * B\n  size: 300\n  Stack(vertical)\n    size: 100\n    Panel(C)\n"
+        );
+    }
+
+    #[test]
+    fn test_dump_json() {
+        let value = tree().to_json();
+        assert_eq!(value["axis"], "horizontal");
+        assert_eq!(value["children"][0]["type"], "tabs");
+        assert_eq!(value["children"][0]["active_ix"], 1);
+        assert_eq!(value["children"][1]["size"], 300.0);
+        assert_eq!(value["children"][1]["children"][0]["title"], "C");
+    }
+
+    #[gpui::test]
+    fn test_dump_after_split_merge_and_close(cx: &mut TestAppContext) {
+        let (dock_area, cx) = cx.add_window_view(|cx| {
+            let root = cx.new_view(|cx| StackPanel::new(Axis::Horizontal, cx));
+            DockArea::new(root, cx)
+        });
+        let (left, right, [a, b, c]) = dock_area.update(cx, |dock_area, cx| {
+            let weak_dock_area = cx.view().downgrade();
+            let left = cx.new_view(|cx| TabPanel::new(weak_dock_area.clone(), cx));
+            let right = cx.new_view(|cx| TabPanel::new(weak_dock_area.clone(), cx));
+            dock_area.root.update(cx, |view, cx| {
+                view.add_panel(left.clone(), Some(px(400.)), weak_dock_area.clone(), cx);
+                view.add_panel(right.clone(), Some(px(400.)), weak_dock_area, cx);
+            });
+
+            let panels = ["A", "B", "C"].map(|title| new_panel(title, cx));
+            left.update(cx, |view, cx| {
+                view.add_panel(panels[0].clone(), cx);
+                view.add_panel(panels[1].clone(), cx);
+            });
+            right.update(cx, |view, cx| view.add_panel(panels[2].clone(), cx));
+            (left, right, panels)
+        });
+        assert_eq!(
+            dump(&dock_area, cx),
+            "Stack(horizontal)\n  Tabs\n    - A\n    * B\n  Tabs\n    * C\n"
+        );
+
+        // Split A to the right of C.
+        let drag = DragPanel::new(a, left.clone());
+        right.update(cx, |view, cx| {
+            view.simulate_drop(&drag, Some(Placement::Right), None, cx)
+        });
+        assert_eq!(
+            dump(&dock_area, cx),
+            "Stack(horizontal)\n  Tabs\n    * B\n  Tabs\n    * C\n  Tabs\n    * A\n"
+        );
+
+        // Merge C into the tabs of B, the empty tabs are removed.
+        let drag = DragPanel::new(c.clone(), right);
+        left.update(cx, |view, cx| view.simulate_drop(&drag, None, None, cx));
+        assert_eq!(
+            dump(&dock_area, cx),
+            "Stack(horizontal)\n  Tabs\n    - B\n    * C\n  Tabs\n    * A\n"
+        );
+
+        // Close B and C, the empty tabs are removed.
+        left.update(cx, |view, cx| {
+            view.remove_panel(b, cx);
+            view.remove_panel(c, cx);
+        });
+        assert_eq!(dump(&dock_area, cx), "Stack(horizontal)\n  Tabs\n    * A\n");
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use gpui::{AnyWindowHandle, Subscription, ViewContext, WindowContext};
+
+type Listener = Rc<dyn Fn(&dyn Any, &mut WindowContext)>;
+
+#[derive(Default)]
+struct Listeners {
+    next_id: usize,
+    /// The listeners with the window they subscribed in.
+    by_type: HashMap<TypeId, Vec<(usize, AnyWindowHandle, Listener)>>,
+}
+
+/// A typed publish/subscribe bus for the cross-panel communication, see [`super::DockArea::event_bus`].
+///
+/// The panels can broadcast the domain events (e.g.: selection changed) to the other panels
+/// of the same DockArea, without holding the direct View references to each other.
+/// The listeners are called in the window they subscribed in, e.g.: a floating window.
+///
+/// ```ignore
+/// struct SelectionChanged(usize);
+///
+/// // In the subscriber panel
+/// self._subscriptions.push(event_bus.subscribe_in(cx, |this, event: &SelectionChanged, cx| {
+///     this.selected_ix = event.0;
+///     cx.notify();
+/// }));
+///
+/// // In the publisher panel
+/// event_bus.publish(SelectionChanged(ix), cx);
+/// ```
+#[derive(Clone, Default)]
+pub struct EventBus {
+    listeners: Rc<RefCell<Listeners>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe to the events of type `E` in the current window,
+    /// the listener is removed when the subscription dropped.
+    pub fn subscribe<E: 'static>(
+        &self,
+        cx: &mut WindowContext,
+        f: impl Fn(&E, &mut WindowContext) + 'static,
+    ) -> Subscription {
+        let type_id = TypeId::of::<E>();
+        let listener: Listener = Rc::new(move |event, cx| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                f(event, cx)
+            }
+        });
+
+        let id = {
+            let mut listeners = self.listeners.borrow_mut();
+            let id = listeners.next_id;
+            listeners.next_id += 1;
+            listeners
+                .by_type
+                .entry(type_id)
+                .or_default()
+                .push((id, cx.window_handle(), listener));
+            id
+        };
+
+        let listeners: Weak<RefCell<Listeners>> = Rc::downgrade(&self.listeners);
+        Subscription::new(move || {
+            if let Some(listeners) = listeners.upgrade() {
+                if let Some(items) = listeners.borrow_mut().by_type.get_mut(&type_id) {
+                    items.retain(|(item_id, _, _)| *item_id != id);
+                }
+            }
+        })
+    }
+
+    /// Subscribe to the events of type `E` in a view, the listener will not be called
+    /// after the view released.
+    ///
+    /// NOTE: Do not publish the event to the same view while it is being updated.
+    pub fn subscribe_in<V: 'static, E: 'static>(
+        &self,
+        cx: &mut ViewContext<V>,
+        f: impl Fn(&mut V, &E, &mut ViewContext<V>) + 'static,
+    ) -> Subscription {
+        let view = cx.view().downgrade();
+        self.subscribe(cx, move |event: &E, cx| {
+            _ = view.update(cx, |this, cx| f(this, event, cx));
+        })
+    }
+
+    /// Publish the event to all subscribers of type `E`, each one in the window it subscribed in.
+    pub fn publish<E: 'static>(&self, event: E, cx: &mut WindowContext) {
+        // Clone the listeners, so that the listeners can subscribe or unsubscribe while publishing.
+        let listeners = self
+            .listeners
+            .borrow()
+            .by_type
+            .get(&TypeId::of::<E>())
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|(_, window, listener)| (*window, listener.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let current_window = cx.window_handle();
+        for (window, listener) in listeners {
+            if window == current_window {
+                listener(&event, cx);
+            } else {
+                // Skip the closed window, its subscriptions are dropped with its views.
+                _ = window.update(cx, |_, cx| listener(&event, cx));
+            }
+        }
+    }
+}
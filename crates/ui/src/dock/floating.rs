@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use gpui::{
+    canvas, point, px, AnyElement, AnyWindowHandle, AppContext, Bounds, EntityId, IntoElement,
+    MouseUpEvent, Pixels, Point, Size, Styled as _, View, ViewContext, VisualContext as _,
+    WeakView, WindowBounds, WindowKind, WindowOptions,
+};
+
+use crate::Root;
+
+use super::{notify_layout, DockArea, DragPanel, PanelView, StackPanel, TabPanel};
+
+/// The default size of a floating window.
+const FLOATING_SIZE: Size<Pixels> = Size {
+    width: px(480.),
+    height: px(360.),
+};
+
+/// A panel torn off from the tabs into its own window, it is docked back when the window is closed,
+/// or when its tab is dragged back onto a tab panel of the dock area.
+pub(super) struct FloatingPanel {
+    pub(super) tab_panel: View<TabPanel>,
+    window: AnyWindowHandle,
+}
+
+/// Return the bounds of the window torn off at the `position` in the screen, the tabs are under the mouse.
+fn floating_bounds(position: Point<Pixels>) -> Bounds<Pixels> {
+    Bounds {
+        origin: position - point(FLOATING_SIZE.width / 2., px(12.)),
+        size: FLOATING_SIZE,
+    }
+}
+
+/// Update the DockArea in its own window, after the current update.
+///
+/// The caller may be in a floating window, or be updated by the DockArea (e.g.: the TabPanel).
+pub(super) fn defer_update_dock_area(
+    dock_area: WeakView<DockArea>,
+    cx: &mut AppContext,
+    f: impl FnOnce(&mut DockArea, &mut ViewContext<DockArea>) + 'static,
+) {
+    cx.defer(move |cx| {
+        let Some(dock_area) = dock_area.upgrade() else {
+            return;
+        };
+        let window = dock_area.read(cx).window;
+        _ = window.update(cx, |_, cx| dock_area.update(cx, f));
+    });
+}
+
+impl DockArea {
+    /// Tear off the `panel` from its tabs into a new window.
+    ///
+    /// The window is opened at the `position` in the screen, or centered if it is `None`.
+    pub(super) fn float_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        from: View<TabPanel>,
+        position: Option<Point<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.push_layout_undo(None, cx);
+        from.update(cx, |view, cx| view.remove_panel(panel.clone(), cx));
+
+        let dock_area = cx.view().downgrade();
+        let tab_panel = cx.new_view(|cx| TabPanel::new(dock_area, cx));
+        tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+        self.open_floating_window(tab_panel, position, cx);
+    }
+
+    /// Open a new window for the floating `tab_panel`, see [`DockArea::float_panel`].
+    pub(super) fn open_floating_window(
+        &mut self,
+        tab_panel: View<TabPanel>,
+        position: Option<Point<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let dock_area = cx.view().downgrade();
+        tab_panel.update(cx, |view, _| view.is_floating = true);
+        let bounds = match position {
+            Some(position) => floating_bounds(position),
+            None => Bounds::centered(None, FLOATING_SIZE, cx),
+        };
+        let options = WindowOptions {
+            window_bounds: Some(WindowBounds::Windowed(bounds)),
+            kind: WindowKind::Normal,
+            ..Default::default()
+        };
+        let root_panel = tab_panel.clone();
+        let window = match cx.open_window(options, |cx| {
+            cx.new_view(|cx| Root::new(root_panel.into(), cx))
+        }) {
+            Ok(window) => window,
+            Err(_) => {
+                // Keep the panel in the dock area, if the window can't be opened.
+                tab_panel.update(cx, |view, _| view.is_floating = false);
+                self.root.update(cx, |view, cx| {
+                    view.add_panel(tab_panel, None, dock_area, cx)
+                });
+                notify_layout(cx);
+                return;
+            }
+        };
+
+        let tab_panel_id = tab_panel.entity_id();
+        _ = window.update(cx, |_, cx| {
+            cx.activate_window();
+            // Dock the panels back instead of closing them with the window.
+            cx.on_window_should_close(move |cx| {
+                defer_update_dock_area(dock_area.clone(), cx, move |dock_area, cx| {
+                    dock_area.dock_floating_panel(tab_panel_id, cx)
+                });
+                true
+            });
+        });
+
+        self.floating_panels.push(FloatingPanel {
+            tab_panel,
+            window: window.into(),
+        });
+        notify_layout(cx);
+    }
+
+    /// Move the floating panel back into the dock area, it is split on the right of the root.
+    pub(super) fn dock_floating_panel(
+        &mut self,
+        tab_panel_id: EntityId,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ix) = self
+            .floating_panels
+            .iter()
+            .position(|floating| floating.tab_panel.entity_id() == tab_panel_id)
+        else {
+            return;
+        };
+
+        let floating = self.floating_panels.remove(ix);
+        close_window(floating.window, cx);
+        self.push_layout_undo(None, cx);
+
+        let dock_area = cx.view().downgrade();
+        let tab_panel = floating.tab_panel;
+        tab_panel.update(cx, |view, _| view.is_floating = false);
+        self.root.update(cx, |view, cx| {
+            view.add_panel(tab_panel, None, dock_area, cx)
+        });
+        notify_layout(cx);
+    }
+
+    /// Close the windows of all the floating panels, e.g.: to load another layout.
+    pub(super) fn close_floating_panels(&mut self, cx: &mut ViewContext<Self>) {
+        for floating in self.floating_panels.drain(..) {
+            close_window(floating.window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Remove the floating panel and close its window, e.g.: its last panel is closed.
+    pub(super) fn remove_floating_panel(
+        &mut self,
+        tab_panel_id: EntityId,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ix) = self
+            .floating_panels
+            .iter()
+            .position(|floating| floating.tab_panel.entity_id() == tab_panel_id)
+        else {
+            return;
+        };
+
+        let floating = self.floating_panels.remove(ix);
+        close_window(floating.window, cx);
+        cx.notify();
+    }
+
+    /// Dock the panel dragged out of a floating window, into the tab panel at the `position` in the screen.
+    ///
+    /// The panel stays in the floating window if it is not dropped on a tab panel of the dock area.
+    pub(super) fn dock_dragged_panel(
+        &mut self,
+        drag: DragPanel,
+        position: Point<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let position = position - cx.bounds().origin;
+        let Some(tab_panel) = self.tab_panel_at(position, cx) else {
+            return;
+        };
+
+        cx.activate_window();
+        tab_panel.update(cx, |view, cx| view.on_drop(&drag, None, cx));
+    }
+
+    /// Return the visible tab panel at the `position` in the window of the dock area.
+    fn tab_panel_at(&self, position: Point<Pixels>, cx: &AppContext) -> Option<View<TabPanel>> {
+        if !self.bounds.get().contains(&position) {
+            return None;
+        }
+
+        let contains =
+            |tab_panel: &View<TabPanel>| tab_panel.read(cx).bounds.get().contains(&position);
+        if let Some(zoomed) = self.zoomed_panel() {
+            return zoomed.downcast::<TabPanel>().ok().filter(contains);
+        }
+
+        self.docks
+            .iter()
+            .filter(|dock| dock.open)
+            .map(|dock| dock.tab_panel.clone())
+            .find(contains)
+            .or_else(|| self.root.read(cx).find_tab_panel_at(position, cx))
+    }
+
+    /// Render the handler to tear off the panel that dropped outside of the dock area.
+    pub(super) fn render_tear_off(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let view = cx.view().clone();
+        let dock_bounds = self.bounds.clone();
+
+        canvas(
+            move |bounds, _| {
+                dock_bounds.set(bounds);
+                bounds
+            },
+            move |_, bounds, cx| {
+                cx.on_mouse_event(move |event: &MouseUpEvent, phase, cx| {
+                    // The drop is handled in the bubble phase, check it before that.
+                    let Some(drag) = view.read(cx).dragging_panel.clone() else {
+                        return;
+                    };
+                    if phase.bubble() {
+                        view.update(cx, |view, _| view.dragging_panel = None);
+                    } else if !bounds.contains(&event.position) {
+                        let position = cx.bounds().origin + event.position;
+                        view.update(cx, |view, cx| {
+                            view.float_panel(drag.panel, drag.tab_panel, Some(position), cx)
+                        });
+                    }
+                });
+            },
+        )
+        .absolute()
+        .size_full()
+        .into_any_element()
+    }
+}
+
+impl StackPanel {
+    /// Find the tab panel at the `position` in the window, in this stack and the nested stacks.
+    fn find_tab_panel_at(
+        &self,
+        position: Point<Pixels>,
+        cx: &AppContext,
+    ) -> Option<View<TabPanel>> {
+        self.panels.iter().find_map(|panel| {
+            let view = panel.view();
+            if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+                let contains = tab_panel.read(cx).bounds.get().contains(&position);
+                contains.then_some(tab_panel)
+            } else if let Ok(stack_panel) = view.downcast::<Self>() {
+                stack_panel.read(cx).find_tab_panel_at(position, cx)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Close the floating window after the current update, it may be the window being updated.
+fn close_window(window: AnyWindowHandle, cx: &mut AppContext) {
+    cx.defer(move |cx| {
+        _ = window.update(cx, |_, cx| cx.remove_window());
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px};
+
+    use super::{floating_bounds, FLOATING_SIZE};
+
+    #[test]
+    fn test_floating_bounds() {
+        let bounds = floating_bounds(point(px(500.), px(300.)));
+        assert_eq!(bounds.size, FLOATING_SIZE);
+        assert_eq!(bounds.origin, point(px(260.), px(288.)));
+    }
+}
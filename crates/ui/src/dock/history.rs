@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use gpui::{AppContext, Axis, EntityId, Pixels};
+
+use super::{PanelView, StackPanel, TabPanel};
+
+/// The max number of the layout changes to undo.
+pub(super) const MAX_LAYOUT_HISTORY: usize = 50;
+
+/// A snapshot of the dock layout tree, that holds the panels to restore them later.
+#[derive(Clone)]
+pub(super) enum LayoutSnapshot {
+    Stack {
+        axis: Axis,
+        /// The children with their sizes, `None` to use the default size.
+        children: Vec<(Option<Pixels>, LayoutSnapshot)>,
+    },
+    Tabs {
+        active_ix: usize,
+        zoomed: bool,
+        /// The number of the pinned panels at the start of the `panels`.
+        pinned_count: usize,
+        panels: Vec<Arc<dyn PanelView>>,
+    },
+    /// Any other panel, it can't be restored.
+    Panel,
+}
+
+impl LayoutSnapshot {
+    /// Return the entity ids of the panels in the tabs.
+    pub(super) fn panel_ids(&self) -> Vec<EntityId> {
+        match self {
+            LayoutSnapshot::Stack { children, .. } => children
+                .iter()
+                .flat_map(|(_, child)| child.panel_ids())
+                .collect(),
+            LayoutSnapshot::Tabs { panels, .. } => panels
+                .iter()
+                .map(|panel| panel.view().entity_id())
+                .collect(),
+            LayoutSnapshot::Panel => vec![],
+        }
+    }
+}
+
+impl StackPanel {
+    pub(super) fn snapshot(&self, cx: &AppContext) -> LayoutSnapshot {
+        self.snapshot_with(None, cx)
+    }
+
+    /// Take the snapshot with the snapshot of the `updating` TabPanel,
+    /// the TabPanel can't be read while it is being updated, e.g.: when it closes a panel.
+    pub(super) fn snapshot_with(
+        &self,
+        updating: Option<(EntityId, &LayoutSnapshot)>,
+        cx: &AppContext,
+    ) -> LayoutSnapshot {
+        let sizes = self.sizes(cx);
+        let children = self
+            .panels
+            .iter()
+            .enumerate()
+            .map(|(ix, panel)| {
+                let view = panel.view();
+                let child = match updating {
+                    Some((id, snapshot)) if id == view.entity_id() => snapshot.clone(),
+                    _ => {
+                        if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+                            tab_panel.read(cx).snapshot()
+                        } else if let Ok(stack_panel) = view.downcast::<Self>() {
+                            stack_panel.read(cx).snapshot_with(updating, cx)
+                        } else {
+                            LayoutSnapshot::Panel
+                        }
+                    }
+                };
+                (sizes.get(ix).copied(), child)
+            })
+            .collect();
+
+        LayoutSnapshot::Stack {
+            axis: self.axis,
+            children,
+        }
+    }
+}
+
+impl TabPanel {
+    pub(super) fn snapshot(&self) -> LayoutSnapshot {
+        LayoutSnapshot::Tabs {
+            active_ix: self.active_ix,
+            zoomed: self.is_zoomed,
+            pinned_count: self.pinned_count,
+            panels: self.panels.clone(),
+        }
+    }
+}
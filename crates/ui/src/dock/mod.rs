@@ -1,54 +1,676 @@
+mod dump;
+mod event_bus;
+mod floating;
+mod history;
+mod navigation;
 mod panel;
+mod panel_header;
 mod stack_panel;
+mod state;
 mod tab_panel;
+mod zone;
 
+use floating::{defer_update_dock_area, FloatingPanel};
+use history::{LayoutSnapshot, MAX_LAYOUT_HISTORY};
+use navigation::NavigationHistory;
+use zone::{Dock, DragDockResize};
+
+pub use dump::*;
+pub use event_bus::*;
 use gpui::{
-    actions, div, prelude::FluentBuilder, AnyWeakView, InteractiveElement as _, IntoElement,
-    ParentElement as _, Render, Styled, View, ViewContext,
+    actions, div, impl_actions, prelude::FluentBuilder, px, AnyView, AnyWeakView, AnyWindowHandle,
+    AppContext, Axis, Bounds, DragMoveEvent, EntityId, EventEmitter, FocusableView as _, Global,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, NavigationDirection,
+    ParentElement as _, Pixels, Render, Styled, View, ViewContext, WindowContext,
 };
 pub use panel::*;
+pub use panel_header::*;
 pub use stack_panel::*;
+pub use state::*;
 pub use tab_panel::*;
+pub use zone::DockPosition;
+
+use std::{cell::Cell, rc::Rc, sync::Arc, time::Duration};
+
+use crate::util::{debounce, Debounce};
+
+actions!(
+    dock,
+    [
+        ToggleZoom,
+        ClosePanel,
+        UndoLayoutChange,
+        RedoLayoutChange,
+        GoBack,
+        GoForward,
+        TogglePinTab,
+        CloseOtherTabs,
+        CloseTabsToRight,
+        CloseAllTabs,
+        ActivateNextTab,
+        ActivatePrevTab,
+        ToggleLeftDock,
+        ToggleRightDock,
+        ToggleBottomDock,
+        ExitZoom,
+        DistributeEvenly,
+        MoveToNewWindow,
+        MoveBackToDock
+    ]
+);
+
+/// Activate the tab at the index of the focused TabPanel.
+#[derive(Clone, PartialEq, serde::Deserialize)]
+pub struct ActivateTab(pub usize);
+
+impl_actions!(dock, [ActivateTab]);
+
+/// The default split ratio of the dropped panel, see [`DockArea::set_split_ratio`].
+pub(crate) const DEFAULT_SPLIT_RATIO: f32 = 0.5;
+
+/// The default min size of a panel, a split is vetoed if it makes a panel smaller than it.
+pub(crate) const PANEL_MIN_SIZE: Pixels = px(100.);
+
+pub(crate) const TAB_PANEL_CONTEXT: &str = "TabPanel";
+const DOCK_AREA_CONTEXT: &str = "DockArea";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys((1..=9).map(|n| {
+        #[cfg(target_os = "macos")]
+        let keystroke = format!("cmd-{}", n);
+        #[cfg(not(target_os = "macos"))]
+        let keystroke = format!("ctrl-{}", n);
+
+        KeyBinding::new(&keystroke, ActivateTab(n - 1), Some(TAB_PANEL_CONTEXT))
+    }));
+    cx.bind_keys([
+        KeyBinding::new("ctrl-tab", ActivateNextTab, Some(TAB_PANEL_CONTEXT)),
+        KeyBinding::new("ctrl-shift-tab", ActivatePrevTab, Some(TAB_PANEL_CONTEXT)),
+        KeyBinding::new("ctrl--", GoBack, Some(DOCK_AREA_CONTEXT)),
+        KeyBinding::new("ctrl-shift--", GoForward, Some(DOCK_AREA_CONTEXT)),
+        KeyBinding::new("escape", ExitZoom, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-b", ToggleLeftDock, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-b", ToggleLeftDock, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-b", ToggleRightDock, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-b", ToggleRightDock, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-j", ToggleBottomDock, Some(DOCK_AREA_CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-j", ToggleBottomDock, Some(DOCK_AREA_CONTEXT)),
+    ]);
+}
+
+/// The state of the running layout transactions, see [`DockArea::transaction`].
+#[derive(Default)]
+struct LayoutTransaction {
+    /// The depth of the nested transactions.
+    depth: usize,
+    /// Whether there are some structural changes to notify at the end.
+    dirty: bool,
+}
+
+impl Global for LayoutTransaction {}
+
+pub(crate) fn begin_layout_transaction(cx: &mut AppContext) {
+    cx.default_global::<LayoutTransaction>().depth += 1;
+}
 
-actions!(dock, [ToggleZoom, ClosePanel]);
+pub(crate) fn end_layout_transaction(cx: &mut WindowContext) {
+    let transaction = cx.default_global::<LayoutTransaction>();
+    transaction.depth = transaction.depth.saturating_sub(1);
+    if transaction.depth > 0 || !transaction.dirty {
+        return;
+    }
+
+    transaction.dirty = false;
+    cx.refresh();
+    layout_updated(cx);
+}
+
+/// The revision of the layouts, increased by the structural changes to observe them.
+#[derive(Default)]
+struct LayoutRevision(usize);
+
+impl Global for LayoutRevision {}
+
+/// Notify the observers of [`LayoutRevision`], e.g.: the DockArea to autosave the layout.
+fn layout_updated(cx: &mut AppContext) {
+    cx.default_global::<LayoutRevision>().0 += 1;
+}
+
+/// Notify the view for the structural changes of the layout,
+/// it will be deferred to the end if in a layout transaction.
+pub(crate) fn notify_layout<V: 'static>(cx: &mut ViewContext<V>) {
+    if let Some(transaction) = cx.try_global::<LayoutTransaction>() {
+        if transaction.depth > 0 {
+            cx.global_mut::<LayoutTransaction>().dirty = true;
+            return;
+        }
+    }
+
+    cx.notify();
+    layout_updated(cx);
+}
+
+pub enum DockEvent {
+    /// The layout (e.g.: the panels or their sizes) is changed, it is debounced to autosave the layout.
+    LayoutChanged(DockNode),
+}
 
 /// The main area of the dock.
 pub struct DockArea {
     root: View<StackPanel>,
     zoom_view: Option<AnyWeakView>,
+    undo_stack: Vec<LayoutSnapshot>,
+    redo_stack: Vec<LayoutSnapshot>,
+    /// The fraction of the new panel, when a panel dropped on the edge of another panel.
+    split_ratio: f32,
+    /// The max nesting depth of the stack panels, a split beyond it falls back to merge into the tabs.
+    max_split_depth: Option<usize>,
+    /// The history of the activated panels, to navigate by [`GoBack`] and [`GoForward`].
+    navigation: NavigationHistory<EntityId>,
+    /// The dump of the last layout change, to skip the changes that are not visible in the dump.
+    last_dump: Option<DockNode>,
+    layout_changed: Debounce<Self, DockNode>,
+
+    /// The collapsible docks around the root, see [`DockArea::set_dock`].
+    docks: Vec<Dock>,
+    /// The panels torn off from the tabs into their own windows,
+    /// by dropping them outside of the dock area or by [`MoveToNewWindow`].
+    floating_panels: Vec<FloatingPanel>,
+    /// The bus for the panels of the dock area to communicate, see [`DockArea::event_bus`].
+    event_bus: EventBus,
+    /// The window of the dock area, the floating panels update the dock area in it.
+    window: AnyWindowHandle,
+    /// The bounds of the dock area in the window, measured in the last paint.
+    bounds: Rc<Cell<Bounds<Pixels>>>,
+    /// The panel being dragged, to tear it off if it is dropped outside of the dock area.
+    dragging_panel: Option<DragPanel>,
 }
 
 impl DockArea {
-    pub fn new(root: View<StackPanel>, _cx: &mut ViewContext<Self>) -> Self {
+    pub fn new(root: View<StackPanel>, cx: &mut ViewContext<Self>) -> Self {
+        cx.observe_global::<LayoutRevision>(Self::on_layout_updated)
+            .detach();
+
         Self {
             root,
             zoom_view: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            split_ratio: DEFAULT_SPLIT_RATIO,
+            max_split_depth: None,
+            navigation: NavigationHistory::new(),
+            last_dump: None,
+            layout_changed: debounce(Duration::from_millis(500), |_, dump, cx| {
+                cx.emit(DockEvent::LayoutChanged(dump))
+            }),
+            docks: Vec::new(),
+            floating_panels: Vec::new(),
+            event_bus: EventBus::new(),
+            window: cx.window_handle(),
+            bounds: Rc::new(Cell::new(Bounds::default())),
+            dragging_panel: None,
+        }
+    }
+
+    /// Return the event bus of the dock area, pass it to the panels to publish or subscribe the events.
+    pub fn event_bus(&self) -> &EventBus {
+        &self.event_bus
+    }
+
+    /// Return the split ratio of the dropped panel, default is `0.5`.
+    pub fn split_ratio(&self) -> f32 {
+        self.split_ratio
+    }
+
+    /// Set the fraction of the space the dropped panel takes, when it split another panel.
+    ///
+    /// The ratio is clamped to `0.1..=0.9`, the [`Panel::preferred_size`] takes precedence.
+    pub fn set_split_ratio(&mut self, ratio: f32) {
+        self.split_ratio = ratio.clamp(0.1, 0.9);
+    }
+
+    /// Return the max nesting depth of the splits, default is `None` (unlimited).
+    pub fn max_split_depth(&self) -> Option<usize> {
+        self.max_split_depth
+    }
+
+    /// Set the max nesting depth of the stack panels, the root is depth `1`.
+    ///
+    /// A panel dropped on the edge is merged into the tabs instead,
+    /// if the split needs a nested stack panel beyond the depth.
+    pub fn set_max_split_depth(&mut self, depth: Option<usize>) {
+        self.max_split_depth = depth.map(|depth| depth.max(1));
+    }
+
+    /// Push the current layout to the undo stack, called before a structural change (close, move, split).
+    ///
+    /// The `updating` is the TabPanel that makes the change with its snapshot, it can't be read by the DockArea.
+    pub(super) fn push_layout_undo(
+        &mut self,
+        updating: Option<(EntityId, &LayoutSnapshot)>,
+        cx: &AppContext,
+    ) {
+        let layout = self.root.read(cx).snapshot_with(updating, cx);
+        self.undo_stack.push(layout);
+        if self.undo_stack.len() > MAX_LAYOUT_HISTORY {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    /// Emit the debounced [`DockEvent::LayoutChanged`] if the dump of the layout is changed.
+    fn on_layout_updated(&mut self, cx: &mut ViewContext<Self>) {
+        // The layout before the first render is the initial layout, it is not a change.
+        let Some(last_dump) = self.last_dump.as_ref() else {
+            return;
+        };
+        let dump = self.root.read(cx).dump(cx);
+        if last_dump == &dump {
+            return;
+        }
+
+        self.last_dump = Some(dump.clone());
+        self.layout_changed.call(dump, cx);
+    }
+
+    /// Undo the last structural change of the layout.
+    pub fn undo_layout_change(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(layout) = self.undo_stack.pop() else {
+            return;
+        };
+
+        self.redo_stack.push(self.root.read(cx).snapshot(cx));
+        self.restore_layout(layout, cx);
+    }
+
+    /// Redo the last undone structural change of the layout.
+    pub fn redo_layout_change(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(layout) = self.redo_stack.pop() else {
+            return;
+        };
+
+        self.undo_stack.push(self.root.read(cx).snapshot(cx));
+        self.restore_layout(layout, cx);
+    }
+
+    fn restore_layout(&mut self, layout: LayoutSnapshot, cx: &mut ViewContext<Self>) {
+        // The floating panels and the docks are not in the layout history,
+        // take back the panels restored into the root.
+        let panel_ids = layout.panel_ids();
+        let LayoutSnapshot::Stack { axis, children } = layout else {
+            return;
+        };
+
+        self.zoom_view = None;
+        let tab_panels = self
+            .floating_panels
+            .iter()
+            .map(|floating| floating.tab_panel.clone())
+            .chain(self.docks.iter().map(|dock| dock.tab_panel.clone()))
+            .collect::<Vec<_>>();
+        for tab_panel in tab_panels {
+            tab_panel.update(cx, |view, cx| {
+                for panel in view.panels.clone() {
+                    if panel_ids.contains(&panel.view().entity_id()) {
+                        view.remove_panel(panel, cx);
+                    }
+                }
+            });
+        }
+        self.root = self.build_stack_panel(axis, children, None, cx);
+        cx.notify();
+        layout_updated(cx);
+    }
+
+    /// Dump the layout of the dock area to save it, e.g.: as JSON when the app is closed.
+    ///
+    /// The panels are saved by their [`Panel::panel_key`], see [`DockArea::load_layout`].
+    pub fn dump_layout(&self, cx: &WindowContext) -> DockLayout {
+        DockLayout {
+            root: self.root.read(cx).dump_layout(cx),
+            docks: self.docks.iter().map(|dock| dock.dump_layout(cx)).collect(),
+            floating: self
+                .floating_panels
+                .iter()
+                .map(|floating| floating.tab_panel.read(cx).dump_layout(cx))
+                .collect(),
+        }
+    }
+
+    /// Restore the layout saved by [`DockArea::dump_layout`], the docks and the floating panels are replaced.
+    ///
+    /// The panels in the dock area are reused by their keys, the `build_panel` is called
+    /// to create the panels that are not in the dock area, the panels can't be built are skipped.
+    pub fn load_layout(
+        &mut self,
+        layout: &DockLayout,
+        build_panel: impl Fn(&str, &mut WindowContext) -> Option<Arc<dyn PanelView>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let current = self.root.read(cx).snapshot(cx);
+        let mut panels = state::keyed_panels(&current, cx);
+        for tab_panel in self.docks.iter().map(|dock| &dock.tab_panel).chain(
+            self.floating_panels
+                .iter()
+                .map(|floating| &floating.tab_panel),
+        ) {
+            panels.extend(state::keyed_panels(&tab_panel.read(cx).snapshot(), cx));
+        }
+
+        let snapshot = layout.root.to_snapshot(&mut panels, &build_panel, cx);
+        let docks = layout
+            .docks
+            .iter()
+            .map(|dock| {
+                let snapshot = dock.tabs.to_snapshot(&mut panels, &build_panel, cx);
+                (dock, snapshot)
+            })
+            .collect::<Vec<_>>();
+        let floating = layout
+            .floating
+            .iter()
+            .map(|tabs| tabs.to_snapshot(&mut panels, &build_panel, cx))
+            .collect::<Vec<_>>();
+
+        self.push_layout_undo(None, cx);
+        self.docks.clear();
+        self.close_floating_panels(cx);
+        self.restore_layout(snapshot, cx);
+
+        for (dock, snapshot) in docks {
+            if let Some(tab_panel) = self.build_tab_panel(snapshot, cx) {
+                self.insert_dock(dock.position, tab_panel, px(dock.size), dock.open, cx);
+            }
+        }
+        for snapshot in floating {
+            if let Some(tab_panel) = self.build_tab_panel(snapshot, cx) {
+                self.open_floating_window(tab_panel, None, cx);
+            }
+        }
+    }
+
+    /// Build the TabPanel of the tabs `snapshot`, `None` if it is not tabs or has no panels.
+    fn build_tab_panel(
+        &mut self,
+        snapshot: LayoutSnapshot,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<View<TabPanel>> {
+        let LayoutSnapshot::Tabs {
+            active_ix,
+            zoomed,
+            pinned_count,
+            panels,
+        } = snapshot
+        else {
+            return None;
+        };
+        if panels.is_empty() {
+            return None;
+        }
+
+        let dock_area = cx.view().downgrade();
+        let tab_panel = cx.new_view(|cx| TabPanel::new(dock_area, cx));
+        tab_panel.update(cx, |view, cx| {
+            for panel in panels {
+                view.add_panel(panel, cx);
+            }
+            view.pinned_count = pinned_count.min(view.panels.len());
+            view.set_active_ix(active_ix.min(view.panels.len() - 1), cx);
+            view.is_zoomed = zoomed;
+        });
+        if zoomed {
+            self.zoom_view = Some(tab_panel.downgrade().into());
+        }
+        Some(tab_panel)
+    }
+
+    fn build_stack_panel(
+        &mut self,
+        axis: Axis,
+        children: Vec<(Option<Pixels>, LayoutSnapshot)>,
+        parent: Option<View<StackPanel>>,
+        cx: &mut ViewContext<Self>,
+    ) -> View<StackPanel> {
+        let dock_area = cx.view().downgrade();
+        let stack_panel = cx.new_view(|cx| {
+            let mut stack_panel = StackPanel::new(axis, cx);
+            stack_panel.parent = parent;
+            stack_panel
+        });
+
+        for (size, child) in children {
+            let size = size.filter(|size| *size > px(0.));
+            match child {
+                LayoutSnapshot::Stack { axis, children } => {
+                    let child =
+                        self.build_stack_panel(axis, children, Some(stack_panel.clone()), cx);
+                    stack_panel.update(cx, |view, cx| {
+                        view.add_panel(child, size, dock_area.clone(), cx)
+                    });
+                }
+                LayoutSnapshot::Tabs { .. } => {
+                    let Some(tab_panel) = self.build_tab_panel(child, cx) else {
+                        continue;
+                    };
+                    stack_panel.update(cx, |view, cx| {
+                        view.add_panel(tab_panel, size, dock_area.clone(), cx)
+                    });
+                }
+                LayoutSnapshot::Panel => {}
+            }
         }
+
+        stack_panel
+    }
+
+    /// Record the activated panel to the navigation history.
+    pub(crate) fn push_navigation(&mut self, panel_id: EntityId) {
+        self.navigation.push(panel_id);
+    }
+
+    /// Re-activate the previous activated panel.
+    pub fn go_back(&mut self, cx: &mut ViewContext<Self>) {
+        while let Some(panel_id) = self.navigation.back() {
+            if self.activate_panel(panel_id, cx) {
+                break;
+            }
+        }
+    }
+
+    /// Re-activate the next activated panel, after [`DockArea::go_back`].
+    pub fn go_forward(&mut self, cx: &mut ViewContext<Self>) {
+        while let Some(panel_id) = self.navigation.forward() {
+            if self.activate_panel(panel_id, cx) {
+                break;
+            }
+        }
+    }
+
+    /// Activate and focus the panel by its [`Panel::panel_key`], return false if it is not found.
+    pub fn activate_panel_by_key(&mut self, key: &str, cx: &mut ViewContext<Self>) -> bool {
+        let Some(panel_id) = self.root.read(cx).find_panel_by_key(key, cx) else {
+            return false;
+        };
+        self.activate_panel(panel_id, cx)
+    }
+
+    /// Activate and focus the panel, return false if the panel is not in the dock area anymore.
+    fn activate_panel(&mut self, panel_id: EntityId, cx: &mut ViewContext<Self>) -> bool {
+        let Some(tab_panel) = self.root.read(cx).find_tab_panel(panel_id, cx) else {
+            return false;
+        };
+
+        tab_panel.update(cx, |view, cx| view.activate_panel(panel_id, cx));
+        cx.focus(&tab_panel.focus_handle(cx));
+        true
+    }
+
+    fn on_action_go_back(&mut self, _: &GoBack, cx: &mut ViewContext<Self>) {
+        self.go_back(cx);
+    }
+
+    fn on_action_go_forward(&mut self, _: &GoForward, cx: &mut ViewContext<Self>) {
+        self.go_forward(cx);
+    }
+
+    fn on_action_undo(&mut self, _: &UndoLayoutChange, cx: &mut ViewContext<Self>) {
+        self.undo_layout_change(cx);
+    }
+
+    fn on_action_redo(&mut self, _: &RedoLayoutChange, cx: &mut ViewContext<Self>) {
+        self.redo_layout_change(cx);
+    }
+
+    /// Run the structural changes (e.g.: add, remove or split panels) as a layout transaction.
+    ///
+    /// The StackPanels and TabPanels will not notify for each change in the transaction,
+    /// the window will be refreshed once at the end instead.
+    pub fn transaction<R>(cx: &mut WindowContext, f: impl FnOnce(&mut WindowContext) -> R) -> R {
+        begin_layout_transaction(cx);
+        let result = f(cx);
+        end_layout_transaction(cx);
+        result
+    }
+
+    /// Dump the layout tree of the dock area, for debugging and snapshot testing.
+    pub fn dump(&self, cx: &WindowContext) -> DockNode {
+        self.root.read(cx).dump(cx)
+    }
+
+    /// Return the zoomed panel, it fills the whole dock area.
+    pub fn zoomed_panel(&self) -> Option<AnyView> {
+        self.zoom_view.as_ref().and_then(|view| view.upgrade())
+    }
+
+    /// Zoom the panel to fill the whole dock area, the panel zoomed before is zoomed out.
+    pub fn zoom_in<P: Panel>(&mut self, panel: View<P>, cx: &mut ViewContext<Self>) {
+        let view: AnyView = panel.into();
+        if self.zoomed_panel().as_ref() == Some(&view) {
+            return;
+        }
+
+        self.zoom_out(cx);
+        self.zoom_view = Some(view.downgrade());
+        if let Ok(tab_panel) = view.downcast::<TabPanel>() {
+            tab_panel.update(cx, |view, cx| {
+                view.is_zoomed = true;
+                cx.notify();
+            });
+        }
+        cx.notify();
+    }
+
+    /// Restore the zoomed panel into the layout.
+    pub fn zoom_out(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(view) = self.zoom_view.take().and_then(|view| view.upgrade()) else {
+            return;
+        };
+
+        // Sync the state of the TabPanel, e.g.: zoomed out by `escape`.
+        if let Ok(tab_panel) = view.downcast::<TabPanel>() {
+            tab_panel.update(cx, |view, cx| {
+                view.is_zoomed = false;
+                cx.notify();
+            });
+        }
+        cx.notify();
     }
 
     /// Toggles the zoom view.
     pub fn toggle_zoom<P: Panel>(&mut self, panel: View<P>, cx: &mut ViewContext<Self>) {
         if self.zoom_view.is_some() {
-            self.zoom_view = None;
+            self.zoom_out(cx);
         } else {
-            self.zoom_view = Some(panel.downgrade().into());
+            self.zoom_in(panel, cx);
         }
-        cx.notify();
+    }
+
+    /// Zoom in or out the dock TabPanel by its events, like the StackPanel does for its children.
+    pub(super) fn subscribe_zoom(
+        &mut self,
+        tab_panel: &View<TabPanel>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        cx.subscribe(
+            tab_panel,
+            |this, tab_panel, event: &PanelEvent, cx| match event {
+                PanelEvent::ZoomIn => this.zoom_in(tab_panel, cx),
+                PanelEvent::ZoomOut => this.zoom_out(cx),
+            },
+        )
+        .detach();
+    }
+
+    fn on_action_exit_zoom(&mut self, _: &ExitZoom, cx: &mut ViewContext<Self>) {
+        if self.zoom_view.is_none() {
+            cx.propagate();
+            return;
+        }
+
+        self.zoom_out(cx);
     }
 }
 
+impl EventEmitter<DockEvent> for DockArea {}
+
 impl Render for DockArea {
-    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if self.last_dump.is_none() {
+            self.last_dump = Some(self.root.read(cx).dump(cx));
+        }
+
         div()
             .id("dock-area")
+            .key_context(DOCK_AREA_CONTEXT)
+            .on_action(cx.listener(Self::on_action_undo))
+            .on_action(cx.listener(Self::on_action_redo))
+            .on_action(cx.listener(Self::on_action_go_back))
+            .on_action(cx.listener(Self::on_action_go_forward))
+            .on_action(cx.listener(Self::on_action_toggle_left_dock))
+            .on_action(cx.listener(Self::on_action_toggle_right_dock))
+            .on_action(cx.listener(Self::on_action_toggle_bottom_dock))
+            .on_action(cx.listener(Self::on_action_exit_zoom))
+            .on_mouse_down(
+                MouseButton::Navigate(NavigationDirection::Back),
+                cx.listener(|this, _, cx| this.go_back(cx)),
+            )
+            .on_mouse_down(
+                MouseButton::Navigate(NavigationDirection::Forward),
+                cx.listener(|this, _, cx| this.go_forward(cx)),
+            )
+            .on_drag_move(cx.listener(|this, drag: &DragMoveEvent<DragPanel>, cx| {
+                this.dragging_panel = Some(drag.drag(cx).clone());
+            }))
+            .on_drag_move(cx.listener(Self::resize_dock))
+            .relative()
             .size_full()
             .overflow_hidden()
             .map(|this| {
-                if let Some(zoom_view) = self.zoom_view.as_ref().and_then(|view| view.upgrade()) {
+                if let Some(zoom_view) = self.zoomed_panel() {
                     this.child(zoom_view)
                 } else {
-                    this.child(self.root.clone())
+                    this.flex()
+                        .flex_row()
+                        .children(self.render_dock(DockPosition::Left, cx))
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .flex_1()
+                                .min_w_0()
+                                .h_full()
+                                .child(div().flex_1().min_h_0().child(self.root.clone()))
+                                .children(self.render_dock(DockPosition::Bottom, cx)),
+                        )
+                        .children(self.render_dock(DockPosition::Right, cx))
                 }
             })
+            .child(self.render_tear_off(cx))
     }
 }
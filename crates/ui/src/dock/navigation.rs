@@ -0,0 +1,133 @@
+use gpui::{AppContext, EntityId, View, WindowContext};
+
+use super::{StackPanel, TabPanel};
+
+/// The max number of the panel activations to navigate back.
+const MAX_NAVIGATION_HISTORY: usize = 100;
+
+/// The history of the activated panels, to navigate back and forward like a browser.
+pub(super) struct NavigationHistory<T> {
+    entries: Vec<T>,
+    /// The index of the current entry.
+    ix: usize,
+}
+
+impl<T: Copy + PartialEq> NavigationHistory<T> {
+    pub(super) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            ix: 0,
+        }
+    }
+
+    /// Push an entry after the current one, and clear the forward entries.
+    ///
+    /// Do nothing if the entry is the current one, e.g.: it is activated by the navigation.
+    pub(super) fn push(&mut self, entry: T) {
+        if self.entries.get(self.ix) == Some(&entry) {
+            return;
+        }
+
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.ix + 1);
+        }
+        self.entries.push(entry);
+        if self.entries.len() > MAX_NAVIGATION_HISTORY {
+            self.entries.remove(0);
+        }
+        self.ix = self.entries.len() - 1;
+    }
+
+    pub(super) fn back(&mut self) -> Option<T> {
+        if self.ix == 0 {
+            return None;
+        }
+
+        self.ix -= 1;
+        self.entries.get(self.ix).copied()
+    }
+
+    pub(super) fn forward(&mut self) -> Option<T> {
+        if self.ix + 1 >= self.entries.len() {
+            return None;
+        }
+
+        self.ix += 1;
+        self.entries.get(self.ix).copied()
+    }
+}
+
+impl StackPanel {
+    /// Find the TabPanel that contains the panel, in this stack and the nested stacks.
+    pub(super) fn find_tab_panel(
+        &self,
+        panel_id: EntityId,
+        cx: &AppContext,
+    ) -> Option<View<TabPanel>> {
+        self.panels.iter().find_map(|panel| {
+            let view = panel.view();
+            if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+                let contains = tab_panel
+                    .read(cx)
+                    .panels
+                    .iter()
+                    .any(|panel| panel.view().entity_id() == panel_id);
+                contains.then_some(tab_panel)
+            } else if let Ok(stack_panel) = view.downcast::<Self>() {
+                stack_panel.read(cx).find_tab_panel(panel_id, cx)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Find the panel by its [`super::Panel::panel_key`], in this stack and the nested stacks.
+    pub(super) fn find_panel_by_key(&self, key: &str, cx: &WindowContext) -> Option<EntityId> {
+        self.panels.iter().find_map(|panel| {
+            let view = panel.view();
+            if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+                tab_panel
+                    .read(cx)
+                    .panels
+                    .iter()
+                    .find(|panel| panel.panel_key(cx).as_deref() == Some(key))
+                    .map(|panel| panel.view().entity_id())
+            } else if let Ok(stack_panel) = view.downcast::<Self>() {
+                stack_panel.read(cx).find_panel_by_key(key, cx)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NavigationHistory;
+
+    #[test]
+    fn test_navigation_history() {
+        let mut history = NavigationHistory::new();
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), None);
+
+        history.push(1);
+        history.push(2);
+        history.push(2);
+        history.push(3);
+        assert_eq!(history.back(), Some(2));
+        assert_eq!(history.back(), Some(1));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), Some(2));
+
+        // The activation by the navigation is ignored.
+        history.push(2);
+        assert_eq!(history.forward(), Some(3));
+        assert_eq!(history.forward(), None);
+
+        history.back();
+        history.push(4);
+        assert_eq!(history.forward(), None);
+        assert_eq!(history.back(), Some(2));
+    }
+}
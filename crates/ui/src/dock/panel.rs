@@ -1,7 +1,12 @@
-use gpui::{AnyView, EventEmitter, FocusableView, SharedString, View, WindowContext};
+use gpui::{
+    AnyElement, AnyView, Bounds, EventEmitter, ExternalPaths, FocusableView, Pixels, SharedString,
+    Subscription, View, ViewContext, WindowContext,
+};
 use rust_i18n::t;
 
-use super::PanelEvent;
+use crate::IconName;
+
+use super::{PanelEvent, PANEL_MIN_SIZE};
 
 pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
     /// The title of the panel, default is `None`.
@@ -9,10 +14,75 @@ pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
         t!("Dock.Unnamed").into()
     }
 
+    /// The unique key of the panel to save it in the [`DockLayout`], default is `None` to not persist the panel.
+    ///
+    /// [`DockLayout`]: super::DockLayout
+    fn panel_key(&self, _cx: &WindowContext) -> Option<SharedString> {
+        None
+    }
+
+    /// The description of the panel to show in the tab tooltip, default is `None`.
+    fn description(&self, _cx: &WindowContext) -> Option<SharedString> {
+        None
+    }
+
+    /// The icon of the panel to show in the pinned tab, default is `None` to use the first letter of title.
+    fn icon(&self, _cx: &WindowContext) -> Option<IconName> {
+        None
+    }
+
+    /// The preferred size of the panel when it split from a dropped panel, default is `None`.
+    ///
+    /// If `None`, the split ratio of the [`DockArea`] will be used.
+    ///
+    /// [`DockArea`]: super::DockArea
+    fn preferred_size(&self, _cx: &WindowContext) -> Option<Pixels> {
+        None
+    }
+
+    /// The min size of the panel, a split that makes the panel smaller than it is vetoed, default is 100px.
+    fn min_size(&self, _cx: &WindowContext) -> Pixels {
+        PANEL_MIN_SIZE
+    }
+
     /// Whether the panel can be closed, default is `true`.
     fn closeable(&self, _cx: &WindowContext) -> bool {
         true
     }
+
+    /// Called when the bounds of the [`ResizablePanel`] that contains the panel are changed,
+    /// e.g.: to switch the layout of the content by the width, default is to do nothing.
+    ///
+    /// [`ResizablePanel`]: crate::resizable::ResizablePanel
+    fn on_resize(&mut self, _bounds: Bounds<Pixels>, _cx: &mut ViewContext<Self>)
+    where
+        Self: Sized,
+    {
+    }
+
+    /// Whether the panel is running a background work (e.g.: a refresh), default is `false`.
+    ///
+    /// The dock shows a spinner on the tab of the busy panel, call `cx.notify()` when it is changed.
+    fn busy(&self, _cx: &WindowContext) -> bool {
+        false
+    }
+
+    /// The toolbar buttons of the panel, rendered in the tab bar (next to the menu button) when the panel is active.
+    ///
+    /// e.g.: the refresh or filter buttons of a table panel, default is empty.
+    fn toolbar_buttons(&mut self, _cx: &mut ViewContext<Self>) -> Vec<AnyElement>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    /// Handle the files dropped from the OS onto the panel (or its tab), default is to ignore them.
+    fn handle_dropped_files(&mut self, _paths: &ExternalPaths, _cx: &mut ViewContext<Self>)
+    where
+        Self: Sized,
+    {
+    }
 }
 
 pub trait PanelView: 'static + Send + Sync {
@@ -21,7 +91,54 @@ pub trait PanelView: 'static + Send + Sync {
         t!("Dock.Unnamed").into()
     }
 
+    /// The unique key of the panel in the saved layout, default is `None`.
+    fn panel_key(&self, _cx: &WindowContext) -> Option<SharedString> {
+        None
+    }
+
+    /// The description of the panel, default is `None`.
+    fn description(&self, _cx: &WindowContext) -> Option<SharedString> {
+        None
+    }
+
+    /// The icon of the panel, default is `None`.
+    fn icon(&self, _cx: &WindowContext) -> Option<IconName> {
+        None
+    }
+
+    /// The preferred size of the panel when split, default is `None`.
+    fn preferred_size(&self, _cx: &WindowContext) -> Option<Pixels> {
+        None
+    }
+
+    /// Whether the panel can be closed, default is `true`.
+    fn closeable(&self, _cx: &WindowContext) -> bool {
+        true
+    }
+
+    /// The min size of the panel when split, default is 100px.
+    fn min_size(&self, _cx: &WindowContext) -> Pixels {
+        PANEL_MIN_SIZE
+    }
+
+    /// Whether the panel is running a background work, default is `false`.
+    fn busy(&self, _cx: &WindowContext) -> bool {
+        false
+    }
+
     fn view(&self) -> AnyView;
+
+    /// Call `f` when the panel view is notified, e.g.: to refresh its cached title.
+    fn observe(&self, cx: &mut WindowContext, f: Box<dyn Fn(&mut WindowContext)>) -> Subscription;
+
+    /// Render the toolbar buttons of the panel.
+    fn toolbar_buttons(&self, cx: &mut WindowContext) -> Vec<AnyElement>;
+
+    /// Route the files dropped from the OS to the panel.
+    fn handle_dropped_files(&self, paths: &ExternalPaths, cx: &mut WindowContext);
+
+    /// Notify the panel that the bounds of its container are changed.
+    fn on_resize(&self, bounds: Bounds<Pixels>, cx: &mut WindowContext);
 }
 
 impl<T: Panel> PanelView for View<T> {
@@ -29,9 +146,53 @@ impl<T: Panel> PanelView for View<T> {
         self.read(cx).title(cx)
     }
 
+    fn panel_key(&self, cx: &WindowContext) -> Option<SharedString> {
+        self.read(cx).panel_key(cx)
+    }
+
+    fn description(&self, cx: &WindowContext) -> Option<SharedString> {
+        self.read(cx).description(cx)
+    }
+
+    fn icon(&self, cx: &WindowContext) -> Option<IconName> {
+        self.read(cx).icon(cx)
+    }
+
+    fn preferred_size(&self, cx: &WindowContext) -> Option<Pixels> {
+        self.read(cx).preferred_size(cx)
+    }
+
+    fn min_size(&self, cx: &WindowContext) -> Pixels {
+        self.read(cx).min_size(cx)
+    }
+
+    fn closeable(&self, cx: &WindowContext) -> bool {
+        self.read(cx).closeable(cx)
+    }
+
+    fn busy(&self, cx: &WindowContext) -> bool {
+        self.read(cx).busy(cx)
+    }
+
     fn view(&self) -> AnyView {
         self.clone().into()
     }
+
+    fn observe(&self, cx: &mut WindowContext, f: Box<dyn Fn(&mut WindowContext)>) -> Subscription {
+        cx.observe(self, move |_, cx| f(cx))
+    }
+
+    fn toolbar_buttons(&self, cx: &mut WindowContext) -> Vec<AnyElement> {
+        self.update(cx, |view, cx| view.toolbar_buttons(cx))
+    }
+
+    fn handle_dropped_files(&self, paths: &ExternalPaths, cx: &mut WindowContext) {
+        self.update(cx, |view, cx| view.handle_dropped_files(paths, cx))
+    }
+
+    fn on_resize(&self, bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+        self.update(cx, |view, cx| view.on_resize(bounds, cx))
+    }
 }
 
 impl From<&dyn PanelView> for AnyView {
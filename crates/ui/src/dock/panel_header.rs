@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, ElementId, InteractiveElement as _, IntoElement,
+    MouseButton, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled,
+    WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable as _};
+
+/// The header of the tool panels, with a title, an icon, a collapse chevron and the action buttons.
+///
+/// The collapsed state is owned by the panel, toggle it in the `on_toggle` handler.
+///
+/// The breadcrumb is shown after the title for the nested navigation in the panel,
+/// e.g.: `Table › Row 12`, click the title or a segment to navigate back.
+#[derive(IntoElement)]
+pub struct PanelHeader {
+    id: ElementId,
+    title: SharedString,
+    icon: Option<IconName>,
+    collapsed: bool,
+    on_toggle: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+    breadcrumb: Vec<SharedString>,
+    on_navigate: Option<Rc<dyn Fn(&usize, &mut WindowContext) + 'static>>,
+    actions: SmallVec<[AnyElement; 2]>,
+}
+
+impl PanelHeader {
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            icon: None,
+            collapsed: false,
+            on_toggle: None,
+            breadcrumb: Vec::new(),
+            on_navigate: None,
+            actions: SmallVec::new(),
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Set the handler when the header is clicked, with the new collapsed state.
+    ///
+    /// The chevron is only shown when the handler is set.
+    pub fn on_toggle(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the breadcrumb path after the title.
+    pub fn breadcrumb(mut self, path: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.breadcrumb = path.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the handler when the title or a breadcrumb segment is clicked,
+    /// with the depth to navigate back to, `0` is the title.
+    pub fn on_navigate(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_navigate = Some(Rc::new(handler));
+        self
+    }
+
+    /// The action buttons at the right of the header, e.g.: refresh, close.
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.actions.push(action.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for PanelHeader {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let collapsed = self.collapsed;
+        let collapsible = self.on_toggle.is_some();
+        let depth_count = self.breadcrumb.len();
+
+        h_flex()
+            .id(self.id)
+            .flex_shrink_0()
+            .h_8()
+            .px_2()
+            .gap_1()
+            .justify_between()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().tab_bar)
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .gap_1()
+                    .text_sm()
+                    .when(collapsible, |this| {
+                        this.child(
+                            Icon::new(if collapsed {
+                                IconName::ChevronRight
+                            } else {
+                                IconName::ChevronDown
+                            })
+                            .small()
+                            .text_color(cx.theme().muted_foreground),
+                        )
+                    })
+                    .when_some(self.icon, |this, icon| this.child(Icon::new(icon).small()))
+                    .children(
+                        std::iter::once(self.title)
+                            .chain(self.breadcrumb)
+                            .enumerate()
+                            .map(|(depth, label)| {
+                                let current = depth == depth_count;
+                                let on_navigate = self.on_navigate.clone().filter(|_| !current);
+
+                                h_flex()
+                                    .id(("breadcrumb", depth))
+                                    .min_w_0()
+                                    .gap_1()
+                                    .when(depth > 0, |this| {
+                                        this.child(
+                                            Icon::new(IconName::ChevronRight)
+                                                .xsmall()
+                                                .text_color(cx.theme().muted_foreground),
+                                        )
+                                    })
+                                    .child(
+                                        div()
+                                            .overflow_hidden()
+                                            .text_ellipsis()
+                                            .whitespace_nowrap()
+                                            .when(!current, |this| {
+                                                this.text_color(cx.theme().muted_foreground)
+                                            })
+                                            .child(label),
+                                    )
+                                    .when_some(on_navigate, |this, on_navigate| {
+                                        this.cursor_pointer()
+                                            .hover(|this| this.text_color(cx.theme().foreground))
+                                            .on_click(move |_, cx| {
+                                                // Navigate should not toggle the panel.
+                                                cx.stop_propagation();
+                                                on_navigate(&depth, cx)
+                                            })
+                                    })
+                            }),
+                    ),
+            )
+            .when(!self.actions.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .gap_1()
+                        .flex_shrink_0()
+                        // Click the actions should not toggle the panel.
+                        .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                        .children(self.actions),
+                )
+            })
+            .when_some(self.on_toggle, |this, on_toggle| {
+                this.cursor_pointer()
+                    .on_click(move |_, cx| on_toggle(&!collapsed, cx))
+            })
+    }
+}
@@ -4,14 +4,14 @@ use crate::{
     h_flex,
     resizable::{h_resizable, resizable_panel, v_resizable, ResizablePanel, ResizablePanelGroup},
     theme::ActiveTheme,
-    Placement,
+    AxisExt as _, Placement,
 };
 
-use super::{DockArea, Panel, PanelEvent, PanelView, TabPanel};
+use super::{notify_layout, DockArea, DockNode, Panel, PanelEvent, PanelView, TabPanel};
 use gpui::{
-    prelude::FluentBuilder as _, Axis, DismissEvent, Entity, EventEmitter, FocusHandle,
-    FocusableView, IntoElement, ParentElement, Pixels, Render, Styled, View, ViewContext,
-    VisualContext, WeakView,
+    prelude::FluentBuilder as _, px, Along as _, AppContext, Axis, DismissEvent, Entity,
+    EventEmitter, FocusHandle, FocusableView, IntoElement, ParentElement, Pixels, Render, Styled,
+    View, ViewContext, VisualContext, WeakView, WindowContext,
 };
 use smallvec::SmallVec;
 
@@ -19,7 +19,7 @@ pub struct StackPanel {
     pub(super) parent: Option<View<StackPanel>>,
     pub(super) axis: Axis,
     focus_handle: FocusHandle,
-    panels: SmallVec<[Arc<dyn PanelView>; 2]>,
+    pub(super) panels: SmallVec<[Arc<dyn PanelView>; 2]>,
     panel_group: View<ResizablePanelGroup>,
 }
 
@@ -27,18 +27,25 @@ impl Panel for StackPanel {}
 
 impl StackPanel {
     pub fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        let panel_group = cx.new_view(|cx| {
+            if axis == Axis::Horizontal {
+                h_resizable(cx)
+            } else {
+                v_resizable(cx)
+            }
+            // The sizes moved by the handles are a layout change, to save the layout.
+            .on_resize(move |_, cx| {
+                _ = view.update(cx, |_, cx| notify_layout(cx));
+            })
+        });
+
         Self {
             axis,
             parent: None,
             focus_handle: cx.focus_handle(),
             panels: SmallVec::new(),
-            panel_group: cx.new_view(|cx| {
-                if axis == Axis::Horizontal {
-                    h_resizable(cx)
-                } else {
-                    v_resizable(cx)
-                }
-            }),
+            panel_group,
         }
     }
 
@@ -47,6 +54,17 @@ impl StackPanel {
         self.parent.is_none()
     }
 
+    /// The nesting depth of the stack panel, the root is `1`.
+    pub(super) fn depth(&self, cx: &AppContext) -> usize {
+        let mut depth = 1;
+        let mut parent = self.parent.clone();
+        while let Some(view) = parent {
+            depth += 1;
+            parent = view.read(cx).parent.clone();
+        }
+        depth
+    }
+
     pub(super) fn panels_len(&self) -> usize {
         self.panels.len()
     }
@@ -123,9 +141,11 @@ impl StackPanel {
     where
         P: Panel,
     {
+        let view = panel.clone();
         resizable_panel()
             .content_view(panel.view())
             .when_some(size, |this, size| this.size(size))
+            .on_resize(move |bounds, cx| view.on_resize(bounds, cx))
     }
 
     fn insert_panel<P>(
@@ -145,12 +165,11 @@ impl StackPanel {
 
         let dock_area = dock_area.clone();
         cx.subscribe(&panel, move |_, panel, event, cx| match event {
-            PanelEvent::ZoomIn | PanelEvent::ZoomOut => {
-                if let Some(dock) = dock_area.upgrade() {
-                    dock.update(cx, |dock, cx| {
-                        dock.toggle_zoom(panel.clone(), cx);
-                    });
-                }
+            PanelEvent::ZoomIn => {
+                _ = dock_area.update(cx, |dock, cx| dock.zoom_in(panel.clone(), cx));
+            }
+            PanelEvent::ZoomOut => {
+                _ = dock_area.update(cx, |dock, cx| dock.zoom_out(cx));
             }
         })
         .detach();
@@ -181,7 +200,7 @@ impl StackPanel {
             view.insert_child(Self::new_resizable_panel(panel, size), ix, cx)
         });
 
-        cx.notify();
+        notify_layout(cx);
     }
 
     /// Remove panel from the stack.
@@ -235,7 +254,7 @@ impl StackPanel {
             });
         }
 
-        cx.notify();
+        notify_layout(cx);
     }
 
     /// Remove all panels from the stack.
@@ -245,12 +264,85 @@ impl StackPanel {
             .update(cx, |view, cx| view.remove_all_children(cx));
     }
 
+    /// Return the sizes of the children along the axis.
+    pub(super) fn sizes(&self, cx: &AppContext) -> Vec<Pixels> {
+        self.panel_group.read(cx).sizes().to_vec()
+    }
+
+    /// Dump the layout tree of the stack panel, see [`DockArea::dump`].
+    pub(super) fn dump(&self, cx: &WindowContext) -> DockNode {
+        let sizes = self.panel_group.read(cx).sizes();
+        let children = self
+            .panels
+            .iter()
+            .enumerate()
+            .map(|(ix, panel)| {
+                let size = sizes.get(ix).copied().unwrap_or_default();
+                let view = panel.view();
+                let node = if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+                    tab_panel.read(cx).dump(cx)
+                } else if let Ok(stack_panel) = view.downcast::<Self>() {
+                    stack_panel.read(cx).dump(cx)
+                } else {
+                    DockNode::Panel {
+                        title: panel.title(cx),
+                    }
+                };
+                (size, node)
+            })
+            .collect();
+
+        DockNode::Stack {
+            axis: self.axis,
+            children,
+        }
+    }
+
+    /// Return the size of the child at `ix` to split by the `placement`.
+    ///
+    /// If the `placement` is not along the axis of the stack, the child will be replaced by
+    /// a new StackPanel, so the size is the cross size of this stack.
+    pub(super) fn split_target_size(
+        &self,
+        ix: usize,
+        placement: Placement,
+        cx: &AppContext,
+    ) -> Pixels {
+        let panel_group = self.panel_group.read(cx);
+        let same_axis = (self.axis.is_vertical() && placement.is_vertical())
+            || (self.axis.is_horizontal() && placement.is_horizontal());
+
+        if same_axis {
+            panel_group.child_size(ix, cx).unwrap_or_default()
+        } else {
+            panel_group.bounds().size.along(placement.axis())
+        }
+    }
+
+    /// Set the size of the panel at `ix`.
+    pub(super) fn resize_panel(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
+        self.panel_group
+            .update(cx, |view, cx| view.set_child_size(ix, size, cx));
+    }
+
+    /// Distribute the sizes of the children evenly, and of the nested stack panels as well.
+    pub fn equalize_sizes(&mut self, cx: &mut ViewContext<Self>) {
+        self.panel_group
+            .update(cx, |view, cx| view.equalize_sizes(cx));
+
+        for panel in self.panels.clone() {
+            if let Ok(stack_panel) = panel.view().downcast::<Self>() {
+                stack_panel.update(cx, |view, cx| view.equalize_sizes(cx));
+            }
+        }
+    }
+
     /// Change the axis of the stack panel.
     pub(super) fn set_axis(&mut self, axis: Axis, cx: &mut ViewContext<Self>) {
         self.axis = axis;
         self.panel_group
             .update(cx, |view, cx| view.set_axis(axis, cx));
-        cx.notify();
+        notify_layout(cx);
     }
 }
 
@@ -270,3 +362,64 @@ impl Render for StackPanel {
             .child(self.panel_group.clone())
     }
 }
+
+/// Return the size of the new panel and the rest size of the split panel,
+/// `None` if the split panel is not rendered yet.
+pub(super) fn split_sizes(
+    size: Pixels,
+    ratio: f32,
+    preferred_size: Option<Pixels>,
+) -> Option<(Pixels, Pixels)> {
+    if size <= px(0.) {
+        return None;
+    }
+
+    let new_size = preferred_size.unwrap_or(size * ratio).round();
+    let new_size = if new_size < px(0.) {
+        px(0.)
+    } else if new_size > size {
+        size
+    } else {
+        new_size
+    };
+    Some((new_size, size - new_size))
+}
+
+/// Return whether the `(new_size, rest_size)` of a split are not smaller than the min sizes of the both panels.
+pub(super) fn split_fits(
+    sizes: (Pixels, Pixels),
+    new_min_size: Pixels,
+    rest_min_size: Pixels,
+) -> bool {
+    let (new_size, rest_size) = sizes;
+    new_size >= new_min_size && rest_size >= rest_min_size
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{split_fits, split_sizes};
+
+    #[test]
+    fn test_split_sizes() {
+        assert_eq!(split_sizes(px(400.), 0.5, None), Some((px(200.), px(200.))));
+        assert_eq!(split_sizes(px(400.), 0.3, None), Some((px(120.), px(280.))));
+        assert_eq!(
+            split_sizes(px(400.), 0.5, Some(px(100.))),
+            Some((px(100.), px(300.)))
+        );
+        assert_eq!(
+            split_sizes(px(400.), 0.5, Some(px(500.))),
+            Some((px(400.), px(0.)))
+        );
+        assert_eq!(split_sizes(px(0.), 0.5, None), None);
+    }
+    #[test]
+    fn test_split_fits() {
+        assert!(split_fits((px(200.), px(200.)), px(100.), px(100.)));
+        assert!(split_fits((px(100.), px(300.)), px(100.), px(100.)));
+        assert!(!split_fits((px(80.), px(320.)), px(100.), px(100.)));
+        assert!(!split_fits((px(300.), px(100.)), px(100.), px(150.)));
+    }
+}
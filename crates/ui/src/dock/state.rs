@@ -0,0 +1,310 @@
+use std::{collections::HashMap, sync::Arc};
+
+use gpui::{px, Axis, SharedString, WindowContext};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    history::LayoutSnapshot, zone::Dock, DockNode, DockPosition, PanelView, StackPanel, TabPanel,
+};
+
+/// The serializable layout of the dock area, see [`super::DockArea::dump_layout`]
+/// and [`super::DockArea::load_layout`].
+///
+/// The panels are saved by their [`super::Panel::panel_key`],
+/// the panels without a key are not persisted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockLayout {
+    pub root: LayoutState,
+    /// The docks around the root, see [`super::DockArea::set_dock`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docks: Vec<DockState>,
+    /// The tabs of the floating windows, they are reopened in the center of the screen.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub floating: Vec<LayoutState>,
+}
+
+/// The serializable layout of a dock around the root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockState {
+    pub position: DockPosition,
+    pub size: f32,
+    pub open: bool,
+    /// The tabs of the dock, only a [`LayoutState::Tabs`] can be restored.
+    pub tabs: LayoutState,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayoutState {
+    /// A StackPanel with the children and their sizes along the axis.
+    Stack {
+        #[serde(with = "serde_axis")]
+        axis: Axis,
+        /// The sizes of the children, `0` means the size is not measured yet.
+        sizes: Vec<f32>,
+        children: Vec<LayoutState>,
+    },
+    /// A TabPanel with the keys of the panels in the tab order.
+    Tabs {
+        active_ix: usize,
+        #[serde(default)]
+        zoomed: bool,
+        /// The number of the pinned panels at the start of the `panels`.
+        #[serde(default)]
+        pinned: usize,
+        panels: Vec<SharedString>,
+    },
+}
+
+mod serde_axis {
+    use gpui::Axis;
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(axis: &Axis, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match axis {
+            Axis::Horizontal => "horizontal",
+            Axis::Vertical => "vertical",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Axis, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "horizontal" => Ok(Axis::Horizontal),
+            "vertical" => Ok(Axis::Vertical),
+            other => Err(serde::de::Error::unknown_variant(
+                other,
+                &["horizontal", "vertical"],
+            )),
+        }
+    }
+}
+
+impl LayoutState {
+    /// Convert the dump of a StackPanel or a TabPanel to the serializable layout,
+    /// `None` for the other panels, they can't be restored.
+    fn from_dump(node: &DockNode) -> Option<Self> {
+        match node {
+            DockNode::Stack { axis, children } => {
+                let mut sizes = vec![];
+                let mut states = vec![];
+                for (size, child) in children {
+                    if let Some(state) = Self::from_dump(child) {
+                        sizes.push(size.0.round());
+                        states.push(state);
+                    }
+                }
+
+                Some(LayoutState::Stack {
+                    axis: *axis,
+                    sizes,
+                    children: states,
+                })
+            }
+            DockNode::Tabs {
+                active_ix,
+                keys,
+                zoomed,
+                pinned_count,
+                ..
+            } => {
+                let panels = keys.iter().flatten().cloned().collect::<Vec<_>>();
+                let pinned = keys.iter().take(*pinned_count).flatten().count();
+                // The index of the active panel in the persisted panels.
+                let active_ix = keys
+                    .get(*active_ix)
+                    .cloned()
+                    .flatten()
+                    .and_then(|key| panels.iter().position(|k| *k == key))
+                    .unwrap_or_default();
+
+                Some(LayoutState::Tabs {
+                    active_ix,
+                    zoomed: *zoomed,
+                    pinned,
+                    panels,
+                })
+            }
+            DockNode::Panel { .. } => None,
+        }
+    }
+
+    /// Convert to the snapshot to build the panels, the panels are taken from the `panels` by the key,
+    /// or built by the `build_panel` if it is not in the dock area.
+    pub(super) fn to_snapshot(
+        &self,
+        panels: &mut HashMap<SharedString, Arc<dyn PanelView>>,
+        build_panel: &dyn Fn(&str, &mut WindowContext) -> Option<Arc<dyn PanelView>>,
+        cx: &mut WindowContext,
+    ) -> LayoutSnapshot {
+        match self {
+            LayoutState::Stack {
+                axis,
+                sizes,
+                children,
+            } => LayoutSnapshot::Stack {
+                axis: *axis,
+                children: children
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, child)| {
+                        let size = sizes.get(ix).copied().filter(|size| *size > 0.).map(px);
+                        (size, child.to_snapshot(panels, build_panel, cx))
+                    })
+                    .collect(),
+            },
+            LayoutState::Tabs {
+                active_ix,
+                zoomed,
+                pinned,
+                panels: keys,
+            } => {
+                let mut pinned_count = 0;
+                let mut tab_panels = vec![];
+                let mut restored = vec![];
+                for (ix, key) in keys.iter().enumerate() {
+                    let panel = panels.remove(key).or_else(|| build_panel(key, cx));
+                    restored.push(panel.is_some());
+                    let Some(panel) = panel else {
+                        continue;
+                    };
+                    if ix < *pinned {
+                        pinned_count += 1;
+                    }
+                    tab_panels.push(panel);
+                }
+
+                LayoutSnapshot::Tabs {
+                    active_ix: restored_active_ix(*active_ix, &restored),
+                    zoomed: *zoomed,
+                    pinned_count,
+                    panels: tab_panels,
+                }
+            }
+        }
+    }
+}
+
+impl StackPanel {
+    /// Dump the serializable layout of the stack panel, see [`super::DockArea::dump_layout`].
+    pub(super) fn dump_layout(&self, cx: &WindowContext) -> LayoutState {
+        LayoutState::from_dump(&self.dump(cx)).expect("a StackPanel can be restored")
+    }
+}
+
+impl TabPanel {
+    /// Dump the serializable layout of the tab panel, see [`super::DockArea::dump_layout`].
+    pub(super) fn dump_layout(&self, cx: &WindowContext) -> LayoutState {
+        LayoutState::from_dump(&self.dump(cx)).expect("a TabPanel can be restored")
+    }
+}
+
+impl Dock {
+    /// Dump the serializable layout of the dock, see [`super::DockArea::dump_layout`].
+    pub(super) fn dump_layout(&self, cx: &WindowContext) -> DockState {
+        DockState {
+            position: self.position,
+            size: self.size.0.round(),
+            open: self.open,
+            tabs: self.tab_panel.read(cx).dump_layout(cx),
+        }
+    }
+}
+
+/// Return the index of the active panel in the restored panels, the `restored` are
+/// whether the saved panels are restored.
+///
+/// If the active panel can't be restored, the restored panel before it is active, or the first one.
+fn restored_active_ix(active_ix: usize, restored: &[bool]) -> usize {
+    restored
+        .iter()
+        .take(active_ix + 1)
+        .filter(|restored| **restored)
+        .count()
+        .saturating_sub(1)
+}
+
+/// Collect the panels in the `snapshot` that have a key, to reuse them when the layout is loaded.
+pub(super) fn keyed_panels(
+    snapshot: &LayoutSnapshot,
+    cx: &WindowContext,
+) -> HashMap<SharedString, Arc<dyn PanelView>> {
+    let mut result = HashMap::new();
+    let mut stack = vec![snapshot];
+    while let Some(node) = stack.pop() {
+        match node {
+            LayoutSnapshot::Stack { children, .. } => {
+                stack.extend(children.iter().map(|(_, child)| child))
+            }
+            LayoutSnapshot::Tabs { panels, .. } => {
+                for panel in panels {
+                    if let Some(key) = panel.panel_key(cx) {
+                        result.insert(key, panel.clone());
+                    }
+                }
+            }
+            LayoutSnapshot::Panel => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::Axis;
+
+    use super::{restored_active_ix, DockLayout, DockPosition, DockState, LayoutState};
+
+    #[test]
+    fn test_layout_json() {
+        let layout = DockLayout {
+            root: LayoutState::Stack {
+                axis: Axis::Horizontal,
+                sizes: vec![200., 0.],
+                children: vec![
+                    LayoutState::Tabs {
+                        active_ix: 1,
+                        zoomed: false,
+                        pinned: 1,
+                        panels: vec!["a".into(), "b".into()],
+                    },
+                    LayoutState::Stack {
+                        axis: Axis::Vertical,
+                        sizes: vec![],
+                        children: vec![],
+                    },
+                ],
+            },
+            docks: vec![DockState {
+                position: DockPosition::Left,
+                size: 240.,
+                open: false,
+                tabs: LayoutState::Tabs {
+                    active_ix: 0,
+                    zoomed: false,
+                    pinned: 0,
+                    panels: vec!["c".into()],
+                },
+            }],
+            floating: vec![],
+        };
+
+        let value = serde_json::to_value(&layout).unwrap();
+        assert_eq!(value["root"]["type"], "stack");
+        assert_eq!(value["root"]["axis"], "horizontal");
+        assert_eq!(value["root"]["children"][0]["panels"][1], "b");
+        assert_eq!(value["root"]["children"][1]["axis"], "vertical");
+        assert_eq!(value["docks"][0]["position"], "left");
+        assert_eq!(value["docks"][0]["tabs"]["panels"][0], "c");
+        assert!(value.get("floating").is_none());
+        assert_eq!(serde_json::from_value::<DockLayout>(value).unwrap(), layout);
+    }
+
+    #[test]
+    fn test_restored_active_ix() {
+        assert_eq!(restored_active_ix(2, &[true, false, true]), 1);
+        assert_eq!(restored_active_ix(1, &[true, false, true]), 0);
+        assert_eq!(restored_active_ix(0, &[false, true]), 0);
+        assert_eq!(restored_active_ix(3, &[true, true]), 1);
+        assert_eq!(restored_active_ix(0, &[]), 0);
+    }
+}
@@ -1,24 +1,53 @@
-use std::sync::Arc;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
 
 use gpui::{
-    div, prelude::FluentBuilder, rems, AnchorCorner, AppContext, DefiniteLength, DismissEvent,
-    DragMoveEvent, Empty, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
-    IntoElement, ParentElement, Render, ScrollHandle, StatefulInteractiveElement, Styled, View,
-    ViewContext, VisualContext as _, WeakView,
+    canvas, div, prelude::FluentBuilder, px, rems, AnchorCorner, Animation, AnimationExt as _,
+    AnyElement, AppContext, Bounds, DefiniteLength, DismissEvent, DragMoveEvent, ElementId, Empty,
+    EntityId, EventEmitter, ExternalPaths, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, MouseButton, MouseDownEvent, MouseUpEvent, ParentElement, Pixels, Render,
+    ScrollHandle, SharedString, StatefulInteractiveElement, Styled, Subscription, Task, View,
+    ViewContext, VisualContext as _, WeakView, WindowContext,
 };
 use rust_i18n::t;
 
 use crate::{
+    animation::{cubic_bezier, motion_duration},
     button::Button,
+    context_menu::ContextMenuExt as _,
+    focusable::cycle_index,
     h_flex,
-    popup_menu::PopupMenuExt,
+    indicator::Indicator,
+    popup_menu::{PopupMenu, PopupMenuExt},
     tab::{Tab, TabBar},
-    theme::ActiveTheme,
+    theme::{ActiveTheme, Theme},
     tooltip::Tooltip,
-    v_flex, AxisExt, IconName, Placement, Selectable, Sizable, StyledExt,
+    v_flex, AxisExt, Icon, IconName, Placement, Selectable, Sizable, StyledExt,
+};
+
+use super::{
+    begin_layout_transaction, defer_update_dock_area, end_layout_transaction, notify_layout,
+    split_fits, split_sizes, ActivateNextTab, ActivatePrevTab, ActivateTab, CloseAllTabs,
+    CloseOtherTabs, ClosePanel, CloseTabsToRight, DistributeEvenly, DockArea, DockNode,
+    MoveBackToDock, MoveToNewWindow, Panel, PanelView, StackPanel, TogglePinTab, ToggleZoom,
+    DEFAULT_SPLIT_RATIO, PANEL_MIN_SIZE, TAB_PANEL_CONTEXT,
 };
 
-use super::{ClosePanel, DockArea, Panel, PanelView, StackPanel, ToggleZoom};
+/// The duration of the content crossfade when the active tab changed.
+const ACTIVATE_DURATION: Duration = Duration::from_millis(150);
+/// The duration of the tabs to slide into place after a drop.
+const REFLOW_DURATION: Duration = Duration::from_millis(200);
+/// The distance of the tabs to slide from, after a drop.
+const REFLOW_OFFSET: Pixels = px(24.);
+/// The dwell delay to activate the tab, while dragging a panel over it.
+const SPRING_LOADED_DELAY: Duration = Duration::from_millis(600);
+/// The duration to show the hint, after a split is vetoed for not enough space.
+const SPLIT_HINT_DURATION: Duration = Duration::from_millis(1500);
 
 pub enum PanelEvent {
     ZoomIn,
@@ -29,11 +58,26 @@ pub enum PanelEvent {
 pub(crate) struct DragPanel {
     pub(crate) panel: Arc<dyn PanelView>,
     pub(crate) tab_panel: View<TabPanel>,
+    /// The cached title of the dragging tab.
+    title: Option<SharedString>,
+    /// The cached label width of the dragging tab.
+    width: Option<Pixels>,
 }
 
 impl DragPanel {
     pub(crate) fn new(panel: Arc<dyn PanelView>, tab_panel: View<TabPanel>) -> Self {
-        Self { panel, tab_panel }
+        Self {
+            panel,
+            tab_panel,
+            title: None,
+            width: None,
+        }
+    }
+
+    fn cached(mut self, title: SharedString, width: Option<Pixels>) -> Self {
+        self.title = Some(title);
+        self.width = width;
+        self
     }
 }
 
@@ -44,7 +88,10 @@ impl Render for DragPanel {
             .cursor_grab()
             .py_1()
             .px_3()
-            .w_24()
+            .map(|this| match self.width {
+                Some(width) => this.w(width + px(24.)),
+                None => this.w_24(),
+            })
             .overflow_hidden()
             .whitespace_nowrap()
             .border_1()
@@ -52,7 +99,7 @@ impl Render for DragPanel {
             .rounded_md()
             .bg(cx.theme().tab_active)
             .opacity(0.75)
-            .child(self.panel.title(cx))
+            .child(self.title.clone().unwrap_or_else(|| self.panel.title(cx)))
     }
 }
 
@@ -60,27 +107,90 @@ pub struct TabPanel {
     focus_handle: FocusHandle,
     dock_area: WeakView<DockArea>,
     stack_panel: Option<View<StackPanel>>,
-    panels: Vec<Arc<dyn PanelView>>,
-    active_ix: usize,
+    pub(super) panels: Vec<Arc<dyn PanelView>>,
+    pub(super) active_ix: usize,
+    /// The number of the pinned tabs, they are always at the start of the `panels`.
+    pub(super) pinned_count: usize,
     tab_bar_scroll_handle: ScrollHandle,
+    /// The cached tab titles, to avoid recompute them in every frame (e.g.: while dragging).
+    ///
+    /// Refreshed when the panels changed, or a panel is notified with a new title.
+    tab_titles: Vec<SharedString>,
+    /// The measured tab label widths for the drag preview, `None` for the tab not measured yet.
+    ///
+    /// Cleared when the panels or the theme changed.
+    tab_widths: Rc<RefCell<Vec<Option<Pixels>>>>,
+    /// The subscriptions to the notifications of the panels by their entity id, to refresh the titles.
+    panel_subscriptions: HashMap<EntityId, Subscription>,
+
+    pub(super) is_zoomed: bool,
+    /// Whether the tab panel is torn off into its own window, see [`MoveToNewWindow`].
+    pub(super) is_floating: bool,
+    /// The bounds of the tab panel in its window, to find the tab panel that a panel is dragged back to.
+    pub(super) bounds: Rc<Cell<Bounds<Pixels>>>,
+    /// The panel dragging in the floating window, it is docked back when dropped on the main window.
+    dragging_panel: Option<DragPanel>,
 
-    is_zoomed: bool,
+    /// Increased when the active tab changed, to restart the content crossfade.
+    activation_id: usize,
+    /// Increased when a panel dropped into the tabs, to restart the tabs slide.
+    reflow_id: usize,
+    /// The index of the dropped tab, the tabs from it will slide into place.
+    reflow_ix: Option<usize>,
 
     /// When drag move, will get the placement of the panel to be split
     will_split_placement: Option<Placement>,
+    /// The fraction of the new panel to preview, when the `will_split_placement` is set.
+    will_split_fraction: f32,
+    /// Whether the split of the `will_split_placement` is vetoed, it makes a panel smaller than its min size.
+    will_split_blocked: bool,
+    /// The task to hide the "not enough space" hint, it is shown after a vetoed split is dropped.
+    split_hint: Option<Task<()>>,
+    /// The tab to activate after the dwell delay, while dragging a panel over it.
+    spring_loaded_tab: Option<(usize, Task<()>)>,
+    /// Whether the dragging panel is inserted after the hovered tab, it is over the right half of the tab.
+    tab_drop_after: bool,
+
+    _subscriptions: Vec<Subscription>,
 }
 
 impl TabPanel {
     pub fn new(dock_area: WeakView<DockArea>, cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![
+            cx.on_focus_in(&focus_handle, Self::record_navigation),
+            // The font and the font size of the tabs may be changed with the theme.
+            cx.observe_global::<Theme>(|this, cx| {
+                this.tab_widths.borrow_mut().fill(None);
+                cx.notify();
+            }),
+        ];
+
         Self {
-            focus_handle: cx.focus_handle(),
+            focus_handle,
             dock_area,
             stack_panel: None,
             panels: Vec::new(),
             active_ix: 0,
+            pinned_count: 0,
             tab_bar_scroll_handle: ScrollHandle::new(),
+            tab_titles: Vec::new(),
+            tab_widths: Rc::new(RefCell::new(Vec::new())),
+            panel_subscriptions: HashMap::new(),
             will_split_placement: None,
+            will_split_fraction: DEFAULT_SPLIT_RATIO,
+            will_split_blocked: false,
+            split_hint: None,
+            spring_loaded_tab: None,
+            tab_drop_after: false,
             is_zoomed: false,
+            is_floating: false,
+            bounds: Rc::new(Cell::new(Bounds::default())),
+            dragging_panel: None,
+            activation_id: 0,
+            reflow_id: 0,
+            reflow_ix: None,
+            _subscriptions,
         }
     }
 
@@ -93,10 +203,98 @@ impl TabPanel {
         self.panels.get(self.active_ix).cloned()
     }
 
-    fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+    /// Recompute the cached tab titles after the panels changed, the widths are measured again.
+    fn refresh_tab_cache(&mut self, cx: &mut ViewContext<Self>) {
+        self.tab_titles = self.panels.iter().map(|panel| panel.title(cx)).collect();
+        *self.tab_widths.borrow_mut() = vec![None; self.panels.len()];
+    }
+
+    /// Observe the notifications of the added `panel`, to refresh its cached title.
+    fn observe_panel(&mut self, panel: &Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        let view = cx.view().downgrade();
+        let panel_id = panel.view().entity_id();
+        let subscription = panel.observe(
+            cx,
+            Box::new(move |cx| {
+                _ = view.update(cx, |this, cx| this.refresh_tab_title(panel_id, cx));
+            }),
+        );
+        self.panel_subscriptions.insert(panel_id, subscription);
+    }
+
+    /// Refresh the cached title of the notified panel, its width is measured again if the title changed.
+    fn refresh_tab_title(&mut self, panel_id: EntityId, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self
+            .panels
+            .iter()
+            .position(|panel| panel.view().entity_id() == panel_id)
+        else {
+            return;
+        };
+
+        let title = self.panels[ix].title(cx);
+        if self.tab_titles.get(ix) == Some(&title) {
+            return;
+        }
+
+        self.tab_titles[ix] = title;
+        if let Some(width) = self.tab_widths.borrow_mut().get_mut(ix) {
+            *width = None;
+        }
+        cx.notify();
+    }
+
+    /// Dump the layout tree of the tab panel, see [`DockArea::dump`].
+    pub(super) fn dump(&self, cx: &WindowContext) -> DockNode {
+        DockNode::Tabs {
+            active_ix: self.active_ix,
+            titles: self.panels.iter().map(|panel| panel.title(cx)).collect(),
+            keys: self
+                .panels
+                .iter()
+                .map(|panel| panel.panel_key(cx))
+                .collect(),
+            zoomed: self.is_zoomed,
+            pinned_count: self.pinned_count,
+        }
+    }
+
+    pub(super) fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let changed = ix != self.active_ix;
+        if changed {
+            self.activation_id += 1;
+        }
         self.active_ix = ix;
         self.tab_bar_scroll_handle.scroll_to_item(ix);
-        cx.notify();
+        if changed && self.focus_handle.contains_focused(cx) {
+            self.record_navigation(cx);
+        }
+        notify_layout(cx);
+    }
+
+    /// Activate the panel by the entity id of its view, e.g.: navigate back to the panel.
+    pub(super) fn activate_panel(&mut self, panel_id: EntityId, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self
+            .panels
+            .iter()
+            .position(|panel| panel.view().entity_id() == panel_id)
+        {
+            self.set_active_ix(ix, cx);
+        }
+    }
+
+    /// Record the active panel to the navigation history of the DockArea.
+    fn record_navigation(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+
+        let panel_id = panel.view().entity_id();
+        let dock_area = self.dock_area.clone();
+        // Defer it, because the DockArea may be updating, e.g.: navigating back.
+        cx.defer(move |_, cx| {
+            _ = dock_area.update(cx, |dock_area, _| dock_area.push_navigation(panel_id));
+        });
     }
 
     /// Add a panel to the end of the tabs
@@ -109,16 +307,20 @@ impl TabPanel {
             return;
         }
 
+        self.observe_panel(&panel, cx);
         self.panels.push(panel);
+        self.refresh_tab_cache(cx);
         // set the active panel to the new panel
         self.set_active_ix(self.panels.len() - 1, cx);
-        cx.notify();
+        notify_layout(cx);
     }
 
+    /// Insert the panel at `ix`, the `pinned` panel is kept in the pinned panels at the start.
     fn insert_panel_at(
         &mut self,
         panel: Arc<dyn PanelView>,
         ix: usize,
+        pinned: bool,
         cx: &mut ViewContext<Self>,
     ) {
         if self
@@ -129,9 +331,18 @@ impl TabPanel {
             return;
         }
 
+        // The unpinned panels can't be inserted before the pinned panels, and the pinned after them.
+        let ix = if pinned {
+            self.pinned_count += 1;
+            ix.min(self.pinned_count - 1)
+        } else {
+            ix.max(self.pinned_count).min(self.panels.len())
+        };
+        self.observe_panel(&panel, cx);
         self.panels.insert(ix, panel);
+        self.refresh_tab_cache(cx);
         self.set_active_ix(ix, cx);
-        cx.notify();
+        notify_layout(cx);
     }
 
     /// Remove a panel from the tab panel
@@ -142,12 +353,27 @@ impl TabPanel {
 
     fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         let panel_view = panel.view();
+        if let Some(ix) = self.panels.iter().position(|p| p.view() == panel_view) {
+            if ix < self.pinned_count {
+                self.pinned_count -= 1;
+            }
+        }
+        self.panel_subscriptions.remove(&panel_view.entity_id());
         self.panels.retain(|p| p.view() != panel_view);
+        self.refresh_tab_cache(cx);
         if self.active_ix >= self.panels.len() {
             self.set_active_ix(self.panels.len().saturating_sub(1), cx)
         }
     }
 
+    /// Return the index of the `panel` in the tabs.
+    fn index_of(&self, panel: &Arc<dyn PanelView>) -> Option<usize> {
+        let panel_id = panel.view().entity_id();
+        self.panels
+            .iter()
+            .position(|p| p.view().entity_id() == panel_id)
+    }
+
     /// Check to remove self from the parent StackPanel, if there is no panel left
     fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
         if !self.panels.is_empty() {
@@ -159,16 +385,101 @@ impl TabPanel {
             stack_panel.update(cx, |view, cx| {
                 view.remove_panel(tab_view, cx);
             })
+        } else {
+            // A floating or dock tab panel, defer it because the DockArea may be updating (e.g.: tearing off).
+            defer_update_dock_area(self.dock_area.clone(), cx, move |dock_area, cx| {
+                dock_area.remove_floating_panel(tab_view.entity_id(), cx);
+                dock_area.remove_dock(tab_view.entity_id(), cx);
+            });
+        }
+    }
+
+    /// Return true if the panel at `ix` is pinned.
+    pub fn is_pinned(&self, ix: usize) -> bool {
+        ix < self.pinned_count
+    }
+
+    /// Return true if the tab panel is split with the others, to distribute their sizes evenly.
+    fn can_distribute(&self, cx: &AppContext) -> bool {
+        self.stack_panel
+            .as_ref()
+            .map_or(false, |stack_panel| stack_panel.read(cx).panels_len() > 1)
+    }
+
+    /// Pin or unpin the panel at `ix`, the pinned panels are moved to the start of the tabs.
+    pub fn toggle_pin(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.panels.len() {
+            return;
+        }
+
+        let panel = self.panels.remove(ix);
+        let new_ix = if self.is_pinned(ix) {
+            self.pinned_count -= 1;
+            self.pinned_count
+        } else {
+            self.pinned_count += 1;
+            self.pinned_count - 1
+        };
+        self.panels.insert(new_ix, panel);
+        self.refresh_tab_cache(cx);
+        self.set_active_ix(new_ix, cx);
+        notify_layout(cx);
+    }
+
+    /// Close the unpinned panels that `should_close` by their index, the `keep` one stays active.
+    fn close_unpinned_panels(
+        &mut self,
+        keep: Option<usize>,
+        should_close: impl Fn(usize) -> bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let panels = self
+            .panels
+            .iter()
+            .enumerate()
+            .filter(|(ix, panel)| {
+                !self.is_pinned(*ix)
+                    && Some(*ix) != keep
+                    && panel.closeable(cx)
+                    && should_close(*ix)
+            })
+            .map(|(_, panel)| panel.clone())
+            .collect::<Vec<_>>();
+        if panels.is_empty() {
+            return;
         }
+
+        self.record_layout_change(cx);
+        let active_panel = keep.and_then(|ix| self.panels.get(ix).cloned());
+        for panel in panels {
+            self.detach_panel(panel, cx);
+        }
+        if let Some(active_panel) = active_panel {
+            let panel_view = active_panel.view();
+            if let Some(ix) = self.panels.iter().position(|p| p.view() == panel_view) {
+                self.set_active_ix(ix, cx);
+            }
+        }
+        self.remove_self_if_empty(cx);
     }
 
     fn render_menu_button(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let is_zoomed = self.is_zoomed;
+        let is_pinned = self.is_pinned(self.active_ix);
+        let is_floating = self.is_floating;
+        let can_distribute = self.can_distribute(cx);
+        let toolbar_buttons = self
+            .active_panel()
+            .map(|panel| panel.toolbar_buttons(cx))
+            .unwrap_or_default();
 
         h_flex()
             .gap_2()
             .occlude()
             .items_center()
+            .when(!toolbar_buttons.is_empty(), |this| {
+                this.child(h_flex().gap_1().items_center().children(toolbar_buttons))
+            })
             .when(self.is_zoomed, |this| {
                 this.child(
                     Button::new("zoom", cx)
@@ -187,16 +498,18 @@ impl TabPanel {
                     .xsmall()
                     .ghost()
                     .popup_menu(move |this, _| {
-                        this.menu(
-                            if is_zoomed {
-                                t!("Dock.Zoom Out")
-                            } else {
-                                t!("Dock.Zoom In")
-                            },
-                            Box::new(ToggleZoom),
-                        )
-                        .separator()
-                        .menu(t!("Dock.Close"), Box::new(ClosePanel))
+                        // A floating tab panel fills its window, there is nothing to zoom.
+                        this.when(!is_floating, |this| {
+                            this.menu(
+                                if is_zoomed {
+                                    t!("Dock.Zoom Out")
+                                } else {
+                                    t!("Dock.Zoom In")
+                                },
+                                Box::new(ToggleZoom),
+                            )
+                        })
+                        .map(|this| tab_menu_items(this, is_pinned, is_floating, can_distribute))
                     })
                     .anchor(AnchorCorner::TopRight),
             )
@@ -204,10 +517,14 @@ impl TabPanel {
 
     fn render_tabs(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
+        let is_floating = self.is_floating;
+        let can_distribute = self.can_distribute(cx);
 
         if self.panels.len() == 1 {
             let panel = self.panels.get(0).unwrap();
-            let title = panel.title(cx);
+            let title = self.tab_titles[0].clone();
+            let description = panel.description(cx);
+            let pinned = self.is_pinned(0);
 
             return h_flex()
                 .justify_between()
@@ -215,24 +532,43 @@ impl TabPanel {
                 .line_height(rems(1.0))
                 .pr_3()
                 .child(
-                    div()
-                        .id("tab")
-                        .py_2()
-                        .px_3()
-                        .min_w_16()
-                        .overflow_hidden()
-                        .text_ellipsis()
-                        .child(title.clone())
-                        .tooltip(move |cx| Tooltip::new(title.clone(), cx))
-                        .on_drag(
-                            DragPanel {
-                                panel: panel.clone(),
-                                tab_panel: view,
-                            },
-                            |drag, cx| {
-                                cx.stop_propagation();
-                                cx.new_view(|_| drag.clone())
-                            },
+                    h_flex()
+                        .min_w_0()
+                        .when(panel.busy(cx), |this| {
+                            this.pl_3().child(Indicator::new().xsmall())
+                        })
+                        .child(
+                            div()
+                                .id("tab")
+                                .py_2()
+                                .px_3()
+                                .min_w_16()
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .child(title.clone())
+                                .tooltip(move |cx| {
+                                    Tooltip::with_meta(
+                                        title.clone(),
+                                        description.clone(),
+                                        Some(&ActivateTab(0)),
+                                        cx,
+                                    )
+                                })
+                                .on_mouse_up(
+                                    MouseButton::Middle,
+                                    cx.listener(|view, _, cx| view.close_panel_at(0, cx)),
+                                )
+                                .context_menu(move |this, _| {
+                                    tab_menu_items(this, pinned, is_floating, can_distribute)
+                                })
+                                .on_drag(
+                                    DragPanel::new(panel.clone(), view)
+                                        .cached(self.tab_titles[0].clone(), None),
+                                    |drag, cx| {
+                                        cx.stop_propagation();
+                                        cx.new_view(|_| drag.clone())
+                                    },
+                                ),
                         ),
                 )
                 .child(self.render_menu_button(cx))
@@ -240,31 +576,148 @@ impl TabPanel {
         }
 
         let tabs_count = self.panels.len();
+        let tab_panel_id = view.entity_id();
+        let panel_ids = Rc::new(
+            self.panels
+                .iter()
+                .map(|panel| panel.view().entity_id())
+                .collect::<Vec<_>>(),
+        );
 
         TabBar::new("tab-bar")
             .track_scroll(self.tab_bar_scroll_handle.clone())
             .children(self.panels.iter().enumerate().map(|(ix, panel)| {
                 let active = ix == self.active_ix;
-                Tab::new(("tab", ix), panel.title(cx))
+                let title = self.tab_titles[ix].clone();
+                let width = self.tab_widths.borrow().get(ix).copied().flatten();
+                let description = panel.description(cx);
+                let pinned = self.is_pinned(ix);
+                let label = div()
+                    .relative()
+                    .child(title.clone())
+                    // Measure the label once, for the width of the drag preview.
+                    .when(width.is_none(), |this| {
+                        let tab_widths = self.tab_widths.clone();
+                        this.child(
+                            canvas(
+                                move |bounds, _| {
+                                    if let Some(width) = tab_widths.borrow_mut().get_mut(ix) {
+                                        *width = Some(bounds.size.width);
+                                    }
+                                },
+                                |_, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        )
+                    });
+                // The pinned tabs are compact, only show the icon (or the first letter of the title).
+                let label = if pinned {
+                    div().map(|this| match panel.icon(cx) {
+                        Some(icon) => this.child(Icon::new(icon).small()),
+                        None => this.child(title.chars().next().unwrap_or_default().to_string()),
+                    })
+                } else {
+                    label
+                };
+
+                let tooltip_title = title.clone();
+                let panel_ids = panel_ids.clone();
+                let drop_after = self.tab_drop_after;
+                let reflow = self.reflow_ix.map_or(false, |reflow_ix| ix >= reflow_ix);
+
+                Tab::new(("tab", ix), label)
                     .py_2()
+                    .when(pinned, |this| this.px_2())
+                    .when(panel.busy(cx), |this| {
+                        this.prefix(Indicator::new().xsmall().into_any_element())
+                    })
                     .selected(active)
+                    .tooltip(move |cx| {
+                        Tooltip::with_meta(
+                            tooltip_title.clone(),
+                            description.clone(),
+                            Some(&ActivateTab(ix)),
+                            cx,
+                        )
+                    })
                     .on_click(cx.listener(move |view, _, cx| {
                         view.set_active_ix(ix, cx);
                     }))
-                    .on_drag(DragPanel::new(panel.clone(), view.clone()), |drag, cx| {
-                        cx.stop_propagation();
-                        cx.new_view(|_| drag.clone())
+                    .on_mouse_up(
+                        MouseButton::Middle,
+                        cx.listener(move |view, _, cx| view.close_panel_at(ix, cx)),
+                    )
+                    // The actions of the context menu apply to the active tab.
+                    .capture_any_mouse_down(cx.listener(move |view, event: &MouseDownEvent, cx| {
+                        if event.button == MouseButton::Right {
+                            view.set_active_ix(ix, cx);
+                        }
+                    }))
+                    .context_menu(move |this, _| {
+                        tab_menu_items(this, pinned, is_floating, can_distribute)
                     })
-                    .drag_over::<DragPanel>(|this, _, cx| {
-                        this.rounded_l_none()
-                            .border_l_2()
-                            .border_r_0()
-                            .border_color(cx.theme().drag_border)
+                    .on_drag(
+                        DragPanel::new(panel.clone(), view.clone()).cached(title, width),
+                        |drag, cx| {
+                            cx.stop_propagation();
+                            cx.new_view(|_| drag.clone())
+                        },
+                    )
+                    .on_drag_move(
+                        cx.listener(move |this, drag: &DragMoveEvent<DragPanel>, cx| {
+                            this.on_tab_drag_move(ix, drag, cx)
+                        }),
+                    )
+                    .drag_over::<DragPanel>(move |this, drag, cx| {
+                        // No indicator if the tab will stay in place.
+                        let drag_id = drag.panel.view().entity_id();
+                        let from = (drag.tab_panel.entity_id() == tab_panel_id)
+                            .then(|| panel_ids.iter().position(|id| *id == drag_id))
+                            .flatten();
+                        if from.map_or(false, |from| {
+                            reorder_index(Some(from), ix + drop_after as usize) == from
+                        }) {
+                            return this;
+                        }
+
+                        this.border_color(cx.theme().drag_border).map(|this| {
+                            if drop_after {
+                                this.rounded_r_none().border_r_2().border_l_0()
+                            } else {
+                                this.rounded_l_none().border_l_2().border_r_0()
+                            }
+                        })
                     })
                     .on_drop(cx.listener(move |this, drag: &DragPanel, cx| {
                         this.will_split_placement = None;
+                        let from = if drag.tab_panel == *cx.view() {
+                            this.index_of(&drag.panel)
+                        } else {
+                            None
+                        };
+                        let ix = reorder_index(from, ix + this.tab_drop_after as usize);
                         this.on_drop(drag, Some(ix), cx)
                     }))
+                    .drag_over::<ExternalPaths>(|this, _, cx| this.bg(cx.theme().drop_target))
+                    .on_drop(cx.listener(move |this, paths: &ExternalPaths, cx| {
+                        this.on_drop_files(ix, paths, cx)
+                    }))
+                    .with_animation(
+                        ElementId::NamedInteger(
+                            format!("tab-reflow-{}", ix).into(),
+                            self.reflow_id,
+                        ),
+                        Animation::new(motion_duration(REFLOW_DURATION, cx))
+                            .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                        move |this, delta| {
+                            if reflow {
+                                this.left(-REFLOW_OFFSET * (1. - delta))
+                            } else {
+                                this
+                            }
+                        },
+                    )
             }))
             .child(
                 // empty space to allow move to last tab right
@@ -317,33 +770,117 @@ impl TabPanel {
                         div()
                             .invisible()
                             .absolute()
-                            .bg(cx.theme().drop_target)
+                            .top_0()
+                            .left_0()
+                            .size_full()
                             .map(|this| match self.will_split_placement {
-                                Some(placement) => {
-                                    let size = DefiniteLength::Fraction(0.25);
-                                    match placement {
-                                        Placement::Left => this.left_0().top_0().bottom_0().w(size),
-                                        Placement::Right => {
-                                            this.right_0().top_0().bottom_0().w(size)
-                                        }
-                                        Placement::Top => this.top_0().left_0().right_0().h(size),
-                                        Placement::Bottom => {
-                                            this.bottom_0().left_0().right_0().h(size)
-                                        }
-                                    }
+                                Some(_) if self.will_split_blocked => {
+                                    this.child(self.render_split_hint(cx))
                                 }
-                                None => this.top_0().left_0().size_full(),
+                                Some(placement) => this.child(self.render_split_preview(
+                                    placement,
+                                    self.will_split_fraction,
+                                    cx,
+                                )),
+                                None => this.bg(cx.theme().drop_target),
                             })
                             .group_drag_over::<DragPanel>("", |this| this.visible())
+                            .group_drag_over::<ExternalPaths>("", |this| this.visible())
                             .on_drop(cx.listener(|this, drag: &DragPanel, cx| {
                                 this.on_drop(drag, None, cx)
+                            }))
+                            .on_drop(cx.listener(|this, paths: &ExternalPaths, cx| {
+                                this.on_drop_files(this.active_ix, paths, cx)
                             })),
                     )
+                    .when(self.split_hint.is_some(), |this| {
+                        this.child(
+                            div()
+                                .absolute()
+                                .top_0()
+                                .left_0()
+                                .size_full()
+                                .child(self.render_split_hint(cx)),
+                        )
+                    })
+                    .with_animation(
+                        ElementId::NamedInteger("tab-content-fade".into(), self.activation_id),
+                        Animation::new(motion_duration(ACTIVATE_DURATION, cx))
+                            .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                        |this, delta| this.opacity(0.5 + 0.5 * delta),
+                    )
                     .into_any_element()
             })
             .unwrap_or(Empty {}.into_any_element())
     }
 
+    /// Preview the layout after split, the new panel and the shrunken current panel.
+    fn render_split_preview(
+        &self,
+        placement: Placement,
+        fraction: f32,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let new_size = DefiniteLength::Fraction(fraction);
+        let new_panel = div()
+            .flex_shrink_0()
+            .bg(cx.theme().drop_target)
+            .border_2()
+            .border_color(cx.theme().drag_border)
+            .map(|this| {
+                if placement.is_horizontal() {
+                    this.h_full().w(new_size)
+                } else {
+                    this.w_full().h(new_size)
+                }
+            });
+        let current_panel = div()
+            .flex_1()
+            .border_1()
+            .border_color(cx.theme().drag_border.opacity(0.5));
+
+        div()
+            .flex()
+            .size_full()
+            .map(|this| {
+                if placement.is_horizontal() {
+                    this.flex_row()
+                } else {
+                    this.flex_col()
+                }
+            })
+            .map(|this| match placement {
+                Placement::Left | Placement::Top => this.child(new_panel).child(current_panel),
+                Placement::Right | Placement::Bottom => this.child(current_panel).child(new_panel),
+            })
+    }
+
+    /// The hint instead of the split preview, when the split is vetoed for not enough space.
+    fn render_split_hint(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex().size_full().justify_center().child(
+            div()
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .bg(cx.theme().popover)
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(t!("Dock.Not enough space").to_string()),
+        )
+    }
+
+    /// Show the "not enough space" hint for a while.
+    fn show_split_hint(&mut self, cx: &mut ViewContext<Self>) {
+        self.split_hint = Some(cx.spawn(|view, mut cx| async move {
+            cx.background_executor().timer(SPLIT_HINT_DURATION).await;
+            _ = view.update(&mut cx, |view, cx| {
+                view.split_hint = None;
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+
     /// Calculate the split direction based on the current mouse position
     fn on_panel_drag_move(&mut self, drag: &DragMoveEvent<DragPanel>, cx: &mut ViewContext<Self>) {
         let bounds = drag.bounds;
@@ -362,13 +899,225 @@ impl TabPanel {
             // center to merge into the current tab
             self.will_split_placement = None;
         }
+
+        // Merge into the tabs, if the split is too deep, or the tab panel is floating.
+        if let Some(placement) = self.will_split_placement {
+            if self.stack_panel.is_none() || self.exceeds_split_depth(placement, cx) {
+                self.will_split_placement = None;
+            }
+        }
+
+        self.will_split_blocked = false;
+        if let Some(placement) = self.will_split_placement {
+            let panel = drag.drag(cx).panel.clone();
+            self.will_split_fraction = match self.split_sizes(&panel, placement, cx) {
+                Some((new_size, rest_size)) => new_size / (new_size + rest_size),
+                None => self.split_ratio(cx),
+            };
+            self.will_split_blocked = !self.can_split(&panel, placement, cx);
+        }
         cx.notify()
     }
 
-    fn on_drop(&mut self, drag: &DragPanel, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+    fn split_ratio(&self, cx: &AppContext) -> f32 {
+        self.dock_area
+            .upgrade()
+            .map_or(DEFAULT_SPLIT_RATIO, |dock_area| {
+                dock_area.read(cx).split_ratio()
+            })
+    }
+
+    /// Return the size of the new panel and the rest size of this panel, after split by the `placement`.
+    fn split_sizes(
+        &self,
+        panel: &Arc<dyn PanelView>,
+        placement: Placement,
+        cx: &ViewContext<Self>,
+    ) -> Option<(Pixels, Pixels)> {
+        let stack_panel = self.stack_panel.as_ref()?.read(cx);
+        let ix = stack_panel.index_of_panel(cx.view().clone())?;
+        let size = stack_panel.split_target_size(ix, placement, cx);
+
+        split_sizes(size, self.split_ratio(cx), panel.preferred_size(cx))
+    }
+
+    /// Return whether the split by the `placement` needs a nested stack panel beyond the max split depth.
+    fn exceeds_split_depth(&self, placement: Placement, cx: &ViewContext<Self>) -> bool {
+        let Some(max_depth) = self
+            .dock_area
+            .upgrade()
+            .and_then(|dock_area| dock_area.read(cx).max_split_depth())
+        else {
+            return false;
+        };
+        let Some(stack_panel) = self.stack_panel.as_ref().map(|view| view.read(cx)) else {
+            return false;
+        };
+
+        // The split along the axis of the parent, or of a single panel stack (its axis is changed),
+        // is added to the parent without nesting.
+        if stack_panel.axis == placement.axis() || stack_panel.panels_len() <= 1 {
+            return false;
+        }
+
+        stack_panel.depth(cx) + 1 > max_depth
+    }
+
+    /// Return whether the split keeps both the new panel and this panel not smaller than their min sizes.
+    fn can_split(
+        &self,
+        panel: &Arc<dyn PanelView>,
+        placement: Placement,
+        cx: &ViewContext<Self>,
+    ) -> bool {
+        let Some(sizes) = self.split_sizes(panel, placement, cx) else {
+            return true;
+        };
+
+        // The dragged panel may be one of this panel, it is moved out after split.
+        let rest_min_size = self
+            .panels
+            .iter()
+            .filter(|p| p.view() != panel.view())
+            .map(|p| p.min_size(cx))
+            .max()
+            .unwrap_or(PANEL_MIN_SIZE);
+
+        split_fits(sizes, panel.min_size(cx), rest_min_size)
+    }
+
+    /// Activate the tab after the dwell delay, while dragging a panel over it (like spring-loaded folders),
+    /// so the panel can be dropped into the content of a background tab.
+    fn on_tab_drag_move(
+        &mut self,
+        ix: usize,
+        drag: &DragMoveEvent<DragPanel>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let is_hovered = drag.bounds.contains(&drag.event.position);
+        if is_hovered {
+            let drop_after =
+                drag.event.position.x > drag.bounds.left() + drag.bounds.size.width / 2.;
+            if drop_after != self.tab_drop_after {
+                self.tab_drop_after = drop_after;
+                cx.notify();
+            }
+        }
+
+        let is_pending = self
+            .spring_loaded_tab
+            .as_ref()
+            .map_or(false, |(pending_ix, _)| *pending_ix == ix);
+
+        if !is_hovered || ix == self.active_ix {
+            if is_pending {
+                self.spring_loaded_tab = None;
+            }
+            return;
+        }
+
+        if is_pending {
+            return;
+        }
+
+        let task = cx.spawn(|view, mut cx| async move {
+            cx.background_executor().timer(SPRING_LOADED_DELAY).await;
+            _ = view.update(&mut cx, |view, cx| {
+                view.spring_loaded_tab = None;
+                // The drag may be canceled or dropped during the delay.
+                if cx.has_active_drag() {
+                    view.set_active_ix(ix, cx);
+                }
+            });
+        });
+        self.spring_loaded_tab = Some((ix, task));
+    }
+
+    /// Activate the panel at `ix`, and route the files dropped from the OS to it.
+    fn on_drop_files(&mut self, ix: usize, paths: &ExternalPaths, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+
+        self.set_active_ix(ix, cx);
+        panel.handle_dropped_files(paths, cx);
+    }
+
+    pub(super) fn on_drop(
+        &mut self,
+        drag: &DragPanel,
+        ix: Option<usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.spring_loaded_tab = None;
+        // Batch the cascaded updates of the panels, to notify once after the drop.
+        begin_layout_transaction(cx);
+        self.drop_panel(drag, ix, cx);
+        end_layout_transaction(cx);
+    }
+
+    /// Track the bounds of the tab panel, and in a floating window, dock the panel dropped outside of it
+    /// back into the tab panel under the mouse, see [`DockArea::dock_dragged_panel`].
+    fn render_drag_back(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let view = cx.view().clone();
+        let tab_bounds = self.bounds.clone();
+        let is_floating = self.is_floating;
+
+        canvas(
+            move |bounds, _| {
+                tab_bounds.set(bounds);
+                bounds
+            },
+            move |_, bounds, cx| {
+                if !is_floating {
+                    return;
+                }
+
+                cx.on_mouse_event(move |event: &MouseUpEvent, phase, cx| {
+                    // The drop is handled in the bubble phase, check it before that.
+                    let Some(drag) = view.read(cx).dragging_panel.clone() else {
+                        return;
+                    };
+                    if phase.bubble() {
+                        view.update(cx, |view, _| view.dragging_panel = None);
+                    } else if !bounds.contains(&event.position) {
+                        let position = cx.bounds().origin + event.position;
+                        let dock_area = view.read(cx).dock_area.clone();
+                        defer_update_dock_area(dock_area, cx, move |dock_area, cx| {
+                            dock_area.dock_dragged_panel(drag, position, cx)
+                        });
+                    }
+                });
+            },
+        )
+        .absolute()
+        .size_full()
+        .into_any_element()
+    }
+
+    /// Drop the `drag` panel like the mouse does, to split this panel by the `placement`,
+    /// or into the tabs at `ix` if the `placement` is `None`.
+    #[cfg(test)]
+    pub(super) fn simulate_drop(
+        &mut self,
+        drag: &DragPanel,
+        placement: Option<Placement>,
+        ix: Option<usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.will_split_placement = placement;
+        self.on_drop(drag, ix, cx);
+    }
+
+    fn drop_panel(&mut self, drag: &DragPanel, ix: Option<usize>, cx: &mut ViewContext<Self>) {
         let panel = drag.panel.clone();
         let is_same_tab = drag.tab_panel == *cx.view();
 
+        // Drop the tab to its own position, do nothing.
+        if is_same_tab && ix.is_some() && ix == self.index_of(&panel) {
+            return;
+        }
+
         // If target is same tab, and it is only one panel, do nothing.
         if is_same_tab && ix.is_none() {
             if self.will_split_placement.is_none() {
@@ -380,6 +1129,21 @@ impl TabPanel {
             }
         }
 
+        // Veto the split before the panel is detached from its tab panel.
+        if let Some(placement) = self.will_split_placement {
+            if !self.can_split(&panel, placement, cx) {
+                self.will_split_placement = None;
+                self.show_split_hint(cx);
+                return;
+            }
+        }
+
+        self.record_layout_change(cx);
+        // Keep the pinned panel pinned when it is reordered in the same tabs.
+        let pinned = is_same_tab
+            && ix.is_some()
+            && self.index_of(&panel).map_or(false, |ix| self.is_pinned(ix));
+
         // Here is looks like remove_panel on a same item, but it differnece.
         //
         // We must to split it to remove_panel, unless it will be crash by error:
@@ -398,10 +1162,14 @@ impl TabPanel {
             self.split_panel(panel, placement, cx);
         } else {
             if let Some(ix) = ix {
-                self.insert_panel_at(panel, ix, cx)
+                self.insert_panel_at(panel, ix, pinned, cx)
             } else {
                 self.add_panel(panel, cx)
             }
+
+            // The dropped panel is activated, let the tabs from it slide into place.
+            self.reflow_id += 1;
+            self.reflow_ix = Some(self.active_ix);
         }
 
         self.remove_self_if_empty(cx);
@@ -415,6 +1183,7 @@ impl TabPanel {
         cx: &mut ViewContext<Self>,
     ) {
         let dock_area = self.dock_area.clone();
+        let sizes = self.split_sizes(&panel, placement, cx);
         // wrap the panel in a TabPanel
         let new_tab_panel = cx.new_view(|cx| Self::new(dock_area.clone(), cx));
         new_tab_panel.update(cx, |view, cx| {
@@ -428,13 +1197,21 @@ impl TabPanel {
             .index_of_panel(cx.view().clone())
             .unwrap_or_default();
 
-        if parent_axis.is_vertical() && placement.is_vertical() {
-            stack_panel.update(cx, |view, cx| {
-                view.add_panel_at(new_tab_panel, ix, placement, dock_area.clone(), cx);
-            });
-        } else if parent_axis.is_horizontal() && placement.is_horizontal() {
+        // The index of the new panel and this panel in the stack, after split.
+        let (new_ix, this_ix) = match placement {
+            Placement::Left | Placement::Top => (ix, ix + 1),
+            Placement::Right | Placement::Bottom => (ix + 1, ix),
+        };
+
+        if (parent_axis.is_vertical() && placement.is_vertical())
+            || (parent_axis.is_horizontal() && placement.is_horizontal())
+        {
             stack_panel.update(cx, |view, cx| {
                 view.add_panel_at(new_tab_panel, ix, placement, dock_area.clone(), cx);
+                if let Some((new_size, rest_size)) = sizes {
+                    view.resize_panel(new_ix, new_size, cx);
+                    view.resize_panel(this_ix, rest_size, cx);
+                }
             });
         } else {
             // 1. Create new StackPanel with new axis
@@ -458,14 +1235,22 @@ impl TabPanel {
                 })
             };
 
-            new_stack_panel.update(cx, |view, cx| match placement {
-                Placement::Left | Placement::Top => {
-                    view.add_panel(new_tab_panel, None, dock_area.clone(), cx);
-                    view.add_panel(tab_panel.clone(), None, dock_area.clone(), cx);
+            new_stack_panel.update(cx, |view, cx| {
+                match placement {
+                    Placement::Left | Placement::Top => {
+                        view.add_panel(new_tab_panel, None, dock_area.clone(), cx);
+                        view.add_panel(tab_panel.clone(), None, dock_area.clone(), cx);
+                    }
+                    Placement::Right | Placement::Bottom => {
+                        view.add_panel(tab_panel.clone(), None, dock_area.clone(), cx);
+                        view.add_panel(new_tab_panel, None, dock_area.clone(), cx);
+                    }
                 }
-                Placement::Right | Placement::Bottom => {
-                    view.add_panel(tab_panel.clone(), None, dock_area.clone(), cx);
-                    view.add_panel(new_tab_panel, None, dock_area.clone(), cx);
+
+                if let Some((new_size, rest_size)) = sizes {
+                    let (new_ix, this_ix) = if new_ix < this_ix { (0, 1) } else { (1, 0) };
+                    view.resize_panel(new_ix, new_size, cx);
+                    view.resize_panel(this_ix, rest_size, cx);
                 }
             });
 
@@ -491,14 +1276,119 @@ impl TabPanel {
         }
     }
 
+    fn on_action_activate_tab(&mut self, action: &ActivateTab, cx: &mut ViewContext<Self>) {
+        if action.0 < self.panels.len() {
+            self.set_active_ix(action.0, cx);
+        }
+    }
+
+    fn on_action_activate_next_tab(&mut self, _: &ActivateNextTab, cx: &mut ViewContext<Self>) {
+        self.activate_adjacent_tab(true, cx);
+    }
+
+    fn on_action_activate_prev_tab(&mut self, _: &ActivatePrevTab, cx: &mut ViewContext<Self>) {
+        self.activate_adjacent_tab(false, cx);
+    }
+
+    /// Activate the next (or the previous) tab, wrapping around the ends.
+    fn activate_adjacent_tab(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let len = self.panels.len();
+        if len <= 1 {
+            return;
+        }
+
+        if let Some(ix) = cycle_index(self.active_ix, len, forward, true) {
+            self.set_active_ix(ix, cx);
+        }
+    }
+
+    fn on_action_toggle_pin(&mut self, _: &TogglePinTab, cx: &mut ViewContext<Self>) {
+        self.toggle_pin(self.active_ix, cx);
+    }
+
+    fn on_action_close_others(&mut self, _: &CloseOtherTabs, cx: &mut ViewContext<Self>) {
+        self.close_unpinned_panels(Some(self.active_ix), |_| true, cx);
+    }
+
+    fn on_action_close_to_right(&mut self, _: &CloseTabsToRight, cx: &mut ViewContext<Self>) {
+        let active_ix = self.active_ix;
+        self.close_unpinned_panels(Some(active_ix), |ix| ix > active_ix, cx);
+    }
+
+    fn on_action_close_all(&mut self, _: &CloseAllTabs, cx: &mut ViewContext<Self>) {
+        self.close_unpinned_panels(None, |_| true, cx);
+    }
+
+    fn on_action_distribute_evenly(&mut self, _: &DistributeEvenly, cx: &mut ViewContext<Self>) {
+        let Some(stack_panel) = self.stack_panel.clone() else {
+            return;
+        };
+
+        self.record_layout_change(cx);
+        stack_panel.update(cx, |view, cx| view.equalize_sizes(cx));
+    }
+
+    fn on_action_move_to_new_window(&mut self, _: &MoveToNewWindow, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        // The only panel of a floating tab panel is already in its own window.
+        if self.is_floating && self.panels.len() == 1 {
+            return;
+        }
+
+        let tab_panel = cx.view().clone();
+        defer_update_dock_area(self.dock_area.clone(), cx, move |dock_area, cx| {
+            dock_area.float_panel(panel, tab_panel, None, cx)
+        });
+    }
+
+    fn on_action_move_back_to_dock(&mut self, _: &MoveBackToDock, cx: &mut ViewContext<Self>) {
+        if !self.is_floating {
+            return;
+        }
+
+        let tab_panel_id = cx.view().entity_id();
+        defer_update_dock_area(self.dock_area.clone(), cx, move |dock_area, cx| {
+            dock_area.dock_floating_panel(tab_panel_id, cx)
+        });
+    }
+
     fn on_action_close_panel(&mut self, _: &ClosePanel, cx: &mut ViewContext<Self>) {
-        if let Some(panel) = self.active_panel() {
-            self.remove_panel(panel, cx);
+        self.close_panel_at(self.active_ix, cx);
+    }
+
+    /// Close the panel at `ix` (e.g.: by the middle click on its tab), if the panel is closeable.
+    fn close_panel_at(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+        if !panel.closeable(cx) {
+            return;
         }
+
+        self.record_layout_change(cx);
+        self.remove_panel(panel, cx);
+    }
+
+    /// Record the current layout to the undo stack of the DockArea, before a structural change.
+    fn record_layout_change(&self, cx: &mut ViewContext<Self>) {
+        let panel_id = cx.view().entity_id();
+        let snapshot = self.snapshot();
+        _ = self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.push_layout_undo(Some((panel_id, &snapshot)), cx)
+        });
     }
 }
 
-impl Panel for TabPanel {}
+impl Panel for TabPanel {
+    fn on_resize(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
+        // The panels share the bounds, the inactive panels can prepare the layout before activated.
+        for panel in self.panels.clone() {
+            panel.on_resize(bounds, cx);
+        }
+    }
+}
 impl FocusableView for TabPanel {
     fn focus_handle(&self, _cx: &AppContext) -> gpui::FocusHandle {
         // FIXME: Delegate to the active panel
@@ -511,13 +1401,96 @@ impl Render for TabPanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
         v_flex()
             .id("tab-panel")
+            .key_context(TAB_PANEL_CONTEXT)
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::on_action_toggle_zoom))
+            .on_action(cx.listener(Self::on_action_activate_tab))
+            .on_action(cx.listener(Self::on_action_activate_next_tab))
+            .on_action(cx.listener(Self::on_action_activate_prev_tab))
             .on_action(cx.listener(Self::on_action_close_panel))
+            .on_action(cx.listener(Self::on_action_toggle_pin))
+            .on_action(cx.listener(Self::on_action_close_others))
+            .on_action(cx.listener(Self::on_action_close_to_right))
+            .on_action(cx.listener(Self::on_action_close_all))
+            .on_action(cx.listener(Self::on_action_distribute_evenly))
+            .on_action(cx.listener(Self::on_action_move_to_new_window))
+            .on_action(cx.listener(Self::on_action_move_back_to_dock))
+            .when(self.is_floating, |this| {
+                this.on_drag_move(cx.listener(|this, drag: &DragMoveEvent<DragPanel>, cx| {
+                    this.dragging_panel = Some(drag.drag(cx).clone());
+                }))
+            })
+            .relative()
             .size_full()
             .overflow_hidden()
             .bg(cx.theme().background)
             .child(self.render_tabs(cx))
             .child(self.render_active_panel(cx))
+            .child(self.render_drag_back(cx))
+    }
+}
+
+/// Append the menu items of the active tab, to the menu button and the context menu of the tabs.
+///
+/// The `can_distribute` is true if the tab panel is split with the others, see [`DistributeEvenly`].
+fn tab_menu_items(
+    menu: PopupMenu,
+    is_pinned: bool,
+    is_floating: bool,
+    can_distribute: bool,
+) -> PopupMenu {
+    menu.menu(
+        if is_pinned {
+            t!("Dock.Unpin Tab")
+        } else {
+            t!("Dock.Pin Tab")
+        },
+        Box::new(TogglePinTab),
+    )
+    .separator()
+    .menu(t!("Dock.Close"), Box::new(ClosePanel))
+    .menu(t!("Dock.Close Others"), Box::new(CloseOtherTabs))
+    .menu(
+        t!("Dock.Close Tabs to the Right"),
+        Box::new(CloseTabsToRight),
+    )
+    .menu(t!("Dock.Close All"), Box::new(CloseAllTabs))
+    .when(can_distribute, |this| {
+        this.separator()
+            .menu(t!("Dock.Distribute Evenly"), Box::new(DistributeEvenly))
+    })
+    .separator()
+    .map(|this| {
+        if is_floating {
+            this.menu(t!("Dock.Move Back to Dock"), Box::new(MoveBackToDock))
+        } else {
+            this.menu(t!("Dock.Move to New Window"), Box::new(MoveToNewWindow))
+        }
+    })
+}
+
+/// Return the index to insert the dropped tab, after it is removed from the index `from` of the same tabs.
+///
+/// The `to` is the insertion index in the tabs before the removal.
+fn reorder_index(from: Option<usize>, to: usize) -> usize {
+    match from {
+        Some(from) if from < to => to - 1,
+        _ => to,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reorder_index;
+
+    #[test]
+    fn test_reorder_index() {
+        assert_eq!(reorder_index(None, 2), 2);
+        // Move to the right, the index is shifted by the removal.
+        assert_eq!(reorder_index(Some(0), 3), 2);
+        assert_eq!(reorder_index(Some(1), 2), 1);
+        // Move to the left.
+        assert_eq!(reorder_index(Some(3), 1), 1);
+        assert_eq!(reorder_index(Some(2), 2), 2);
     }
 }
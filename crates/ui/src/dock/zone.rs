@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Axis, DragMoveEvent, EntityId,
+    InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render,
+    StatefulInteractiveElement as _, Styled as _, View, ViewContext, VisualContext as _,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{
+    DockArea, PanelView, TabPanel, ToggleBottomDock, ToggleLeftDock, ToggleRightDock,
+    PANEL_MIN_SIZE,
+};
+
+/// The position of a [`DockArea`] dock, around the center of the dock area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DockPosition {
+    Left,
+    Right,
+    Bottom,
+}
+
+impl DockPosition {
+    /// Return the axis the dock is resized along.
+    pub fn axis(&self) -> Axis {
+        match self {
+            Self::Left | Self::Right => Axis::Horizontal,
+            Self::Bottom => Axis::Vertical,
+        }
+    }
+}
+
+/// A collapsible zone of the dock area, its size is kept when it is closed.
+pub(super) struct Dock {
+    pub(super) position: DockPosition,
+    pub(super) tab_panel: View<TabPanel>,
+    pub(super) size: Pixels,
+    pub(super) open: bool,
+}
+
+/// Drag to resize the dock at the position.
+#[derive(Clone, Render)]
+pub(super) struct DragDockResize(DockPosition);
+
+/// Keep the `size` of a dock in the `max` size of the dock area, leaving the min size to the center.
+fn clamp_dock_size(size: Pixels, max: Pixels) -> Pixels {
+    size.min(max - PANEL_MIN_SIZE).max(PANEL_MIN_SIZE)
+}
+
+/// Shrink the sizes of the `left` and `right` docks proportionally to fit in the `width`
+/// of the dock area, leaving the min size to the center.
+fn fit_side_docks(left: Pixels, right: Pixels, width: Pixels) -> (Pixels, Pixels) {
+    let available = (width - PANEL_MIN_SIZE).max(px(0.));
+    let total = left + right;
+    if total <= available {
+        return (left, right);
+    }
+
+    let ratio = available / total;
+    (left * ratio, right * ratio)
+}
+
+impl DockArea {
+    /// Set the `panel` into the dock at the `position` with the `size`, the dock is opened.
+    ///
+    /// The panels of the existing dock at the position are replaced.
+    pub fn set_dock(
+        &mut self,
+        position: DockPosition,
+        panel: Arc<dyn PanelView>,
+        size: Pixels,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let dock_area = cx.view().downgrade();
+        let tab_panel = cx.new_view(|cx| TabPanel::new(dock_area, cx));
+        tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+        self.insert_dock(position, tab_panel, size, true, cx);
+    }
+
+    /// Insert the dock of the `tab_panel` at the `position`, the existing dock at the position is replaced.
+    pub(super) fn insert_dock(
+        &mut self,
+        position: DockPosition,
+        tab_panel: View<TabPanel>,
+        size: Pixels,
+        open: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.subscribe_zoom(&tab_panel, cx);
+        self.docks.retain(|dock| dock.position != position);
+        self.docks.push(Dock {
+            position,
+            tab_panel,
+            size,
+            open,
+        });
+        cx.notify();
+    }
+
+    /// Return true if there is a dock at the `position` and it is open.
+    pub fn is_dock_open(&self, position: DockPosition) -> bool {
+        self.dock(position).map_or(false, |dock| dock.open)
+    }
+
+    /// Open or close the dock at the `position`, its size is kept to reopen it.
+    pub fn set_dock_open(
+        &mut self,
+        position: DockPosition,
+        open: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(dock) = self.docks.iter_mut().find(|dock| dock.position == position) {
+            dock.open = open;
+            cx.notify();
+        }
+    }
+
+    /// Toggle the dock at the `position`, see [`DockArea::set_dock_open`].
+    pub fn toggle_dock(&mut self, position: DockPosition, cx: &mut ViewContext<Self>) {
+        let open = self.is_dock_open(position);
+        self.set_dock_open(position, !open, cx);
+    }
+
+    fn dock(&self, position: DockPosition) -> Option<&Dock> {
+        self.docks.iter().find(|dock| dock.position == position)
+    }
+
+    /// Return the size of the open dock at the `position`, if it is open.
+    fn open_dock_size(&self, position: DockPosition) -> Option<Pixels> {
+        self.dock(position)
+            .filter(|dock| dock.open)
+            .map(|dock| dock.size)
+    }
+
+    /// Return the rendered size of the dock at the `position`, it is fitted in the dock area,
+    /// e.g.: the window is resized smaller than the docks.
+    fn fitted_dock_size(&self, position: DockPosition) -> Option<Pixels> {
+        let size = self.open_dock_size(position)?;
+        let bounds = self.bounds.get().size;
+        // Not measured before the first paint.
+        if bounds.width <= px(0.) {
+            return Some(size);
+        }
+
+        let size = match position {
+            DockPosition::Left | DockPosition::Right => {
+                let left = self.open_dock_size(DockPosition::Left).unwrap_or_default();
+                let right = self.open_dock_size(DockPosition::Right).unwrap_or_default();
+                let (left, right) = fit_side_docks(left, right, bounds.width);
+                if position == DockPosition::Left {
+                    left
+                } else {
+                    right
+                }
+            }
+            DockPosition::Bottom => size.min((bounds.height - PANEL_MIN_SIZE).max(px(0.))),
+        };
+        Some(size)
+    }
+
+    /// Remove the dock of the tab panel, e.g.: its last panel is dragged into the center.
+    pub(super) fn remove_dock(&mut self, tab_panel_id: EntityId, cx: &mut ViewContext<Self>) {
+        self.docks
+            .retain(|dock| dock.tab_panel.entity_id() != tab_panel_id);
+        cx.notify();
+    }
+
+    pub(super) fn resize_dock(
+        &mut self,
+        drag: &DragMoveEvent<DragDockResize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let position = drag.drag(cx).0;
+        let bounds = drag.bounds;
+        let mouse = drag.event.position;
+        // The left and right docks share the width, the center keeps its min size.
+        let (size, max) = match position {
+            DockPosition::Left => (
+                mouse.x - bounds.left(),
+                bounds.size.width - self.open_dock_size(DockPosition::Right).unwrap_or_default(),
+            ),
+            DockPosition::Right => (
+                bounds.right() - mouse.x,
+                bounds.size.width - self.open_dock_size(DockPosition::Left).unwrap_or_default(),
+            ),
+            DockPosition::Bottom => (bounds.bottom() - mouse.y, bounds.size.height),
+        };
+
+        if let Some(dock) = self.docks.iter_mut().find(|dock| dock.position == position) {
+            dock.size = clamp_dock_size(size, max);
+            cx.notify();
+        }
+    }
+
+    pub(super) fn on_action_toggle_left_dock(
+        &mut self,
+        _: &ToggleLeftDock,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.toggle_dock(DockPosition::Left, cx);
+    }
+
+    pub(super) fn on_action_toggle_right_dock(
+        &mut self,
+        _: &ToggleRightDock,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.toggle_dock(DockPosition::Right, cx);
+    }
+
+    pub(super) fn on_action_toggle_bottom_dock(
+        &mut self,
+        _: &ToggleBottomDock,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.toggle_dock(DockPosition::Bottom, cx);
+    }
+
+    /// Render the open dock at the `position` with its resize handle on the side of the center.
+    pub(super) fn render_dock(
+        &self,
+        position: DockPosition,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<AnyElement> {
+        let dock = self.dock(position).filter(|dock| dock.open)?;
+        let size = self.fitted_dock_size(position)?;
+        let handle_size = px(cx.theme().resize_handle_size);
+        let hover_color = cx.theme().resize_handle_hover;
+
+        let handle = div()
+            .id(("dock-resize-handle", position as usize))
+            .flex_none()
+            .bg(cx.theme().resize_handle)
+            .hover(|this| this.bg(hover_color))
+            .map(|this| match position.axis() {
+                Axis::Horizontal => this.h_full().w(handle_size).cursor_col_resize(),
+                Axis::Vertical => this.w_full().h(handle_size).cursor_row_resize(),
+            })
+            .on_drag(DragDockResize(position), |drag, cx| {
+                cx.stop_propagation();
+                cx.new_view(|_| drag.clone())
+            });
+
+        let panel = div()
+            .flex_none()
+            .overflow_hidden()
+            .map(|this| match position.axis() {
+                Axis::Horizontal => this.h_full().w(size),
+                Axis::Vertical => this.w_full().h(size),
+            })
+            .child(dock.tab_panel.clone());
+
+        Some(
+            div()
+                .flex()
+                .flex_none()
+                .map(|this| match position {
+                    DockPosition::Left => this.flex_row().h_full().child(panel).child(handle),
+                    DockPosition::Right => this.flex_row().h_full().child(handle).child(panel),
+                    DockPosition::Bottom => this.flex_col().w_full().child(handle).child(panel),
+                })
+                .into_any_element(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{clamp_dock_size, fit_side_docks};
+
+    #[test]
+    fn test_clamp_dock_size() {
+        assert_eq!(clamp_dock_size(px(300.), px(1000.)), px(300.));
+        assert_eq!(clamp_dock_size(px(20.), px(1000.)), px(100.));
+        assert_eq!(clamp_dock_size(px(950.), px(1000.)), px(900.));
+    }
+
+    #[test]
+    fn test_fit_side_docks() {
+        assert_eq!(
+            fit_side_docks(px(300.), px(200.), px(1000.)),
+            (px(300.), px(200.))
+        );
+        assert_eq!(
+            fit_side_docks(px(600.), px(300.), px(550.)),
+            (px(300.), px(150.))
+        );
+        assert_eq!(fit_side_docks(px(0.), px(300.), px(50.)), (px(0.), px(0.)));
+    }
+}
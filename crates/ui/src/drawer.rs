@@ -8,8 +8,9 @@ use gpui::{
 };
 
 use crate::{
-    button::Button, h_flex, modal::overlay_color, root::ContextModal as _, scroll::ScrollbarAxis,
-    theme::ActiveTheme, v_flex, IconName, Placement, Sizable, StyledExt as _,
+    animation::motion_duration, button::Button, h_flex, modal::overlay_color,
+    root::ContextModal as _, scroll::ScrollbarAxis, theme::ActiveTheme, v_flex, IconName,
+    Placement, Sizable, StyledExt as _,
 };
 
 #[derive(IntoElement)]
@@ -207,7 +208,7 @@ impl RenderOnce for Drawer {
                             })
                             .with_animation(
                                 "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                Animation::new(motion_duration(Duration::from_secs_f64(0.15), cx)),
                                 move |this, delta| {
                                     let y = px(-100.) + delta * px(100.);
                                     this.map(|this| match placement {
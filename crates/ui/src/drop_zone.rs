@@ -0,0 +1,132 @@
+//! A zone to drop the files from the OS, or to paste the images from the clipboard by `cmd-v` (`ctrl-v`).
+//!
+//! ```ignore
+//! let drop_zone = cx.new_view(DropZone::new);
+//! cx.subscribe(&drop_zone, |this, _, event: &DropZoneEvent, cx| match event {
+//!     DropZoneEvent::PasteImage(image) => this.upload(image.format, image.bytes.clone(), cx),
+//!     DropZoneEvent::DropFiles(paths) => this.open(paths, cx),
+//! })
+//! .detach();
+//! ```
+use std::path::PathBuf;
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, ClipboardEntry, EventEmitter,
+    ExternalPaths, FocusHandle, FocusableView, Image, InteractiveElement as _, IntoElement,
+    KeyBinding, ParentElement as _, Render, SharedString, Styled as _, ViewContext,
+};
+use rust_i18n::t;
+
+use crate::{theme::ActiveTheme as _, v_flex, IconName, StyledExt as _};
+
+actions!(drop_zone, [Paste]);
+
+const CONTEXT: &str = "DropZone";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-v", Paste, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-v", Paste, Some(CONTEXT)),
+    ]);
+}
+
+pub enum DropZoneEvent {
+    /// The images pasted from the clipboard, with their format and bytes.
+    PasteImage(Image),
+    /// The files dropped from the OS.
+    DropFiles(Vec<PathBuf>),
+}
+
+pub struct DropZone {
+    focus_handle: FocusHandle,
+    label: SharedString,
+}
+
+impl DropZone {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            label: t!("DropZone.Drop files or paste an image here").into(),
+        }
+    }
+
+    /// Set the hint label of the zone.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Emit the images in the clipboard, the text in the clipboard is propagated to the parent.
+    fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
+        let images = cx
+            .read_from_clipboard()
+            .map(|item| {
+                item.entries()
+                    .iter()
+                    .filter_map(|entry| match entry {
+                        ClipboardEntry::Image(image) => Some(image.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if images.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        for image in images {
+            cx.emit(DropZoneEvent::PasteImage(image));
+        }
+    }
+
+    fn drop_files(&mut self, paths: &ExternalPaths, cx: &mut ViewContext<Self>) {
+        cx.focus(&self.focus_handle);
+        cx.emit(DropZoneEvent::DropFiles(paths.paths().to_vec()));
+    }
+}
+
+impl EventEmitter<DropZoneEvent> for DropZone {}
+
+impl FocusableView for DropZone {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DropZone {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        div()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::paste))
+            .on_drop(cx.listener(Self::drop_files))
+            .drag_over::<ExternalPaths>(|this, _, cx| {
+                this.bg(cx.theme().drop_target)
+                    .border_color(cx.theme().drag_border)
+            })
+            .flex()
+            .items_center()
+            .justify_center()
+            .size_full()
+            .min_h_24()
+            .p_4()
+            .border_2()
+            .border_color(cx.theme().border)
+            .rounded(px(cx.theme().radius))
+            .when(focused, |this| this.outline(cx))
+            .child(
+                v_flex()
+                    .items_center()
+                    .gap_2()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(IconName::Inbox)
+                    .child(self.label.clone()),
+            )
+    }
+}
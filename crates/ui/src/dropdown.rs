@@ -2,7 +2,7 @@ use gpui::{
     actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement,
     AppContext, Bounds, ClickEvent, DismissEvent, ElementId, EventEmitter, FocusHandle,
     FocusableView, InteractiveElement, IntoElement, KeyBinding, Length, ParentElement, Pixels,
-    Render, SharedString, StatefulInteractiveElement, Styled, Task, View, ViewContext,
+    Render, SharedString, Size, StatefulInteractiveElement, Styled, Task, View, ViewContext,
     VisualContext, WeakView, WindowContext,
 };
 use rust_i18n::t;
@@ -10,8 +10,13 @@ use rust_i18n::t;
 use crate::{
     h_flex,
     input::ClearButton,
+    label::HighlightedLabel,
     list::{self, List, ListDelegate, ListItem},
+    overlay::OverlayExt as _,
+    popover::PopupWidth,
+    position::{compute_position, Placement, PositionOptions},
     theme::{ActiveTheme, Colorize},
+    tooltip::Tooltip,
     v_flex, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
 };
 
@@ -82,6 +87,8 @@ pub trait DropdownDelegate: Sized {
         false
     }
 
+    /// Load the items of the query, the returned task is cancelled when the query is changed again,
+    /// or the dropdown is released.
     fn perform_search(&mut self, _query: &str, _cx: &mut ViewContext<Dropdown<Self>>) -> Task<()> {
         Task::Ready(Some(()))
     }
@@ -111,6 +118,7 @@ struct DropdownListDelegate<D: DropdownDelegate + 'static> {
     delegate: D,
     dropdown: WeakView<Dropdown<D>>,
     selected_index: Option<usize>,
+    query: String,
 }
 
 impl<D> ListDelegate for DropdownListDelegate<D>
@@ -143,13 +151,17 @@ where
                 .selected(selected)
                 .input_text_size(size)
                 .list_size(size)
-                .child(div().whitespace_nowrap().child(item.title().to_string()));
+                .child(HighlightedLabel::with_query(item.title(), &self.query).whitespace_nowrap());
             Some(list_item)
         } else {
             None
         }
     }
 
+    fn item_label(&self, ix: usize) -> Option<SharedString> {
+        self.delegate.get(ix).map(|item| item.title())
+    }
+
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
         if let Some(view) = self.dropdown.upgrade() {
             cx.update_view(&view, |view, cx| {
@@ -177,6 +189,7 @@ where
     }
 
     fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.query = query.to_string();
         self.dropdown
             .upgrade()
             .map_or(Task::Ready(None), |dropdown| {
@@ -223,10 +236,13 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     selected_value: Option<<D::Item as DropdownItem>::Value>,
     empty: Option<Box<dyn Fn(&WindowContext) -> AnyElement + 'static>>,
     width: Length,
-    menu_width: Length,
+    menu_width: PopupWidth,
     /// Store the bounds of the input
     bounds: Bounds<Pixels>,
+    /// Store the size of the menu, to flip it to the top when there is not enough space.
+    menu_size: Size<Pixels>,
     disabled: bool,
+    disabled_reason: Option<SharedString>,
 }
 
 pub struct SearchableVec<T> {
@@ -309,6 +325,7 @@ where
             delegate,
             dropdown: cx.view().downgrade(),
             selected_index,
+            query: String::new(),
         };
 
         let searchable = delegate.delegate.can_search();
@@ -337,9 +354,11 @@ where
             title_prefix: None,
             empty: None,
             width: Length::Auto,
-            menu_width: Length::Auto,
+            menu_width: PopupWidth::MatchTrigger,
             bounds: Bounds::default(),
+            menu_size: Size::default(),
             disabled: false,
+            disabled_reason: None,
         };
         this.set_selected_index(selected_index, cx);
         this
@@ -351,8 +370,8 @@ where
         self
     }
 
-    /// Set the width of the dropdown menu, default: Length::Auto
-    pub fn menu_width(mut self, width: impl Into<Length>) -> Self {
+    /// Set the width of the dropdown menu, default: `PopupWidth::MatchTrigger`
+    pub fn menu_width(mut self, width: impl Into<PopupWidth>) -> Self {
         self.menu_width = width.into();
         self
     }
@@ -385,9 +404,9 @@ where
         self
     }
 
-    /// Set the disable state for the dropdown.
-    pub fn disabled(mut self, disabled: bool) -> Self {
-        self.disabled = disabled;
+    /// Set the reason to show as tooltip when the dropdown is disabled.
+    pub fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
         self
     }
 
@@ -461,6 +480,10 @@ where
     }
 
     fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
         if !self.open {
             self.open = true;
         }
@@ -470,6 +493,10 @@ where
     }
 
     fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
         if !self.open {
             self.open = true;
             cx.notify();
@@ -530,6 +557,16 @@ where
     }
 }
 
+impl<D> Disableable for Dropdown<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl<D> Sizable for Dropdown<D>
 where
     D: DropdownDelegate + 'static,
@@ -564,6 +601,14 @@ where
         let show_clean = self.cleanable && self.selected_index(cx).is_some();
         let view = cx.view().clone();
         let bounds = self.bounds;
+        let position = compute_position(
+            bounds,
+            self.menu_size,
+            cx.viewport_size(),
+            PositionOptions::new(Placement::Bottom)
+                .offset(px(6.))
+                .shift(false),
+        );
         let allow_open = !(self.open || self.disabled);
         let outline_visible = is_focused && !self.disabled;
 
@@ -592,11 +637,15 @@ where
                     .shadow_sm()
                     .map(|this| {
                         if self.disabled {
-                            this.cursor_not_allowed()
+                            this.cursor_not_allowed().bg(cx.theme().muted)
                         } else {
                             this.cursor_pointer()
                         }
                     })
+                    .when_some(
+                        self.disabled_reason.clone().filter(|_| self.disabled),
+                        |this, reason| this.tooltip(move |cx| Tooltip::new(reason.clone(), cx)),
+                    )
                     .overflow_hidden()
                     .input_text_size(self.size)
                     .map(|this| match self.width {
@@ -604,6 +653,7 @@ where
                         Length::Auto => this.w_full(),
                     })
                     .when(outline_visible, |this| this.outline(cx))
+                    .focus_ring(&self.focus_handle, cx)
                     .input_size(self.size)
                     .when(allow_open, |this| {
                         this.on_click(cx.listener(Self::toggle_menu))
@@ -660,26 +710,41 @@ where
             .when(self.open, |this| {
                 this.child(
                     deferred(
-                        anchored().snap_to_window().child(
+                        anchored().position(position.origin).snap_to_window().child(
                             div()
-                                .map(|this| match self.menu_width {
-                                    Length::Auto => this.w(bounds.size.width),
-                                    Length::Definite(w) => this.w(w),
-                                })
+                                .relative()
+                                .map(|this| self.menu_width.apply(this, bounds.size.width))
                                 .child(
                                     v_flex()
                                         .track_focus(&self.list.focus_handle(cx))
                                         .occlude()
-                                        .mt_1p5()
                                         .bg(cx.theme().background)
                                         .border_1()
                                         .border_color(cx.theme().border)
                                         .rounded(px(cx.theme().radius))
                                         .shadow_md()
-                                        .on_mouse_down_out(|_, cx| {
-                                            cx.dispatch_action(Box::new(Escape));
-                                        })
+                                        .on_dismiss(
+                                            cx.listener(|this, _, cx| this.escape(&Escape, cx)),
+                                        )
                                         .child(self.list.clone()),
+                                )
+                                .child(
+                                    canvas(
+                                        {
+                                            let view = cx.view().clone();
+                                            move |bounds, cx| {
+                                                view.update(cx, |r, cx| {
+                                                    if r.menu_size != bounds.size {
+                                                        r.menu_size = bounds.size;
+                                                        cx.notify();
+                                                    }
+                                                })
+                                            }
+                                        },
+                                        |_, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full(),
                                 ),
                         ),
                     )
@@ -0,0 +1,174 @@
+//! A label that turns into a [`TextInput`] to rename it, by click or `F2`.
+//!
+//! The change is committed by `enter` or blur, and cancelled by `escape`.
+//!
+//! ```ignore
+//! let label = cx.new_view(|cx| EditableLabel::new("Untitled", cx));
+//! cx.subscribe(&label, |this, _, event: &EditableLabelEvent, cx| match event {
+//!     EditableLabelEvent::Rename(name) => this.rename(name.clone(), cx),
+//! })
+//! .detach();
+//! ```
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, AppContext, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Render,
+    SharedString, StatefulInteractiveElement as _, Styled as _, View, ViewContext,
+    VisualContext as _,
+};
+
+use crate::{
+    input::{InputEvent, TextInput},
+    label::Label,
+    Sizable as _,
+};
+
+actions!(editable_label, [StartEdit, CancelEdit]);
+
+const CONTEXT: &str = "EditableLabel";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("f2", StartEdit, Some(CONTEXT)),
+        KeyBinding::new("escape", CancelEdit, Some(CONTEXT)),
+    ]);
+}
+
+pub enum EditableLabelEvent {
+    /// The text is committed with a new non-empty value.
+    Rename(SharedString),
+}
+
+pub struct EditableLabel {
+    focus_handle: FocusHandle,
+    text: SharedString,
+    input: View<TextInput>,
+    editing: bool,
+    disabled: bool,
+}
+
+impl EditableLabel {
+    pub fn new(text: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).small());
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            text: text.into(),
+            input,
+            editing: false,
+            disabled: false,
+        }
+    }
+
+    pub fn text(&self) -> SharedString {
+        self.text.clone()
+    }
+
+    /// Set the text without emitting the [`EditableLabelEvent::Rename`], the editing is cancelled.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.text = text.into();
+        self.editing = false;
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        if disabled {
+            self.editing = false;
+        }
+        cx.notify();
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Turn into the input with the current text, and focus it.
+    pub fn start_edit(&mut self, cx: &mut ViewContext<Self>) {
+        if self.editing || self.disabled {
+            return;
+        }
+
+        self.editing = true;
+        let text = self.text.clone();
+        self.input.update(cx, |input, cx| {
+            input.set_text(text, cx);
+            input.focus(cx);
+        });
+        cx.notify();
+    }
+
+    /// Commit the text of the input, the empty or unchanged text is ignored.
+    fn commit(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.editing {
+            return;
+        }
+
+        self.editing = false;
+        let text = self.input.read(cx).text().trim().to_string();
+        if !text.is_empty() && text != self.text.as_ref() {
+            self.text = text.into();
+            cx.emit(EditableLabelEvent::Rename(self.text.clone()));
+        }
+        cx.notify();
+    }
+
+    fn cancel(&mut self, _: &CancelEdit, cx: &mut ViewContext<Self>) {
+        if !self.editing {
+            return;
+        }
+
+        self.editing = false;
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    fn on_action_start_edit(&mut self, _: &StartEdit, cx: &mut ViewContext<Self>) {
+        self.start_edit(cx);
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::PressEnter => {
+                self.commit(cx);
+                self.focus_handle.focus(cx);
+            }
+            InputEvent::Blur => self.commit(cx),
+            _ => {}
+        }
+    }
+}
+
+impl EventEmitter<EditableLabelEvent> for EditableLabel {}
+
+impl FocusableView for EditableLabel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EditableLabel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .id("editable-label")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_start_edit))
+            .on_action(cx.listener(Self::cancel))
+            .map(|this| {
+                if self.editing {
+                    this.child(self.input.clone())
+                } else {
+                    this.overflow_hidden()
+                        .when(!self.disabled, |this| this.cursor_text())
+                        .on_click(cx.listener(|this, _, cx| this.start_edit(cx)))
+                        .child(Label::new(self.text.clone()))
+                }
+            })
+    }
+}
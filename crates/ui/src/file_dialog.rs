@@ -0,0 +1,191 @@
+//! The async wrappers of the OS file and folder dialogs.
+//!
+//! ```ignore
+//! let task = file_dialog::open_file(vec![FileFilter::new("Images", ["png", "jpg"])], cx);
+//! cx.spawn(|view, mut cx| async move {
+//!     if let Some(path) = task.await {
+//!         // ...
+//!     }
+//! })
+//! .detach();
+//! ```
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+};
+
+use gpui::{AppContext, PathPromptOptions, SharedString, Task};
+
+/// A filter to limit the file types by extensions, e.g.: `Images (*.png, *.jpg)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    pub name: SharedString,
+    /// The extensions without the leading dot, e.g.: `png`.
+    pub extensions: Vec<SharedString>,
+}
+
+impl FileFilter {
+    pub fn new<S: Into<SharedString>>(
+        name: impl Into<SharedString>,
+        extensions: impl IntoIterator<Item = S>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+
+        self.extensions
+            .iter()
+            .any(|filter_ext| filter_ext.as_ref() == "*" || filter_ext.eq_ignore_ascii_case(ext))
+    }
+}
+
+/// Return true if the path matches any of the filters, or the filters is empty.
+fn matches_filters(path: &Path, filters: &[FileFilter]) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| filter.matches(path))
+}
+
+/// Append the first extension of the filters, if the path not matches any of the filters.
+fn with_default_extension(path: PathBuf, filters: &[FileFilter]) -> PathBuf {
+    if matches_filters(&path, filters) {
+        return path;
+    }
+
+    let ext = filters
+        .iter()
+        .flat_map(|filter| filter.extensions.iter())
+        .find(|ext| ext.as_ref() != "*");
+
+    match ext {
+        Some(ext) => {
+            let mut path = path.into_os_string();
+            path.push(".");
+            path.push(ext.as_ref());
+            path.into()
+        }
+        None => path,
+    }
+}
+
+fn prompt_for_paths(
+    options: PathPromptOptions,
+    filters: Vec<FileFilter>,
+    cx: &mut AppContext,
+) -> impl Future<Output = Vec<PathBuf>> {
+    let receiver = cx.prompt_for_paths(options);
+    async move {
+        let paths = receiver.await.ok().flatten().unwrap_or_default();
+        // The platform prompts are not support the filters, so we filter the selected paths here.
+        paths
+            .into_iter()
+            .filter(|path| path.is_dir() || matches_filters(path, &filters))
+            .collect()
+    }
+}
+
+/// Open a file, return `None` if the dialog is canceled or the file is not matches the filters.
+pub fn open_file(filters: Vec<FileFilter>, cx: &mut AppContext) -> Task<Option<PathBuf>> {
+    let paths = prompt_for_paths(
+        PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: false,
+        },
+        filters,
+        cx,
+    );
+
+    cx.spawn(|_| async move { paths.await.into_iter().next() })
+}
+
+/// Open multiple files, the files that are not matches the filters will be ignored.
+pub fn open_files(filters: Vec<FileFilter>, cx: &mut AppContext) -> Task<Vec<PathBuf>> {
+    let paths = prompt_for_paths(
+        PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: true,
+        },
+        filters,
+        cx,
+    );
+
+    cx.spawn(|_| paths)
+}
+
+/// Open a folder, return `None` if the dialog is canceled.
+pub fn open_folder(cx: &mut AppContext) -> Task<Option<PathBuf>> {
+    let paths = prompt_for_paths(
+        PathPromptOptions {
+            files: false,
+            directories: true,
+            multiple: false,
+        },
+        vec![],
+        cx,
+    );
+
+    cx.spawn(|_| async move { paths.await.into_iter().next() })
+}
+
+/// Prompt for a new path to save file, start in the `directory`.
+///
+/// The first extension of the filters will be appended, if the path is not matches the filters.
+pub fn save_file(
+    directory: &Path,
+    filters: Vec<FileFilter>,
+    cx: &mut AppContext,
+) -> Task<Option<PathBuf>> {
+    let receiver = cx.prompt_for_new_path(directory);
+    cx.spawn(|_| async move {
+        let path = receiver.await.ok().flatten()?;
+        Some(with_default_extension(path, &filters))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{matches_filters, with_default_extension, FileFilter};
+
+    #[test]
+    fn test_matches_filters() {
+        let filters = vec![FileFilter::new("Images", ["png", "jpg"])];
+        assert!(matches_filters(Path::new("a.png"), &filters));
+        assert!(matches_filters(Path::new("a.JPG"), &filters));
+        assert!(!matches_filters(Path::new("a.txt"), &filters));
+        assert!(!matches_filters(Path::new("a"), &filters));
+        assert!(matches_filters(Path::new("a.txt"), &[]));
+        assert!(matches_filters(
+            Path::new("a.txt"),
+            &[FileFilter::new("All", ["*"])]
+        ));
+    }
+
+    #[test]
+    fn test_with_default_extension() {
+        let filters = vec![
+            FileFilter::new("All", ["*"]),
+            FileFilter::new("Text", ["txt"]),
+        ];
+        assert_eq!(
+            with_default_extension(PathBuf::from("a.txt"), &filters),
+            PathBuf::from("a.txt")
+        );
+        assert_eq!(
+            with_default_extension(PathBuf::from("a"), &filters),
+            PathBuf::from("a.txt")
+        );
+        assert_eq!(
+            with_default_extension(PathBuf::from("a"), &[]),
+            PathBuf::from("a")
+        );
+    }
+}
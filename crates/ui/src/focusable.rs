@@ -1,4 +1,39 @@
-use gpui::{FocusHandle, ViewContext};
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    actions, AppContext, FocusHandle, Global, InteractiveElement, KeyBinding, ViewContext,
+    WindowContext,
+};
+
+/// The last input modality of the user.
+///
+/// This is used to only show the focus ring when the user is navigating by keyboard.
+#[derive(Default)]
+struct InputModality {
+    keyboard: bool,
+}
+
+impl Global for InputModality {}
+
+/// Record the last input modality, `true` for keyboard, `false` for mouse.
+pub(crate) fn set_keyboard_modality(keyboard: bool, cx: &mut WindowContext) {
+    let changed = cx
+        .try_global::<InputModality>()
+        .map_or(true, |modality| modality.keyboard != keyboard);
+
+    if changed {
+        cx.set_global(InputModality { keyboard });
+        cx.refresh();
+    }
+}
+
+/// Returns true if the `focus_handle` is focused and the last input was from keyboard.
+pub fn is_focus_visible(focus_handle: &FocusHandle, cx: &WindowContext) -> bool {
+    focus_handle.is_focused(cx)
+        && cx
+            .try_global::<InputModality>()
+            .map_or(false, |modality| modality.keyboard)
+}
 
 /// A trait for views that can cycle focus between its children.
 ///
@@ -38,3 +73,218 @@ pub trait FocusableCycle {
         cx.stop_propagation();
     }
 }
+
+actions!(focus_cycle, [FocusPrev, FocusNext, FocusFirst, FocusLast]);
+
+const CONTEXT: &str = "FocusCycle";
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", FocusPrev, Some(CONTEXT)),
+        KeyBinding::new("up", FocusPrev, Some(CONTEXT)),
+        KeyBinding::new("right", FocusNext, Some(CONTEXT)),
+        KeyBinding::new("down", FocusNext, Some(CONTEXT)),
+        KeyBinding::new("home", FocusFirst, Some(CONTEXT)),
+        KeyBinding::new("end", FocusLast, Some(CONTEXT)),
+    ]);
+}
+
+/// A roving focus helper for the composite widgets, like Toolbar, TabBar, RadioGroup.
+///
+/// The group only takes one stop in the tab order (the [`FocusCycle::focus_handle`]),
+/// and the arrow keys move the focus between the items.
+///
+/// Keep the `FocusCycle` in your view, pass the [`FocusCycle::handle`] to each item,
+/// and call [`FocusCycle::bind`] on the container element to handle the arrow keys.
+#[derive(Clone)]
+pub struct FocusCycle {
+    handles: Vec<FocusHandle>,
+    active_ix: Rc<Cell<usize>>,
+    wrap: bool,
+}
+
+impl FocusCycle {
+    /// Create a new FocusCycle with `len` items.
+    pub fn new(len: usize, cx: &mut WindowContext) -> Self {
+        Self {
+            handles: (0..len).map(|_| cx.focus_handle()).collect(),
+            active_ix: Rc::new(Cell::new(0)),
+            wrap: true,
+        }
+    }
+
+    /// Set whether to wrap around when moving past the first or last item, default is true.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Update the number of the items, the existing focus handles will be kept.
+    pub fn set_len(&mut self, len: usize, cx: &mut WindowContext) {
+        if len < self.handles.len() {
+            self.handles.truncate(len);
+        } else {
+            let extra = len - self.handles.len();
+            self.handles.extend((0..extra).map(|_| cx.focus_handle()));
+        }
+
+        if self.active_ix.get() >= len {
+            self.active_ix.set(len.saturating_sub(1));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns the focus handle of the item at `ix`.
+    pub fn handle(&self, ix: usize) -> &FocusHandle {
+        &self.handles[ix]
+    }
+
+    /// Returns the index of the active item, that is the last focused item in the group.
+    pub fn active_index(&self) -> usize {
+        self.active_ix.get()
+    }
+
+    /// Returns the focus handle to enter the group, use this in the tab order of the parent.
+    ///
+    /// Panics if the group is empty.
+    pub fn focus_handle(&self) -> FocusHandle {
+        self.handles[self.active_ix.get()].clone()
+    }
+
+    /// Returns true if any item in the group is focused.
+    pub fn contains_focused(&self, cx: &WindowContext) -> bool {
+        self.handles.iter().any(|handle| handle.is_focused(cx))
+    }
+
+    /// Returns the index of the focused item in the group.
+    pub fn focused_index(&self, cx: &WindowContext) -> Option<usize> {
+        self.handles.iter().position(|handle| handle.is_focused(cx))
+    }
+
+    /// Make the item at `ix` as the active item without focusing it,
+    /// e.g.: the checked radio to enter the RadioGroup.
+    pub fn set_active_index(&self, ix: usize) {
+        if ix < self.handles.len() {
+            self.active_ix.set(ix);
+        }
+    }
+
+    /// Focus the item at `ix`, and make it as the active item.
+    pub fn focus(&self, ix: usize, cx: &mut WindowContext) {
+        if let Some(handle) = self.handles.get(ix) {
+            self.active_ix.set(ix);
+            handle.focus(cx);
+        }
+    }
+
+    pub fn focus_next(&self, cx: &mut WindowContext) {
+        if let Some(ix) = self.next_index(true, cx) {
+            self.focus(ix, cx);
+        }
+    }
+
+    pub fn focus_prev(&self, cx: &mut WindowContext) {
+        if let Some(ix) = self.next_index(false, cx) {
+            self.focus(ix, cx);
+        }
+    }
+
+    pub fn focus_first(&self, cx: &mut WindowContext) {
+        self.focus(0, cx);
+    }
+
+    pub fn focus_last(&self, cx: &mut WindowContext) {
+        self.focus(self.handles.len().saturating_sub(1), cx);
+    }
+
+    fn next_index(&self, is_next: bool, cx: &WindowContext) -> Option<usize> {
+        let len = self.handles.len();
+        if len == 0 {
+            return None;
+        }
+
+        // Prefer the focused item, the focus may be moved by mouse.
+        let current = self.focused_index(cx).unwrap_or(self.active_ix.get());
+
+        cycle_index(current, len, is_next, self.wrap)
+    }
+
+    /// Bind the arrow keys to the container element of the group.
+    pub fn bind<E: InteractiveElement>(&self, element: E) -> E {
+        self.bind_with(element, |_, _| {})
+    }
+
+    /// Bind the arrow keys like [`FocusCycle::bind`], and call `on_move` with the index of the item
+    /// that the keys moved the focus to, e.g.: to select the radio in a RadioGroup.
+    pub fn bind_with<E: InteractiveElement>(
+        &self,
+        element: E,
+        on_move: impl Fn(usize, &mut WindowContext) + 'static,
+    ) -> E {
+        let on_move = Rc::new(on_move);
+        let listener = |f: fn(&Self, &mut WindowContext)| {
+            let this = self.clone();
+            let on_move = on_move.clone();
+            move |cx: &mut WindowContext| {
+                let current = this.focused_index(cx);
+                f(&this, cx);
+                let ix = this.active_index();
+                if current != Some(ix) {
+                    on_move(ix, cx);
+                }
+            }
+        };
+
+        let focus_prev = listener(Self::focus_prev);
+        let focus_next = listener(Self::focus_next);
+        let focus_first = listener(Self::focus_first);
+        let focus_last = listener(Self::focus_last);
+        element
+            .key_context(CONTEXT)
+            .on_action(move |_: &FocusPrev, cx| focus_prev(cx))
+            .on_action(move |_: &FocusNext, cx| focus_next(cx))
+            .on_action(move |_: &FocusFirst, cx| focus_first(cx))
+            .on_action(move |_: &FocusLast, cx| focus_last(cx))
+    }
+}
+
+/// Returns the index after moving from `current` in `len` items, `None` if can't move.
+pub(crate) fn cycle_index(current: usize, len: usize, is_next: bool, wrap: bool) -> Option<usize> {
+    if is_next {
+        if current + 1 < len {
+            Some(current + 1)
+        } else if wrap {
+            Some(0)
+        } else {
+            None
+        }
+    } else if current > 0 {
+        Some(current - 1)
+    } else if wrap {
+        Some(len - 1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycle_index;
+
+    #[test]
+    fn test_cycle_index() {
+        assert_eq!(cycle_index(0, 3, true, true), Some(1));
+        assert_eq!(cycle_index(2, 3, true, true), Some(0));
+        assert_eq!(cycle_index(2, 3, true, false), None);
+        assert_eq!(cycle_index(1, 3, false, true), Some(0));
+        assert_eq!(cycle_index(0, 3, false, true), Some(2));
+        assert_eq!(cycle_index(0, 3, false, false), None);
+    }
+}
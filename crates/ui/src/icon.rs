@@ -1,9 +1,46 @@
-use crate::{theme::ActiveTheme, Sizable, Size};
+use std::collections::HashMap;
+
+use crate::{svg_img, theme::ActiveTheme, Sizable, Size, SvgSource};
 use gpui::{
-    prelude::FluentBuilder as _, svg, AnyElement, Hsla, IntoElement, Render, RenderOnce,
-    SharedString, StyleRefinement, Styled, Svg, View, VisualContext, WindowContext,
+    prelude::FluentBuilder as _, px, svg, AnyElement, AppContext, Global, Hsla, IntoElement,
+    Render, RenderOnce, SharedString, StyleRefinement, Styled, Svg, View, VisualContext,
+    WindowContext,
 };
 
+/// The original size of the embedded svg icons, they are scaled to the rendered size when rasterized.
+const ICON_SOURCE_SIZE: f32 = 24.;
+
+/// The custom icons registered by the application.
+#[derive(Default)]
+struct IconRegistry {
+    icons: HashMap<SharedString, SvgSource>,
+}
+
+impl Global for IconRegistry {}
+
+/// Register a custom icon by name, then use it by `IconName::Custom(name)`.
+///
+/// The `source` can be an asset path (e.g.: `icons/foo.svg`) or the embedded svg bytes.
+///
+/// The icons from an asset path will be tinted by the text color, same as the built-in icons.
+/// The embedded svg icons are rasterized and cached per size and color.
+pub fn register_icon(
+    name: impl Into<SharedString>,
+    source: impl Into<SvgSource>,
+    cx: &mut AppContext,
+) {
+    cx.default_global::<IconRegistry>()
+        .icons
+        .insert(name.into(), source.into());
+}
+
+/// Resolve the icon path to the registered source, or use it as an asset path.
+fn resolve_icon(path: &SharedString, cx: &AppContext) -> SvgSource {
+    cx.try_global::<IconRegistry>()
+        .and_then(|registry| registry.icons.get(path).cloned())
+        .unwrap_or_else(|| SvgSource::Path(path.clone()))
+}
+
 #[derive(IntoElement, Clone)]
 pub enum IconName {
     ArrowDown,
@@ -29,6 +66,7 @@ pub enum IconName {
     EllipsisVertical,
     Eye,
     EyeOff,
+    Filter,
     GitHub,
     Globe,
     Heart,
@@ -53,6 +91,8 @@ pub enum IconName {
     ThumbsDown,
     ThumbsUp,
     TriangleAlert,
+    /// A custom icon registered by [`register_icon`].
+    Custom(SharedString),
 }
 
 impl IconName {
@@ -81,6 +121,7 @@ impl IconName {
             IconName::EllipsisVertical => "icons/ellipsis-vertical.svg",
             IconName::Eye => "icons/eye.svg",
             IconName::EyeOff => "icons/eye-off.svg",
+            IconName::Filter => "icons/filter.svg",
             IconName::GitHub => "icons/github.svg",
             IconName::Globe => "icons/globe.svg",
             IconName::Heart => "icons/heart.svg",
@@ -105,6 +146,7 @@ impl IconName {
             IconName::ThumbsDown => "icons/thumbs-down.svg",
             IconName::ThumbsUp => "icons/thumbs-up.svg",
             IconName::TriangleAlert => "icons/triangle-alert.svg",
+            IconName::Custom(name) => return name,
         }
         .into()
     }
@@ -216,20 +258,45 @@ impl Sizable for Icon {
     }
 }
 
+fn with_icon_size<E: Styled>(this: E, size: Option<Size>) -> E {
+    this.when_some(size, |this, size| match size {
+        Size::Size(px) => this.size(px),
+        Size::XSmall => this.size_3(),
+        Size::Small => this.size_3p5(),
+        Size::Medium => this.size_4(),
+        Size::Large => this.size_6(),
+    })
+}
+
+/// Render the embedded svg icon as a tinted image.
+fn render_svg_data(
+    data: std::sync::Arc<[u8]>,
+    style: StyleRefinement,
+    size: Option<Size>,
+    text_color: Hsla,
+) -> AnyElement {
+    let mut img = svg_img();
+    *img.style() = style;
+
+    with_icon_size(img, size)
+        .source(data, px(ICON_SOURCE_SIZE), px(ICON_SOURCE_SIZE))
+        .scale_to_fit()
+        .tint(text_color)
+        .into_any_element()
+}
+
 impl RenderOnce for Icon {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
         let text_color = self.text_color.unwrap_or_else(|| cx.text_style().color);
 
-        self.base
-            .text_color(text_color)
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path)
+        match resolve_icon(&self.path, cx) {
+            SvgSource::Path(path) => with_icon_size(self.base.text_color(text_color), self.size)
+                .path(path)
+                .into_any_element(),
+            SvgSource::Data(data) => {
+                render_svg_data(data, self.base.style().clone(), self.size, text_color)
+            }
+        }
     }
 }
 
@@ -243,16 +310,18 @@ impl Render for Icon {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
         let text_color = self.text_color.unwrap_or_else(|| cx.theme().foreground);
 
-        svg()
-            .flex_none()
-            .text_color(text_color)
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path.clone())
+        match resolve_icon(&self.path, cx) {
+            SvgSource::Path(path) => {
+                with_icon_size(svg().flex_none().text_color(text_color), self.size)
+                    .path(path)
+                    .into_any_element()
+            }
+            SvgSource::Data(data) => render_svg_data(
+                data,
+                svg().flex_none().style().clone(),
+                self.size,
+                text_color,
+            ),
+        }
     }
 }
@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+use gpui::{px, Hsla, SharedString, UnderlineStyle};
+
+/// The underline style of a [`TextDecoration`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecorationStyle {
+    #[default]
+    Squiggly,
+    Solid,
+}
+
+/// An underline of a range of the text in the [`super::TextInput`], e.g.: a spelling or lint error.
+///
+/// ```ignore
+/// input.set_decorations(
+///     vec![TextDecoration::new(0..3)
+///         .color(cx.theme().destructive)
+///         .message("Did you mean: The")],
+///     cx,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextDecoration {
+    /// The byte range of the text.
+    pub range: Range<usize>,
+    pub style: DecorationStyle,
+    /// The color of the underline, default is the color of the text.
+    pub color: Option<Hsla>,
+    /// The message to show in a tooltip when the range is hovered.
+    pub message: Option<SharedString>,
+}
+
+impl TextDecoration {
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            range,
+            style: DecorationStyle::default(),
+            color: None,
+            message: None,
+        }
+    }
+
+    /// Set the style of the underline, default is [`DecorationStyle::Squiggly`].
+    pub fn style(mut self, style: DecorationStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub(super) fn underline(&self, text_color: Hsla) -> UnderlineStyle {
+        UnderlineStyle {
+            color: Some(self.color.unwrap_or(text_color)),
+            thickness: px(1.0),
+            wavy: self.style == DecorationStyle::Squiggly,
+        }
+    }
+}
+
+/// Split the text of the `len` into the segments by the `ranges`,
+/// each segment has the index of the last range that covers it.
+pub(super) fn split_by_ranges(
+    len: usize,
+    ranges: &[Range<usize>],
+) -> Vec<(Range<usize>, Option<usize>)> {
+    let mut offsets = ranges
+        .iter()
+        .flat_map(|range| [range.start, range.end])
+        .filter(|offset| *offset < len)
+        .chain([0, len])
+        .collect::<Vec<_>>();
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    offsets
+        .windows(2)
+        .map(|pair| {
+            let segment = pair[0]..pair[1];
+            let ix = ranges
+                .iter()
+                .rposition(|range| range.start <= segment.start && segment.end <= range.end);
+            (segment, ix)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_by_ranges;
+
+    #[test]
+    fn test_split_by_ranges() {
+        assert_eq!(split_by_ranges(5, &[]), vec![(0..5, None)]);
+        assert_eq!(split_by_ranges(0, &[]), vec![]);
+        assert_eq!(
+            split_by_ranges(10, &[2..4, 3..6]),
+            vec![
+                (0..2, None),
+                (2..3, Some(0)),
+                (3..4, Some(1)),
+                (4..6, Some(1)),
+                (6..10, None)
+            ]
+        );
+        assert_eq!(split_by_ranges(4, &[0..4]), vec![(0..4, Some(0))]);
+    }
+}
@@ -7,22 +7,24 @@ use std::ops::Range;
 
 use super::blink_cursor::BlinkCursor;
 use super::change::Change;
+use super::decoration::{split_by_ranges, TextDecoration};
+use super::mask::MaskPattern;
 use super::ClearButton;
 use crate::history::History;
 use crate::indicator::Indicator;
 use crate::theme::ActiveTheme;
-use crate::StyledExt as _;
 use crate::{event::InteractiveElementExt as _, Size};
+use crate::{h_flex, StyledExt as _};
 use crate::{Sizable, StyleSized};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    actions, div, fill, point, px, relative, rems, size, AnyElement, AppContext, Bounds,
-    ClickEvent, ClipboardItem, Context as _, Element, ElementId, ElementInputHandler, EventEmitter,
-    FocusHandle, FocusableView, GlobalElementId, InteractiveElement as _, IntoElement, KeyBinding,
-    KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    PaintQuad, ParentElement as _, Pixels, Point, Render, ShapedLine, SharedString, Style,
-    Styled as _, TextRun, UTF16Selection, UnderlineStyle, View, ViewContext, ViewInputHandler,
-    WindowContext,
+    actions, anchored, deferred, div, fill, point, px, relative, rems, size, AnyElement,
+    AppContext, Bounds, ClickEvent, ClipboardItem, Context as _, DragMoveEvent, Element, ElementId,
+    ElementInputHandler, EntityId, EventEmitter, FocusHandle, FocusableView, GlobalElementId,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, LayoutId, Model, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, ParentElement as _, Pixels, Point,
+    Render, ShapedLine, SharedString, StatefulInteractiveElement as _, Style, Styled as _, TextRun,
+    UTF16Selection, UnderlineStyle, View, ViewContext, ViewInputHandler, WindowContext,
 };
 use unicode_segmentation::*;
 
@@ -61,6 +63,35 @@ pub enum InputEvent {
     Blur,
 }
 
+/// The selected text dragged from a [`TextInput`], it is dropped into another input to copy it,
+/// or into the same input to move it.
+#[derive(Clone)]
+pub struct DraggedText {
+    text: SharedString,
+    source: EntityId,
+    range: Range<usize>,
+}
+
+impl Render for DraggedText {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .max_w(px(240.))
+            .px_2()
+            .py_0p5()
+            .rounded(px(cx.theme().radius))
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .text_color(cx.theme().popover_foreground)
+            .text_sm()
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .opacity(0.8)
+            .child(self.text.clone())
+    }
+}
+
 const CONTEXT: &str = "Input";
 
 pub fn init(cx: &mut AppContext) {
@@ -132,6 +163,11 @@ pub struct TextInput {
     size: Size,
     pattern: Option<regex::Regex>,
     validate: Option<Box<dyn Fn(&str) -> bool + 'static>>,
+    mask_pattern: Option<MaskPattern>,
+    decorations: Vec<TextDecoration>,
+    hovered_decoration: Option<usize>,
+    /// The offset to insert the [`DraggedText`] that is dragging over the input.
+    drop_offset: Option<usize>,
 }
 
 impl EventEmitter<InputEvent> for TextInput {}
@@ -164,6 +200,10 @@ impl TextInput {
             size: Size::Medium,
             pattern: None,
             validate: None,
+            mask_pattern: None,
+            decorations: Vec::new(),
+            hovered_decoration: None,
+            drop_offset: None,
         };
 
         // Observe the blink cursor to repaint the view when it changes.
@@ -209,6 +249,53 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Set the decorations to underline the ranges of the text, e.g.: the spelling or lint errors.
+    ///
+    /// The ranges are of the current text, set them again when the text is changed (see [`InputEvent::Change`]).
+    pub fn set_decorations(
+        &mut self,
+        decorations: Vec<TextDecoration>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.decorations = decorations;
+        self.hovered_decoration = None;
+        cx.notify();
+    }
+
+    pub fn decorations(&self) -> &[TextDecoration] {
+        &self.decorations
+    }
+
+    /// Return the decorations in the text, the outdated ones (e.g.: out of the text) are skipped.
+    fn valid_decorations(&self) -> impl Iterator<Item = &TextDecoration> {
+        self.decorations
+            .iter()
+            .filter(|decoration| self.is_valid_range(&decoration.range))
+    }
+
+    fn is_valid_range(&self, range: &Range<usize>) -> bool {
+        !range.is_empty()
+            && range.end <= self.text.len()
+            && self.text.is_char_boundary(range.start)
+            && self.text.is_char_boundary(range.end)
+    }
+
+    /// Return the index of the decoration with a message at the `position`, measured in the last paint.
+    fn decoration_at(&self, position: Point<Pixels>) -> Option<usize> {
+        let bounds = self.last_bounds?;
+        if self.masked {
+            return None;
+        }
+
+        self.decorations.iter().position(|decoration| {
+            decoration.message.is_some()
+                && self.is_valid_range(&decoration.range)
+                && self
+                    .range_bounds(&decoration.range, bounds)
+                    .map_or(false, |bounds| bounds.contains(&position))
+        })
+    }
+
     /// Set the masked state of the input field.
     pub fn set_masked(&mut self, masked: bool, cx: &mut ViewContext<Self>) {
         self.masked = masked;
@@ -242,6 +329,9 @@ impl TextInput {
     }
 
     /// Set the prefix element of the input field, for example a search Icon.
+    ///
+    /// The element is placed inside the padding and the focus ring of the input,
+    /// with the muted text color for the unit labels, e.g.: `"$"`.
     pub fn prefix<F, E>(mut self, builder: F) -> Self
     where
         F: Fn(&mut ViewContext<Self>) -> E + 'static,
@@ -251,7 +341,8 @@ impl TextInput {
         self
     }
 
-    /// Set the suffix element of the input field, for example a clear button.
+    /// Set the suffix element of the input field, for example a clear button,
+    /// or a unit label like `"px"`, see [`TextInput::prefix`].
     pub fn suffix<F, E>(mut self, builder: F) -> Self
     where
         F: Fn(&mut ViewContext<Self>) -> E + 'static,
@@ -290,6 +381,20 @@ impl TextInput {
         self
     }
 
+    /// Set the mask of the input field, it constrains the typing and formats the text on blur.
+    pub fn mask_pattern(mut self, mask: MaskPattern) -> Self {
+        self.mask_pattern = Some(mask);
+        self
+    }
+
+    /// Return the raw value of the text without the mask, e.g.: `1234.50` for `$1,234.50`.
+    pub fn unmask_text(&self) -> SharedString {
+        match &self.mask_pattern {
+            Some(mask) => mask.unmask(&self.text).into(),
+            None => self.text.clone(),
+        }
+    }
+
     /// Set true to show indicator at the input right.
     pub fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
@@ -384,6 +489,8 @@ impl TextInput {
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        // Clicking in the input commits the IME composition as it is.
+        self.marked_range = None;
         self.is_selecting = true;
         let offset = self.index_for_mouse_position(event.position);
 
@@ -402,12 +509,129 @@ impl TextInput {
 
     fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut ViewContext<Self>) {
         self.is_selecting = false;
+        // The drag is ended, e.g.: the text is dropped outside of the input.
+        self.drop_offset = None;
+    }
+
+    fn on_drag_text_move(&mut self, drag: &DragMoveEvent<DraggedText>, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let drop_offset = drag
+            .bounds
+            .contains(&drag.event.position)
+            .then(|| self.index_for_mouse_position(drag.event.position));
+        if drop_offset != self.drop_offset {
+            self.drop_offset = drop_offset;
+            cx.notify();
+        }
+    }
+
+    /// Insert the dropped text at the drop offset, the text dropped from the input itself is moved.
+    fn on_drop_text(&mut self, drag: &DraggedText, cx: &mut ViewContext<Self>) {
+        self.drop_offset = None;
+        if self.disabled {
+            return;
+        }
+
+        let offset = self.index_for_mouse_position(cx.mouse_position());
+
+        // The range to replace, its new text, and the offset of the dropped text after the edit.
+        let (range, new_text, offset) = if drag.source == cx.entity_id() {
+            if drag.range.contains(&offset) || drag.range.end == offset {
+                cx.notify();
+                return;
+            }
+
+            // Move the text by replacing the span between the dragged range and the drop offset,
+            // so the move is validated as a whole and undone in one step.
+            if offset < drag.range.start {
+                let Some(between) = self.text.get(offset..drag.range.start) else {
+                    return;
+                };
+                (
+                    offset..drag.range.end,
+                    format!("{}{}", drag.text, between),
+                    offset,
+                )
+            } else {
+                let Some(between) = self.text.get(drag.range.end..offset) else {
+                    return;
+                };
+                (
+                    drag.range.start..offset,
+                    format!("{}{}", between, drag.text),
+                    offset - drag.range.len(),
+                )
+            }
+        } else {
+            (offset..offset, drag.text.to_string(), offset)
+        };
+
+        self.replace_text_in_range(Some(self.range_to_utf16(&range)), &new_text, cx);
+        let range = offset..offset + drag.text.len();
+        if self.text.get(range.clone()) == Some(drag.text.as_ref()) {
+            self.selected_range = range;
+            self.selection_reversed = false;
+        }
+        self.focus(cx);
+        cx.notify();
+    }
+
+    /// Render the selected text as the drag source, it is dragged to drop it into an input.
+    fn render_drag_selection(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.selected_range.is_empty() || self.masked || self.disabled {
+            return None;
+        }
+
+        let line = self.last_layout.as_ref()?;
+        let range = self.selected_range.clone();
+        let left = self.scroll_offset.x + line.x_for_index(range.start);
+        let width = line.x_for_index(range.end) - line.x_for_index(range.start);
+        let dragged_text = DraggedText {
+            text: self.text[range.clone()].to_string().into(),
+            source: cx.entity_id(),
+            range,
+        };
+
+        Some(
+            div()
+                .id("drag-selection")
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(left)
+                .w(width)
+                .cursor_default()
+                .on_mouse_down(MouseButton::Left, |event, cx| {
+                    // Keep the double click to select the word.
+                    if event.click_count == 1 {
+                        cx.stop_propagation();
+                    }
+                })
+                .on_click(cx.listener(|this, event: &ClickEvent, cx| {
+                    if event.up.click_count == 1 {
+                        this.move_to(this.index_for_mouse_position(event.up.position), cx);
+                    }
+                }))
+                .on_drag(dragged_text, |drag, cx| {
+                    cx.stop_propagation();
+                    cx.new_view(|_| drag.clone())
+                }),
+        )
     }
 
     fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
         if self.is_selecting {
             self.select_to(self.index_for_mouse_position(event.position), cx);
         }
+
+        let hovered_decoration = self.decoration_at(event.position);
+        if hovered_decoration != self.hovered_decoration {
+            self.hovered_decoration = hovered_decoration;
+            cx.notify();
+        }
     }
 
     fn show_character_palette(&mut self, _: &ShowCharacterPalette, cx: &mut ViewContext<Self>) {
@@ -569,18 +793,7 @@ impl TextInput {
     }
 
     fn offset_from_utf16(&self, offset: usize) -> usize {
-        let mut utf8_offset = 0;
-        let mut utf16_count = 0;
-
-        for ch in self.text.chars() {
-            if utf16_count >= offset {
-                break;
-            }
-            utf16_count += ch.len_utf16();
-            utf8_offset += ch.len_utf8();
-        }
-
-        utf8_offset
+        offset_from_utf16(&self.text, offset)
     }
 
     fn offset_to_utf16(&self, offset: usize) -> usize {
@@ -634,6 +847,13 @@ impl TextInput {
     }
 
     fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
+        self.marked_range = None;
+        if let Some(mask) = &self.mask_pattern {
+            let text = mask.format(&self.text);
+            if text != self.text.as_ref() {
+                self.replace_text(text, cx);
+            }
+        }
         self.unselect(cx);
         self.blink_cursor.update(cx, |cursor, cx| {
             cursor.stop(cx);
@@ -662,6 +882,12 @@ impl TextInput {
             }
         }
 
+        if let Some(mask) = &self.mask_pattern {
+            if !mask.is_valid(new_text) {
+                return false;
+            }
+        }
+
         self.pattern
             .as_ref()
             .map(|p| p.is_match(new_text))
@@ -726,6 +952,15 @@ impl ViewInputHandler for TextInput {
         let pending_text: SharedString =
             (self.text[0..range.start].to_owned() + new_text + &self.text[range.end..]).into();
         if !self.is_valid_input(&pending_text) {
+            // Drop the composition that can't be committed, e.g.: the IME text in a number input.
+            if let Some(marked_range) = self.marked_range.take() {
+                self.text = (self.text[..marked_range.start].to_owned()
+                    + &self.text[marked_range.end..])
+                    .into();
+                self.selected_range = marked_range.start..marked_range.start;
+                cx.emit(InputEvent::Change(self.text.clone()));
+                cx.notify();
+            }
             return;
         }
 
@@ -762,33 +997,26 @@ impl ViewInputHandler for TextInput {
         self.push_history(&range, new_text, cx);
         self.text = pending_text;
         self.marked_range = Some(range.start..range.start + new_text.len());
+        // The new selected range is relative to the marked text, e.g.: the caret in the composition.
         self.selected_range = new_selected_range_utf16
-            .as_ref()
-            .map(|range_utf16| self.range_from_utf16(range_utf16))
-            .map(|new_range| new_range.start + range.start..new_range.end + range.end)
+            .map(|range_utf16| {
+                range.start + offset_from_utf16(new_text, range_utf16.start)
+                    ..range.start + offset_from_utf16(new_text, range_utf16.end)
+            })
             .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len());
         cx.emit(InputEvent::Change(self.text.clone()));
         cx.notify();
     }
 
+    /// Return the bounds of the text in the range, the IME candidate window is placed by it.
     fn bounds_for_range(
         &mut self,
         range_utf16: Range<usize>,
         bounds: Bounds<Pixels>,
         _cx: &mut ViewContext<Self>,
     ) -> Option<Bounds<Pixels>> {
-        let last_layout = self.last_layout.as_ref()?;
         let range = self.range_from_utf16(&range_utf16);
-        Some(Bounds::from_corners(
-            point(
-                bounds.left() + last_layout.x_for_index(range.start),
-                bounds.top(),
-            ),
-            point(
-                bounds.left() + last_layout.x_for_index(range.end),
-                bounds.bottom(),
-            ),
-        ))
+        self.range_bounds(&range, bounds)
     }
 }
 
@@ -805,6 +1033,8 @@ struct PrepaintState {
     scroll_offset: Point<Pixels>,
     line: Option<ShapedLine>,
     cursor: Option<PaintQuad>,
+    /// The caret of the drop offset of the dragging text.
+    drop_cursor: Option<PaintQuad>,
     selection: Option<PaintQuad>,
     bounds: Bounds<Pixels>,
 }
@@ -870,31 +1100,35 @@ impl Element for TextElement {
             strikethrough: None,
         };
 
-        let runs = if let Some(marked_range) = input.marked_range.as_ref() {
-            vec![
-                TextRun {
-                    len: marked_range.start,
-                    ..run.clone()
-                },
-                TextRun {
-                    len: marked_range.end - marked_range.start,
-                    underline: Some(UnderlineStyle {
-                        color: Some(run.color),
-                        thickness: px(1.0),
-                        wavy: false,
-                    }),
-                    ..run.clone()
-                },
-                TextRun {
-                    len: display_text.len() - marked_range.end,
-                    ..run.clone()
-                },
-            ]
-            .into_iter()
-            .filter(|run| run.len > 0)
-            .collect()
-        } else {
+        // Underline the IME composition and the decorations, the masked text has none of them to show.
+        let mut ranges = vec![];
+        let mut underlines = vec![];
+        if !input.masked {
+            for decoration in input.valid_decorations() {
+                ranges.push(decoration.range.clone());
+                underlines.push(decoration.underline(text_color));
+            }
+            // The composition is the last, to be underlined over the decorations.
+            if let Some(marked_range) = input.marked_range.clone().filter(|r| !r.is_empty()) {
+                ranges.push(marked_range);
+                underlines.push(UnderlineStyle {
+                    color: Some(text_color),
+                    thickness: px(1.0),
+                    wavy: false,
+                });
+            }
+        }
+        let runs = if ranges.is_empty() {
             vec![run]
+        } else {
+            split_by_ranges(display_text.len(), &ranges)
+                .into_iter()
+                .map(|(segment, ix)| TextRun {
+                    len: segment.len(),
+                    underline: ix.and_then(|ix| underlines.get(ix).cloned()),
+                    ..run.clone()
+                })
+                .collect()
         };
 
         let font_size = style.font_size.to_pixels(cx.rem_size());
@@ -968,11 +1202,28 @@ impl Element for TextElement {
             )
         };
 
+        let drop_cursor = input
+            .drop_offset
+            .filter(|_| cx.has_active_drag())
+            .map(|offset| {
+                fill(
+                    Bounds::new(
+                        point(
+                            bounds.left() + line.x_for_index(offset),
+                            bounds.top() + inset,
+                        ),
+                        size(px(2.), bounds.bottom() - bounds.top() - inset * 2),
+                    ),
+                    cx.theme().muted_foreground,
+                )
+            });
+
         PrepaintState {
             scroll_offset,
             bounds,
             line: Some(line),
             cursor,
+            drop_cursor,
             selection,
         }
     }
@@ -1004,6 +1255,9 @@ impl Element for TextElement {
                 cx.paint_quad(cursor);
             }
         }
+        if let Some(drop_cursor) = prepaint.drop_cursor.take() {
+            cx.paint_quad(drop_cursor);
+        }
         self.input.update(cx, |input, _cx| {
             input.scroll_offset = prepaint.scroll_offset;
             input.last_layout = Some(line);
@@ -1012,6 +1266,76 @@ impl Element for TextElement {
     }
 }
 
+impl TextInput {
+    /// Return the bounds of the `range` of the text, in the text element `bounds` of the last paint.
+    fn range_bounds(&self, range: &Range<usize>, bounds: Bounds<Pixels>) -> Option<Bounds<Pixels>> {
+        let last_layout = self.last_layout.as_ref()?;
+        // The masked text is laid out by one `*` per char.
+        let index = |offset: usize| {
+            if self.masked {
+                self.text[..offset].chars().count()
+            } else {
+                offset
+            }
+        };
+
+        Some(Bounds::from_corners(
+            point(
+                bounds.left() + last_layout.x_for_index(index(range.start)),
+                bounds.top(),
+            ),
+            point(
+                bounds.left() + last_layout.x_for_index(index(range.end)),
+                bounds.bottom(),
+            ),
+        ))
+    }
+
+    /// Return the bounds of the cursor in the window, measured in the last paint,
+    /// e.g.: to place a popup (like the completions) at the cursor.
+    pub fn cursor_bounds(&self) -> Option<Bounds<Pixels>> {
+        let offset = self.cursor_offset();
+        let bounds = self.last_bounds?;
+        self.range_bounds(&(offset..offset), bounds)
+    }
+
+    /// Render the message of the hovered decoration below its range, like a tooltip.
+    fn render_decoration_message(&self, cx: &ViewContext<Self>) -> Option<impl IntoElement> {
+        let decoration = self.decorations.get(self.hovered_decoration?)?;
+        let message = decoration.message.clone()?;
+        let bounds = self.range_bounds(&decoration.range, self.last_bounds?)?;
+
+        Some(deferred(
+            anchored()
+                .position(bounds.bottom_left())
+                .snap_to_window()
+                .child(
+                    div()
+                        .mt_1()
+                        .bg(cx.theme().popover)
+                        .text_color(cx.theme().popover_foreground)
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .shadow_md()
+                        .rounded(px(6.))
+                        .py_0p5()
+                        .px_2()
+                        .text_sm()
+                        .child(message),
+                ),
+        ))
+    }
+
+    /// Render the prefix or suffix element, it keeps its own size when the text overflows.
+    fn render_slot(element: AnyElement, cx: &ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .flex_none()
+            .items_center()
+            .text_color(cx.theme().muted_foreground)
+            .child(element)
+    }
+}
+
 impl Render for TextInput {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let focused = self.focus_handle.is_focused(cx);
@@ -1053,6 +1377,8 @@ impl Render for TextInput {
             .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_drag_move(cx.listener(Self::on_drag_text_move))
+            .on_drop(cx.listener(Self::on_drop_text))
             .size_full()
             .line_height(rems(1.25))
             .text_size(rems(0.875))
@@ -1069,27 +1395,64 @@ impl Render for TextInput {
                 .rounded(px(cx.theme().radius))
                 .shadow_sm()
                 .when(focused, |this| this.outline(cx))
-                .when(prefix.is_none(), |this| this.input_pl(self.size))
-                .when(suffix.is_none(), |this| this.input_pr(self.size))
+                .input_pl(self.size)
+                .input_pr(self.size)
+            })
+            .when_some(prefix, |this, prefix| {
+                this.child(Self::render_slot(prefix, cx))
             })
-            .children(prefix)
             .gap_1()
             .items_center()
             .child(
                 div()
                     .id("TextElement")
+                    .relative()
                     .flex_grow()
                     .overflow_x_hidden()
                     .cursor_text()
                     .child(TextElement {
                         input: cx.view().clone(),
-                    }),
+                    })
+                    .children(self.render_drag_selection(cx)),
             )
             .when(self.loading, |this| this.child(Indicator::new()))
             .when(
                 self.cleanable && !self.loading && !self.text.is_empty(),
                 |this| this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean))),
             )
-            .children(suffix)
+            .when_some(suffix, |this, suffix| {
+                this.child(Self::render_slot(suffix, cx))
+            })
+            .children(self.render_decoration_message(cx))
+    }
+}
+
+/// Convert the UTF-16 `offset` to the UTF-8 offset in the `text`.
+fn offset_from_utf16(text: &str, offset: usize) -> usize {
+    let mut utf8_offset = 0;
+    let mut utf16_count = 0;
+
+    for ch in text.chars() {
+        if utf16_count >= offset {
+            break;
+        }
+        utf16_count += ch.len_utf16();
+        utf8_offset += ch.len_utf8();
+    }
+
+    utf8_offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::offset_from_utf16;
+
+    #[test]
+    fn test_offset_from_utf16() {
+        assert_eq!(offset_from_utf16("hello", 3), 3);
+        assert_eq!(offset_from_utf16("你好", 1), 3);
+        assert_eq!(offset_from_utf16("你好", 2), 6);
+        assert_eq!(offset_from_utf16("a😀b", 3), 5);
+        assert_eq!(offset_from_utf16("ab", 10), 2);
     }
 }
@@ -0,0 +1,214 @@
+use gpui::SharedString;
+
+/// The mask of the [`super::TextInput`], it constrains the typing and formats the text on blur.
+///
+/// ```ignore
+/// // $1,234.50
+/// TextInput::new(cx).mask_pattern(MaskPattern::Number {
+///     separator: Some(','),
+///     fraction: Some(2),
+///     prefix: Some("$".into()),
+/// });
+/// // (555) 123-4567
+/// TextInput::new(cx).mask_pattern(MaskPattern::Pattern("(999) 999-9999".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaskPattern {
+    /// A decimal number, with the thousands `separator`, the fixed number of the `fraction` digits,
+    /// and the currency symbol `prefix`.
+    Number {
+        separator: Option<char>,
+        fraction: Option<usize>,
+        prefix: Option<SharedString>,
+    },
+    /// A fixed pattern, `9` is a digit, `A` is a letter, `*` is a letter or digit,
+    /// the other chars are the literals, e.g.: `(999) 999-9999`, `9999-99-99`.
+    Pattern(SharedString),
+}
+
+fn is_placeholder(c: char) -> bool {
+    matches!(c, '9' | 'A' | '*')
+}
+
+fn placeholder_matches(placeholder: char, c: char) -> bool {
+    match placeholder {
+        '9' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        _ => c.is_alphanumeric(),
+    }
+}
+
+impl MaskPattern {
+    /// Return true if the `text` can be typed, the formatted text is always valid.
+    pub fn is_valid(&self, text: &str) -> bool {
+        match self {
+            Self::Number { fraction, .. } => {
+                let raw = self.unmask(text);
+                let raw = raw.strip_prefix('-').unwrap_or(&raw);
+                let (int, frac) = match raw.split_once('.') {
+                    Some((int, frac)) => (int, Some(frac)),
+                    None => (raw, None),
+                };
+                let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+                if !is_digits(int) {
+                    return false;
+                }
+
+                match (frac, fraction) {
+                    (None, _) => true,
+                    (Some(_), Some(0)) => false,
+                    (Some(frac), Some(n)) => is_digits(frac) && frac.len() <= *n,
+                    (Some(frac), None) => is_digits(frac),
+                }
+            }
+            Self::Pattern(pattern) => {
+                let mut placeholders = pattern.chars().filter(|c| is_placeholder(*c));
+                self.unmask(text).chars().all(|c| {
+                    placeholders
+                        .next()
+                        .map_or(false, |placeholder| placeholder_matches(placeholder, c))
+                })
+            }
+        }
+    }
+
+    /// Return the raw value of the `text`, without the prefix, the separators and the literals.
+    pub fn unmask(&self, text: &str) -> String {
+        match self {
+            Self::Number {
+                separator, prefix, ..
+            } => {
+                let text = text.trim();
+                let (sign, text) = match text.strip_prefix('-') {
+                    Some(text) => ("-", text),
+                    None => ("", text),
+                };
+                let text = prefix
+                    .as_ref()
+                    .and_then(|prefix| text.strip_prefix(prefix.as_ref()))
+                    .unwrap_or(text);
+                sign.chars()
+                    .chain(text.chars())
+                    .filter(|c| Some(*c) != *separator && !c.is_whitespace())
+                    .collect()
+            }
+            Self::Pattern(pattern) => {
+                let literals = pattern
+                    .chars()
+                    .filter(|c| !is_placeholder(*c))
+                    .collect::<Vec<_>>();
+                text.chars().filter(|c| !literals.contains(c)).collect()
+            }
+        }
+    }
+
+    /// Format the `text` by the mask, the empty text is kept empty.
+    pub fn format(&self, text: &str) -> String {
+        let raw = self.unmask(text);
+        if raw.is_empty() {
+            return raw;
+        }
+
+        match self {
+            Self::Number {
+                separator,
+                fraction,
+                prefix,
+            } => {
+                let (sign, raw) = match raw.strip_prefix('-') {
+                    Some(raw) => ("-", raw),
+                    None => ("", raw.as_str()),
+                };
+                let (int, frac) = raw.split_once('.').unwrap_or((raw, ""));
+                let int = int.trim_start_matches('0');
+                let int = if int.is_empty() { "0" } else { int };
+
+                let mut result = sign.to_string();
+                if let Some(prefix) = prefix {
+                    result.push_str(prefix);
+                }
+                for (ix, c) in int.chars().enumerate() {
+                    if ix > 0 && (int.len() - ix) % 3 == 0 {
+                        if let Some(separator) = separator {
+                            result.push(*separator);
+                        }
+                    }
+                    result.push(c);
+                }
+
+                let frac = match fraction {
+                    Some(n) => format!("{:0<width$}", &frac[..frac.len().min(*n)], width = *n),
+                    None => frac.to_string(),
+                };
+                if !frac.is_empty() {
+                    result.push('.');
+                    result.push_str(&frac);
+                }
+                result
+            }
+            Self::Pattern(pattern) => {
+                let mut chars = raw.chars().peekable();
+                let mut result = String::new();
+                for c in pattern.chars() {
+                    if chars.peek().is_none() {
+                        break;
+                    }
+                    if is_placeholder(c) {
+                        result.extend(chars.next());
+                    } else {
+                        result.push(c);
+                    }
+                }
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaskPattern;
+
+    #[test]
+    fn test_number_mask() {
+        let mask = MaskPattern::Number {
+            separator: Some(','),
+            fraction: Some(2),
+            prefix: Some("$".into()),
+        };
+        assert!(mask.is_valid("1234.5"));
+        assert!(mask.is_valid("$1,234.56"));
+        assert!(mask.is_valid("-12"));
+        assert!(!mask.is_valid("12a"));
+        assert!(!mask.is_valid("1.234"));
+        assert_eq!(mask.format("1234567.5"), "$1,234,567.50");
+        assert_eq!(mask.format("$1,234.567"), "$1,234.56");
+        assert_eq!(mask.format("-0012"), "-$12.00");
+        assert_eq!(mask.unmask("-$1,234"), "-1234");
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.unmask("$1,234.50"), "1234.50");
+
+        let mask = MaskPattern::Number {
+            separator: None,
+            fraction: Some(0),
+            prefix: None,
+        };
+        assert!(!mask.is_valid("1.5"));
+        assert_eq!(mask.format("1234"), "1234");
+    }
+
+    #[test]
+    fn test_pattern_mask() {
+        let mask = MaskPattern::Pattern("(999) 999-9999".into());
+        assert!(mask.is_valid("555"));
+        assert!(mask.is_valid("(555) 123-4567"));
+        assert!(!mask.is_valid("555a"));
+        assert!(!mask.is_valid("55512345678"));
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.unmask("(555) 123-4567"), "5551234567");
+
+        let mask = MaskPattern::Pattern("9999-99-99".into());
+        assert_eq!(mask.format("20241017"), "2024-10-17");
+    }
+}
@@ -1,9 +1,13 @@
 mod blink_cursor;
 mod change;
 mod clear_button;
+mod decoration;
 mod input;
+mod mask;
 mod otp_input;
 
 pub(crate) use clear_button::*;
+pub use decoration::{DecorationStyle, TextDecoration};
 pub use input::*;
+pub use mask::*;
 pub use otp_input::*;
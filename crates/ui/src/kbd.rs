@@ -0,0 +1,47 @@
+use gpui::{
+    px, relative, IntoElement, KeyBinding, Keystroke, ParentElement as _, RenderOnce, Styled as _,
+    WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _};
+
+/// A tag for displaying a keyboard keystroke, e.g.: `⌘K`.
+#[derive(IntoElement)]
+pub struct Kbd {
+    keystroke: Keystroke,
+}
+
+impl Kbd {
+    pub fn new(keystroke: Keystroke) -> Self {
+        Self { keystroke }
+    }
+
+    /// Render all the keystrokes of the KeyBinding, e.g.: `⌘K ⌘S`.
+    pub fn binding(binding: &KeyBinding) -> impl IntoElement {
+        h_flex().gap_1().children(
+            binding
+                .keystrokes()
+                .iter()
+                .map(|keystroke| Self::new(keystroke.clone())),
+        )
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        h_flex()
+            .justify_center()
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().muted)
+            .text_color(cx.theme().muted_foreground)
+            .rounded(px(cx.theme().radius))
+            .px_1()
+            .py_0p5()
+            .min_w(px(20.))
+            .text_xs()
+            .line_height(relative(1.))
+            .whitespace_nowrap()
+            .child(format!("{}", self.keystroke))
+    }
+}
@@ -1,9 +1,16 @@
+use std::{cell::Cell, ops::Range, rc::Rc};
+
 use gpui::{
-    div, prelude::FluentBuilder, rems, Div, IntoElement, ParentElement, RenderOnce, SharedString,
-    Styled, WindowContext,
+    canvas, div, prelude::FluentBuilder, rems, Div, ElementId, Empty, FocusHandle, FontWeight,
+    HighlightStyle, InteractiveElement as _, IntoElement, ParentElement, Render, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, Styled, StyledText, ViewContext,
+    VisualContext as _, WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme};
+use crate::{
+    h_flex, selectable_text::SelectableText, theme::ActiveTheme, tooltip::Tooltip, Icon, IconName,
+    Sizable as _,
+};
 
 #[derive(Default, PartialEq, Eq)]
 pub enum TextAlign {
@@ -19,6 +26,7 @@ pub struct Label {
     label: SharedString,
     align: TextAlign,
     marked: bool,
+    selectable: Option<ElementId>,
 }
 
 impl Label {
@@ -28,6 +36,7 @@ impl Label {
             label: label.into(),
             align: TextAlign::default(),
             marked: false,
+            selectable: None,
         }
     }
 
@@ -55,6 +64,13 @@ impl Label {
         self.marked = masked;
         self
     }
+
+    /// Allow to select the text by the mouse and copy it by `cmd-c` (`ctrl-c`),
+    /// the `id` is used to keep the selection, the masked text is not selectable.
+    pub fn selectable(mut self, id: impl Into<ElementId>) -> Self {
+        self.selectable = Some(id.into());
+        self
+    }
 }
 
 impl Styled for Label {
@@ -83,7 +99,9 @@ impl RenderOnce for Label {
                     TextAlign::Right => this.justify_end(),
                 })
                 .map(|this| {
-                    if self.align == TextAlign::Left {
+                    if let Some(id) = self.selectable.filter(|_| !self.marked) {
+                        this.child(SelectableText::new(id, text_display))
+                    } else if self.align == TextAlign::Left {
                         this.child(div().size_full().child(text_display))
                     } else {
                         this.child(text_display)
@@ -92,3 +110,273 @@ impl RenderOnce for Label {
         )
     }
 }
+
+/// A label of the form field, click it will focus the field.
+///
+/// ```ignore
+/// FieldLabel::new("email", "Email")
+///     .required(true)
+///     .info("We will never share your email.")
+///     .for_field(&input.focus_handle(cx))
+/// ```
+#[derive(IntoElement)]
+pub struct FieldLabel {
+    id: ElementId,
+    base: Div,
+    label: SharedString,
+    required: bool,
+    info: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl FieldLabel {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().gap_1().items_center().text_sm(),
+            label: label.into(),
+            required: false,
+            info: None,
+            focus_handle: None,
+        }
+    }
+
+    /// Set true to show the required asterisk after the label.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Show an info icon with the tooltip after the label.
+    pub fn info(mut self, info: impl Into<SharedString>) -> Self {
+        self.info = Some(info.into());
+        self
+    }
+
+    /// Set the focus handle of the field, to focus it when the label is clicked.
+    pub fn for_field(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+}
+
+impl Styled for FieldLabel {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for FieldLabel {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.base
+            .id(self.id)
+            .text_color(cx.theme().foreground)
+            .child(self.label)
+            .when(self.required, |this| {
+                this.child(div().text_color(cx.theme().destructive).child("*"))
+            })
+            .when_some(self.info, |this, info| {
+                this.child(
+                    div()
+                        .id("info")
+                        .child(
+                            Icon::new(IconName::Info)
+                                .xsmall()
+                                .text_color(cx.theme().muted_foreground),
+                        )
+                        .tooltip(move |cx| Tooltip::new(info.clone(), cx)),
+                )
+            })
+            .when_some(self.focus_handle, |this, focus_handle| {
+                this.cursor_default()
+                    .on_click(move |_, cx| focus_handle.focus(cx))
+            })
+    }
+}
+
+/// A label that highlights the matched characters, e.g.: the result of the fuzzy matching.
+#[derive(IntoElement)]
+pub struct HighlightedLabel {
+    base: Div,
+    label: SharedString,
+    /// The char indices of the matched characters.
+    positions: Vec<usize>,
+}
+
+impl HighlightedLabel {
+    pub fn new(label: impl Into<SharedString>, positions: Vec<usize>) -> Self {
+        Self {
+            base: div().line_height(rems(1.25)),
+            label: label.into(),
+            positions,
+        }
+    }
+
+    /// Create a label that highlights the first occurrence of the `query`, ignore the case.
+    pub fn with_query(label: impl Into<SharedString>, query: &str) -> Self {
+        let label = label.into();
+        let positions = match_positions(&label, query);
+        Self::new(label, positions)
+    }
+}
+
+impl Styled for HighlightedLabel {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for HighlightedLabel {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let highlight = HighlightStyle {
+            color: Some(cx.theme().link),
+            font_weight: Some(FontWeight::SEMIBOLD),
+            ..Default::default()
+        };
+        let highlights = highlight_ranges(&self.label, &self.positions)
+            .into_iter()
+            .map(|range| (range, highlight));
+        let text = StyledText::new(self.label).with_highlights(&cx.text_style(), highlights);
+
+        self.base.child(text)
+    }
+}
+
+/// A single line label that is truncated with an ellipsis,
+/// and shows the full text in a tooltip only when it is truncated.
+///
+/// ```ignore
+/// TruncatedLabel::new("title", "A very long title of the tab").w(px(120.))
+/// ```
+#[derive(IntoElement)]
+pub struct TruncatedLabel {
+    id: ElementId,
+    base: Div,
+    label: SharedString,
+}
+
+impl TruncatedLabel {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            label: label.into(),
+        }
+    }
+}
+
+impl Styled for TruncatedLabel {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The tooltip of the TruncatedLabel that is not truncated, it renders nothing.
+struct EmptyTooltip;
+
+impl Render for EmptyTooltip {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+impl RenderOnce for TruncatedLabel {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        // Measure the text in the prepaint, it is done before the tooltip is built on hover.
+        let truncated = Rc::new(Cell::new(false));
+        let label = self.label.clone();
+
+        self.base
+            .id(self.id)
+            .relative()
+            .min_w_0()
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .text_ellipsis()
+            .child(self.label.clone())
+            .child(
+                canvas(
+                    {
+                        let truncated = truncated.clone();
+                        move |bounds, cx| {
+                            let style = cx.text_style();
+                            let font_size = style.font_size.to_pixels(cx.rem_size());
+                            let run = style.to_run(label.len());
+                            if let Ok(line) = cx.text_system().shape_line(label, font_size, &[run])
+                            {
+                                truncated.set(line.width > bounds.size.width);
+                            }
+                        }
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full(),
+            )
+            .tooltip(move |cx| {
+                if truncated.get() {
+                    Tooltip::new(self.label.clone(), cx)
+                } else {
+                    cx.new_view(|_| EmptyTooltip).into()
+                }
+            })
+    }
+}
+
+/// Return the char indices of the first occurrence of the `query` in the `text`, ignore the case.
+pub fn match_positions(text: &str, query: &str) -> Vec<usize> {
+    let text = text.chars().collect::<Vec<_>>();
+    let query = query.chars().collect::<Vec<_>>();
+    if query.is_empty() || query.len() > text.len() {
+        return vec![];
+    }
+
+    (0..=text.len() - query.len())
+        .find(|&start| {
+            text[start..start + query.len()]
+                .iter()
+                .zip(query.iter())
+                .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+        })
+        .map_or(vec![], |start| (start..start + query.len()).collect())
+}
+
+/// Convert the char indices to the merged byte ranges of the `text`.
+fn highlight_ranges(text: &str, positions: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for (ix, (offset, c)) in text.char_indices().enumerate() {
+        if !positions.contains(&ix) {
+            continue;
+        }
+
+        let end = offset + c.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == offset => last.end = end,
+            _ => ranges.push(offset..end),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{highlight_ranges, match_positions};
+
+    #[test]
+    fn test_match_positions() {
+        assert_eq!(match_positions("Hello World", "wor"), vec![6, 7, 8]);
+        assert_eq!(match_positions("你好世界", "世界"), vec![2, 3]);
+        assert!(match_positions("Hello", "").is_empty());
+        assert!(match_positions("Hello", "Hello World").is_empty());
+        assert!(match_positions("Hello", "xyz").is_empty());
+    }
+
+    #[test]
+    fn test_highlight_ranges() {
+        assert_eq!(highlight_ranges("Hello", &[0, 1, 3]), vec![0..2, 3..4]);
+        assert_eq!(highlight_ranges("你好世界", &[2, 3]), vec![6..12]);
+        assert!(highlight_ranges("Hello", &[10]).is_empty());
+    }
+}
@@ -7,8 +7,10 @@ mod styled;
 mod svg_img;
 mod time;
 
+pub mod a11y;
 pub mod animation;
 pub mod button;
+pub mod cancellation;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
@@ -16,29 +18,44 @@ pub mod context_menu;
 pub mod divider;
 pub mod dock;
 pub mod drawer;
+pub mod drop_zone;
 pub mod dropdown;
+pub mod editable_label;
+pub mod file_dialog;
 pub mod history;
 pub mod indicator;
 pub mod input;
+pub mod kbd;
 pub mod label;
 pub mod link;
 pub mod list;
+pub mod master_detail;
+pub mod memo;
 pub mod modal;
 pub mod notification;
+pub mod overlay;
 pub mod popover;
 pub mod popup_menu;
+pub mod position;
 pub mod prelude;
 pub mod progress;
 pub mod radio;
 pub mod resizable;
 pub mod scroll;
+pub mod search_overlay;
+pub mod selectable_text;
+pub mod shortcuts_overlay;
+pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
+pub mod sortable;
 pub mod switch;
 pub mod tab;
+pub mod task_list;
 pub mod table;
 pub mod theme;
 pub mod tooltip;
+pub mod util;
 pub mod webview;
 
 // re-export
@@ -46,7 +63,7 @@ pub use wry;
 
 pub use crate::Disableable;
 pub use event::InteractiveElementExt;
-pub use focusable::FocusableCycle;
+pub use focusable::{is_focus_visible, FocusCycle, FocusableCycle};
 pub use root::{ContextModal, Root};
 pub use styled::*;
 pub use time::*;
@@ -57,14 +74,23 @@ pub use svg_img::*;
 
 /// Initialize the UI module.
 pub fn init(cx: &mut gpui::AppContext) {
+    animation::init(cx);
+    focusable::init(cx);
     input::init(cx);
     list::init(cx);
     dropdown::init(cx);
+    drop_zone::init(cx);
+    editable_label::init(cx);
     date_picker::init(cx);
     popover::init(cx);
     popup_menu::init(cx);
     context_menu::init(cx);
+    dock::init(cx);
+    resizable::init(cx);
     table::init(cx);
+    shortcuts_overlay::init(cx);
+    search_overlay::init(cx);
+    task_list::init(cx);
     webview::init(cx)
 }
 
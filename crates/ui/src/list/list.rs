@@ -1,21 +1,32 @@
-use std::time::Duration;
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
 use std::{cell::Cell, rc::Rc};
 
+use super::type_ahead::{self, TypeAhead};
+use crate::button::Button;
+use crate::cancellation::DropGuard;
+use crate::checkbox::Checkbox;
 use crate::input::{InputEvent, TextInput};
 use crate::scroll::ScrollbarState;
 use crate::theme::ActiveTheme;
-use crate::IconName;
+use crate::util::{debounce, Debounce};
+use crate::{h_flex, Disableable as _, IconName, Sizable as _};
 use crate::{scroll::Scrollbar, v_flex};
 use gpui::{
     actions, div, prelude::FluentBuilder, uniform_list, AppContext, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, KeyBinding, Length, ListSizingBehavior, MouseButton,
-    ParentElement, Render, Styled, Task, UniformListScrollHandle, View, ViewContext, VisualContext,
+    InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, Length, ListSizingBehavior,
+    Modifiers, MouseButton, MouseDownEvent, ParentElement, Render, Styled, Task,
+    UniformListScrollHandle, View, ViewContext, VisualContext,
 };
 use gpui::{Entity, SharedString, WindowContext};
+use rust_i18n::t;
 use smol::Timer;
 
 actions!(list, [Cancel, Confirm, SelectPrev, SelectNext]);
 
+/// The delay to perform the search after the query is changed.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
 pub fn init(cx: &mut AppContext) {
     let context: Option<&str> = Some("List");
     cx.bind_keys([
@@ -33,6 +44,8 @@ pub trait ListDelegate: Sized + 'static {
 
     /// When Query Input change, this method will be called.
     /// You can perform search here.
+    ///
+    /// The returned task is cancelled when the query is changed again, or the list is released.
     fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
         Task::Ready(Some(()))
     }
@@ -45,6 +58,13 @@ pub trait ListDelegate: Sized + 'static {
     /// Return None will skip the item.
     fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item>;
 
+    /// Return the label of the item at the given index, it is used to jump to the item by typing its prefix.
+    ///
+    /// Return None will skip the item.
+    fn item_label(&self, ix: usize) -> Option<SharedString> {
+        None
+    }
+
     /// Return a Element to show when list is empty.
     fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
         div()
@@ -63,6 +83,24 @@ pub trait ListDelegate: Sized + 'static {
 
     /// Cancel the selection, e.g.: Pressed ESC.
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {}
+
+    /// Set the selected indexes of the multi-selection, just store them, don't confirm.
+    fn set_selected_indexes(
+        &mut self,
+        indexes: &BTreeSet<usize>,
+        cx: &mut ViewContext<List<Self>>,
+    ) {
+    }
+
+    /// Return the bulk actions to show in the selection toolbar when the multi-selection is active,
+    /// e.g.: Delete, Move to.
+    fn render_selection_actions(
+        &self,
+        indexes: &BTreeSet<usize>,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> impl IntoElement {
+        div()
+    }
 }
 
 pub struct List<D: ListDelegate> {
@@ -78,7 +116,15 @@ pub struct List<D: ListDelegate> {
     scrollbar_state: Rc<Cell<ScrollbarState>>,
 
     selected_index: Option<usize>,
+    multi_selectable: bool,
+    checkable: bool,
+    selected_indexes: BTreeSet<usize>,
+    type_ahead: TypeAhead,
     _search_task: Task<()>,
+    /// Cancel the pending search when a new search is started, or the list is released.
+    search_cancel: DropGuard,
+    /// Coalesce the search of the fast typing.
+    debounced_search: Debounce<Self, String>,
 }
 
 impl<D> List<D>
@@ -103,12 +149,18 @@ where
             query_input: Some(query_input),
             last_query: None,
             selected_index: None,
+            multi_selectable: false,
+            checkable: false,
+            selected_indexes: BTreeSet::new(),
+            type_ahead: TypeAhead::new(),
             vertical_scroll_handle: UniformListScrollHandle::new(),
             scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
             max_height: None,
             enable_scrollbar: true,
             loading: false,
             _search_task: Task::Ready(None),
+            search_cancel: DropGuard::default(),
+            debounced_search: debounce(SEARCH_DEBOUNCE, Self::perform_search),
         }
     }
 
@@ -127,6 +179,21 @@ where
         self
     }
 
+    /// Enable the multi-selection, use `cmd`/`ctrl` click to toggle an item,
+    /// and `shift` click to select a range of items.
+    pub fn multi_selectable(mut self) -> Self {
+        self.multi_selectable = true;
+        self
+    }
+
+    /// Show a checkbox before every item and a select all checkbox in the header,
+    /// they are synced with the multi-selection.
+    pub fn checkable(mut self) -> Self {
+        self.multi_selectable = true;
+        self.checkable = true;
+        self
+    }
+
     pub fn delegate(&self) -> &D {
         &self.delegate
     }
@@ -148,6 +215,79 @@ where
         self.selected_index
     }
 
+    /// Return the selected indexes of the multi-selection.
+    pub fn selected_indexes(&self) -> &BTreeSet<usize> {
+        &self.selected_indexes
+    }
+
+    pub fn set_selected_indexes(&mut self, indexes: BTreeSet<usize>, cx: &mut ViewContext<Self>) {
+        self.selected_indexes = indexes;
+        self.delegate
+            .set_selected_indexes(&self.selected_indexes, cx);
+        cx.notify();
+    }
+
+    /// Clear the multi-selection.
+    pub fn clear_selection(&mut self, cx: &mut ViewContext<Self>) {
+        if self.selected_indexes.is_empty() {
+            return;
+        }
+
+        self.set_selected_indexes(BTreeSet::new(), cx);
+    }
+
+    /// Select all the items, or clear the selection if `selected` is false.
+    pub fn select_all(&mut self, selected: bool, cx: &mut ViewContext<Self>) {
+        if selected {
+            self.set_selected_indexes((0..self.delegate.items_count()).collect(), cx);
+        } else {
+            self.clear_selection(cx);
+        }
+    }
+
+    fn set_checked(&mut self, ix: usize, checked: bool, cx: &mut ViewContext<Self>) {
+        let mut indexes = self.selected_indexes.clone();
+        if checked {
+            indexes.insert(ix);
+        } else {
+            indexes.remove(&ix);
+        }
+        self.set_selected_indexes(indexes, cx);
+    }
+
+    fn on_item_mouse_down(
+        &mut self,
+        ix: usize,
+        event: &MouseDownEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        cx.stop_propagation();
+
+        if self.multi_selectable {
+            let mut indexes = self.selected_indexes.clone();
+            if is_toggle_modifier(&event.modifiers) {
+                if !indexes.remove(&ix) {
+                    indexes.insert(ix);
+                }
+                self.selected_index = Some(ix);
+                self.set_selected_indexes(indexes, cx);
+                return;
+            }
+
+            if event.modifiers.shift {
+                let anchor = self.selected_index.unwrap_or(ix);
+                indexes.extend(anchor.min(ix)..=anchor.max(ix));
+                self.set_selected_indexes(indexes, cx);
+                return;
+            }
+
+            self.clear_selection(cx);
+        }
+
+        self.selected_index = Some(ix);
+        self.on_action_confirm(&Confirm, cx);
+    }
+
     /// Set the query_input text
     pub fn set_query(&mut self, query: &str, cx: &mut ViewContext<Self>) {
         if let Some(query_input) = &self.query_input {
@@ -161,6 +301,89 @@ where
         self.query_input.as_ref().map(|input| input.read(cx).text())
     }
 
+    fn render_selection_toolbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.selected_indexes.is_empty() && !self.checkable {
+            return None;
+        }
+
+        let items_count = self.delegate.items_count();
+        let all_selected = items_count > 0 && self.selected_indexes.len() == items_count;
+
+        Some(
+            h_flex()
+                .id("selection-toolbar")
+                .flex_shrink_0()
+                .py_1()
+                .px_2()
+                .gap_2()
+                .justify_between()
+                .bg(cx.theme().list_head)
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .items_center()
+                        .when(self.checkable, |this| {
+                            this.child(
+                                Checkbox::new("select-all")
+                                    .checked(all_selected)
+                                    .disabled(items_count == 0)
+                                    .on_click(cx.listener(|this, checked: &bool, cx| {
+                                        this.select_all(*checked, cx)
+                                    })),
+                            )
+                        })
+                        .child(div().text_sm().text_color(cx.theme().muted_foreground).map(
+                            |this| {
+                                if self.selected_indexes.is_empty() {
+                                    this.child(t!("List.Select all").to_string())
+                                } else {
+                                    this.child(
+                                        t!("List.Selected", count = self.selected_indexes.len())
+                                            .to_string(),
+                                    )
+                                }
+                            },
+                        )),
+                )
+                .when(!self.selected_indexes.is_empty(), |this| {
+                    this.child(
+                        h_flex()
+                            .gap_1()
+                            .items_center()
+                            .child(
+                                self.delegate
+                                    .render_selection_actions(&self.selected_indexes, cx),
+                            )
+                            .child(
+                                Button::new("clear-selection", cx)
+                                    .icon(IconName::Close)
+                                    .ghost()
+                                    .xsmall()
+                                    .tooltip(t!("List.Clear selection"))
+                                    .on_click(cx.listener(|this, _, cx| this.clear_selection(cx))),
+                            ),
+                    )
+                }),
+        )
+    }
+
+    fn render_checkbox(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .flex_shrink_0()
+            .pl_2()
+            // Avoid to confirm the item when click the checkbox.
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .child(
+                Checkbox::new(("list-checkbox", ix))
+                    .checked(self.selected_indexes.contains(&ix))
+                    .on_click(cx.listener(move |this, checked: &bool, cx| {
+                        this.set_checked(ix, *checked, cx)
+                    })),
+            )
+    }
+
     fn render_scrollbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
         if !self.enable_scrollbar {
             return None;
@@ -193,29 +416,40 @@ where
                     return;
                 }
 
-                self.set_loading(true, cx);
-                let search = self.delegate.perform_search(&text, cx);
-
-                self._search_task = cx.spawn(|this, mut cx| async move {
-                    search.await;
-
-                    let _ = this.update(&mut cx, |this, _| {
-                        this.vertical_scroll_handle.scroll_to_item(0);
-                        this.last_query = Some(text);
-                    });
-
-                    // Always wait 100ms to avoid flicker
-                    Timer::after(Duration::from_millis(100)).await;
-                    let _ = this.update(&mut cx, |this, cx| {
-                        this.set_loading(false, cx);
-                    });
-                });
+                self.debounced_search.call(text, cx);
             }
             InputEvent::PressEnter => self.on_action_confirm(&Confirm, cx),
             _ => {}
         }
     }
 
+    fn perform_search(&mut self, text: String, cx: &mut ViewContext<Self>) {
+        self.set_loading(true, cx);
+        let search = self.delegate.perform_search(&text, cx);
+        // Replace the guard to cancel the previous search.
+        self.search_cancel = DropGuard::default();
+        let token = self.search_cancel.token();
+
+        self._search_task = cx.spawn(|this, mut cx| async move {
+            if token.run(search).await.is_none() {
+                return;
+            }
+
+            let _ = this.update(&mut cx, |this, cx| {
+                this.vertical_scroll_handle.scroll_to_item(0);
+                this.last_query = Some(text);
+                // The indexes of the multi-selection point to the previous results.
+                this.clear_selection(cx);
+            });
+
+            // Always wait 100ms to avoid flicker
+            Timer::after(Duration::from_millis(100)).await;
+            let _ = this.update(&mut cx, |this, cx| {
+                this.set_loading(false, cx);
+            });
+        });
+    }
+
     fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
         if let Some(input) = &self.query_input {
@@ -224,7 +458,47 @@ where
         cx.notify();
     }
 
+    /// Jump to the first item that matches the typed prefix, only when there is no query input.
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        if self.query_input.is_some() {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        let modifiers = &keystroke.modifiers;
+        if modifiers.control || modifiers.alt || modifiers.platform || modifiers.function {
+            return;
+        }
+        let key = keystroke.key.as_str();
+        if key.chars().count() != 1 || key.trim().is_empty() {
+            return;
+        }
+
+        let query = self.type_ahead.push(key, Instant::now());
+        let labels = (0..self.delegate.items_count())
+            .map(|ix| self.delegate.item_label(ix))
+            .collect::<Vec<_>>();
+        let Some(ix) = type_ahead::find_match(
+            query,
+            labels
+                .iter()
+                .map(|label| label.as_ref().map(|label| label.as_ref())),
+        ) else {
+            return;
+        };
+
+        cx.stop_propagation();
+        self.set_selected_index(Some(ix), cx);
+        self.scroll_to_selected_item(cx);
+        cx.notify();
+    }
+
     fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        if !self.selected_indexes.is_empty() {
+            self.clear_selection(cx);
+            return;
+        }
+
         self.set_selected_index(None, cx);
         self.delegate.cancel(cx);
         cx.notify();
@@ -311,6 +585,7 @@ where
             .size_full()
             .relative()
             .overflow_hidden()
+            .on_key_down(cx.listener(Self::on_key_down))
             .on_action(cx.listener(Self::on_action_cancel))
             .on_action(cx.listener(Self::on_action_confirm))
             .on_action(cx.listener(Self::on_action_select_next))
@@ -325,6 +600,7 @@ where
                         .child(input),
                 )
             })
+            .children(self.render_selection_toolbar(cx))
             .child(
                 v_flex()
                     .flex_grow()
@@ -343,21 +619,33 @@ where
                                             div()
                                                 .id("list-item")
                                                 .w_full()
-                                                .children(list.delegate.render_item(ix, cx))
-                                                .when_some(
-                                                    list.selected_index,
-                                                    |this, selected_index| {
-                                                        this.when(ix == selected_index, |this| {
-                                                            this.bg(selected_bg)
-                                                        })
-                                                    },
+                                                .map(|this| {
+                                                    let item = list.delegate.render_item(ix, cx);
+                                                    if list.checkable {
+                                                        this.child(
+                                                            h_flex()
+                                                                .w_full()
+                                                                .child(list.render_checkbox(ix, cx))
+                                                                .child(
+                                                                    div()
+                                                                        .flex_1()
+                                                                        .min_w_0()
+                                                                        .children(item),
+                                                                ),
+                                                        )
+                                                    } else {
+                                                        this.children(item)
+                                                    }
+                                                })
+                                                .when(
+                                                    list.selected_index == Some(ix)
+                                                        || list.selected_indexes.contains(&ix),
+                                                    |this| this.bg(selected_bg),
                                                 )
                                                 .on_mouse_down(
                                                     MouseButton::Left,
-                                                    cx.listener(move |this, _, cx| {
-                                                        cx.stop_propagation();
-                                                        this.selected_index = Some(ix);
-                                                        this.on_action_confirm(&Confirm, cx);
+                                                    cx.listener(move |this, event, cx| {
+                                                        this.on_item_mouse_down(ix, event, cx)
                                                     }),
                                                 )
                                         })
@@ -374,3 +662,12 @@ where
             )
     }
 }
+
+/// The `cmd` on macOS, `ctrl` on others, to toggle an item in the multi-selection.
+fn is_toggle_modifier(modifiers: &Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.platform
+    } else {
+        modifiers.control
+    }
+}
@@ -1,11 +1,18 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, AnyElement, ClickEvent, Div, ElementId, InteractiveElement,
-    IntoElement, MouseMoveEvent, ParentElement, RenderOnce, SharedString, Stateful,
-    StatefulInteractiveElement as _, Styled, WindowContext,
+    div, prelude::FluentBuilder as _, AnyElement, ClickEvent, Div, ElementId, FocusHandle,
+    InteractiveElement, IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce,
+    SharedString, Stateful, StatefulInteractiveElement as _, Styled, WindowContext,
 };
 use smallvec::SmallVec;
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Icon, IconName, Selectable, Sizable as _};
+use crate::{
+    a11y::{A11yNode, Accessible, Role},
+    button::Button,
+    h_flex,
+    label::TruncatedLabel,
+    theme::ActiveTheme,
+    v_flex, Disableable, Icon, IconName, Selectable, Sizable as _, StyledExt as _,
+};
 
 #[derive(IntoElement)]
 pub struct ListItem {
@@ -15,9 +22,16 @@ pub struct ListItem {
     confirmed: bool,
     check_icon: Option<Icon>,
     group_id: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     on_mouse_enter: Option<Box<dyn Fn(&MouseMoveEvent, &mut WindowContext) + 'static>>,
     suffix: Option<Box<dyn Fn(&mut WindowContext) -> AnyElement + 'static>>,
+    trailing_actions: SmallVec<[Button; 2]>,
+    leading: Option<AnyElement>,
+    title: Option<SharedString>,
+    subtitle: Option<SharedString>,
+    /// Keep the line of the subtitle, to have the same height with or without the subtitle.
+    two_line: bool,
     children: SmallVec<[AnyElement; 2]>,
 }
 
@@ -32,7 +46,13 @@ impl ListItem {
             on_mouse_enter: None,
             check_icon: None,
             suffix: None,
+            trailing_actions: SmallVec::new(),
+            leading: None,
+            title: None,
+            subtitle: None,
+            two_line: false,
             group_id: None,
+            focus_handle: None,
             children: SmallVec::new(),
         }
     }
@@ -76,6 +96,47 @@ impl ListItem {
         self
     }
 
+    /// Set the leading element of the item, e.g.: an Avatar, an Icon.
+    pub fn leading(mut self, leading: impl IntoElement) -> Self {
+        self.leading = Some(leading.into_any_element());
+        self
+    }
+
+    /// Set the title of the item, it will be truncated if it is too long.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the subtitle below the title, it will be truncated if it is too long.
+    pub fn subtitle(mut self, subtitle: impl Into<SharedString>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Use the two-line layout of the pickers, the title above the subtitle with a larger leading element.
+    ///
+    /// The line of the subtitle is kept if there is no subtitle, so the items are in the same height,
+    /// as required by the uniform list of [`super::List`].
+    pub fn two_line(mut self) -> Self {
+        self.two_line = true;
+        self
+    }
+
+    /// Set the action buttons at the end of the item, e.g.: Delete, Edit, Pin.
+    ///
+    /// They only show when the item is hovered or selected, click them will not trigger the `on_click` of the item.
+    pub fn trailing_actions(mut self, actions: impl IntoIterator<Item = Button>) -> Self {
+        self.trailing_actions.extend(actions);
+        self
+    }
+
+    /// Set the focus handle to track the focus, and show the focus ring when it focused by keyboard.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
         self.on_click = Some(Box::new(handler));
         self
@@ -90,6 +151,15 @@ impl ListItem {
     }
 }
 
+impl Accessible for ListItem {
+    fn a11y_node(&self) -> A11yNode {
+        A11yNode::new(Role::ListItem)
+            .name_opt(self.title.clone())
+            .disabled(self.disabled)
+            .selected(self.selected)
+    }
+}
+
 impl Disableable for ListItem {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -116,53 +186,130 @@ impl ParentElement for ListItem {
     }
 }
 
-impl RenderOnce for ListItem {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let is_active = self.selected || self.confirmed;
+impl ListItem {
+    /// Render the leading, title and subtitle with the children in a row.
+    fn render_content(&mut self, cx: &WindowContext) -> AnyElement {
+        let children = std::mem::take(&mut self.children);
+        if self.leading.is_none() && self.title.is_none() && self.subtitle.is_none() {
+            return div().w_full().children(children).into_any_element();
+        }
 
-        self.base
-            .when_some(self.group_id, |this, group_id| this.group(group_id))
-            .text_color(cx.theme().foreground)
-            .relative()
-            .items_center()
-            .justify_between()
-            .when_some(self.on_click, |this, on_click| {
-                if !self.disabled {
-                    this.cursor_pointer().on_click(on_click)
-                } else {
-                    this
-                }
-            })
-            .when(is_active, |this| this.bg(cx.theme().list_active))
-            .when(!is_active && !self.disabled, |this| {
-                this.hover(|this| this.bg(cx.theme().list_hover))
-            })
-            // Mouse enter
-            .when_some(self.on_mouse_enter, |this, on_mouse_enter| {
-                if !self.disabled {
-                    this.on_mouse_move(move |ev, cx| (on_mouse_enter)(ev, cx))
-                } else {
-                    this
-                }
+        h_flex()
+            .w_full()
+            .min_w_0()
+            .gap_x_2()
+            .when_some(self.leading.take(), |this, leading| {
+                this.child(
+                    div()
+                        .flex_shrink_0()
+                        .when(self.two_line, |this| {
+                            this.flex().size_8().items_center().justify_center()
+                        })
+                        .child(leading),
+                )
             })
             .child(
-                h_flex()
-                    .w_full()
-                    .items_center()
-                    .justify_between()
-                    .gap_x_1()
-                    .child(div().w_full().children(self.children))
-                    .when_some(self.check_icon, |this, icon| {
+                v_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .when_some(self.title.take(), |this, title| {
+                        this.child(TruncatedLabel::new("title", title))
+                    })
+                    .when(self.subtitle.is_some() || self.two_line, |this| {
                         this.child(
-                            div().w_5().items_center().justify_center().when(
+                            div()
+                                .h_5()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .children(self.subtitle.take()),
+                        )
+                    }),
+            )
+            .children(children)
+            .into_any_element()
+    }
+}
+
+impl RenderOnce for ListItem {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        let content = self.render_content(cx);
+        let is_active = self.selected || self.confirmed;
+        let group_id = self
+            .group_id
+            .unwrap_or_else(|| SharedString::from("list-item"));
+
+        let item =
+            self.base
+                .group(group_id.clone())
+                .text_color(cx.theme().foreground)
+                .relative()
+                .items_center()
+                .justify_between()
+                .when_some(self.on_click, |this, on_click| {
+                    if !self.disabled {
+                        this.cursor_pointer().on_click(on_click)
+                    } else {
+                        this
+                    }
+                })
+                .when(is_active, |this| this.bg(cx.theme().list_active))
+                .when(!is_active && !self.disabled, |this| {
+                    this.hover(|this| this.bg(cx.theme().list_hover))
+                })
+                // Mouse enter
+                .when_some(self.on_mouse_enter, |this, on_mouse_enter| {
+                    if !self.disabled {
+                        this.on_mouse_move(move |ev, cx| (on_mouse_enter)(ev, cx))
+                    } else {
+                        this
+                    }
+                })
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_x_1()
+                        .child(content)
+                        .when_some(self.check_icon, |this, icon| {
+                            this.child(div().w_5().items_center().justify_center().when(
                                 self.confirmed,
                                 |this| {
                                     this.child(icon.small().text_color(cx.theme().muted_foreground))
                                 },
+                            ))
+                        }),
+                )
+                .when(!self.trailing_actions.is_empty(), |this| {
+                    this.child(
+                        h_flex()
+                            .flex_shrink_0()
+                            .gap_1()
+                            .when(!is_active, |this| {
+                                this.invisible()
+                                    .group_hover(group_id, |this| this.visible())
+                            })
+                            // Avoid to trigger the `on_click` of the item when click between the buttons.
+                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                            .children(
+                                self.trailing_actions
+                                    .into_iter()
+                                    .map(|button| button.ghost().xsmall()),
                             ),
-                        )
-                    }),
-            )
-            .when_some(self.suffix, |this, suffix| this.child(suffix(cx)))
+                    )
+                })
+                .when_some(self.suffix, |this, suffix| this.child(suffix(cx)))
+                .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                    this.focus_ring(focus_handle, cx)
+                });
+
+        match self.focus_handle {
+            Some(focus_handle) => item.track_focus(&focus_handle).into_any_element(),
+            None => item.into_any_element(),
+        }
     }
 }
@@ -1,5 +1,6 @@
 mod list;
 mod list_item;
+mod type_ahead;
 
 pub use list::*;
 pub use list_item::*;
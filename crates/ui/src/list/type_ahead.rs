@@ -0,0 +1,67 @@
+use std::time::{Duration, Instant};
+
+/// The typed characters will be reset after this timeout.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Accumulate the typed characters to jump to the item by its label, like the native menus.
+pub(super) struct TypeAhead {
+    query: String,
+    last_typed_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    pub(super) fn new() -> Self {
+        Self {
+            query: String::new(),
+            last_typed_at: None,
+        }
+    }
+
+    /// Append the typed text to the query, the query will be reset if the timeout is reached.
+    ///
+    /// Returns the accumulated query in lowercase.
+    pub(super) fn push(&mut self, text: &str, now: Instant) -> &str {
+        let expired = self
+            .last_typed_at
+            .map_or(true, |at| now.duration_since(at) > TYPE_AHEAD_TIMEOUT);
+        if expired {
+            self.query.clear();
+        }
+
+        self.query.push_str(&text.to_lowercase());
+        self.last_typed_at = Some(now);
+        &self.query
+    }
+}
+
+/// Return the index of the first label starts with the query, ignore the case.
+pub(super) fn find_match<'a>(
+    query: &str,
+    labels: impl IntoIterator<Item = Option<&'a str>>,
+) -> Option<usize> {
+    labels
+        .into_iter()
+        .position(|label| label.map_or(false, |label| label.to_lowercase().starts_with(query)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{find_match, TypeAhead};
+
+    #[test]
+    fn test_type_ahead() {
+        let now = Instant::now();
+        let mut type_ahead = TypeAhead::new();
+        assert_eq!(type_ahead.push("A", now), "a");
+        assert_eq!(type_ahead.push("p", now + Duration::from_millis(500)), "ap");
+        assert_eq!(type_ahead.push("b", now + Duration::from_millis(2000)), "b");
+
+        let labels = [Some("Banana"), None, Some("Apple"), Some("Apricot")];
+        assert_eq!(find_match("ap", labels), Some(2));
+        assert_eq!(find_match("apr", labels), Some(3));
+        assert_eq!(find_match("b", labels), Some(0));
+        assert_eq!(find_match("c", labels), None);
+    }
+}
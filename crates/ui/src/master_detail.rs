@@ -0,0 +1,315 @@
+//! A settings style layout, a searchable navigation list at the left and the detail of
+//! the selected item at the right.
+//!
+//! It collapses to a single column when the width is less than the breakpoint,
+//! the navigation list is shown first, and a back button returns to it from the detail.
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, prelude::FluentBuilder as _, px, AnyElement, AppContext, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, ParentElement, Pixels,
+    Render, SharedString, StatefulInteractiveElement as _, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    label::{match_positions, HighlightedLabel},
+    list::ListItem,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _,
+};
+
+/// The default width to collapse to a single column.
+const DEFAULT_BREAKPOINT: Pixels = px(600.);
+
+/// An item in the navigation list of the [`MasterDetail`].
+pub struct MasterDetailItem {
+    label: SharedString,
+    icon: Option<IconName>,
+    keywords: Vec<SharedString>,
+    detail: Rc<dyn Fn(&mut WindowContext) -> AnyElement>,
+}
+
+impl MasterDetailItem {
+    pub fn new<E: IntoElement>(
+        label: impl Into<SharedString>,
+        detail: impl Fn(&mut WindowContext) -> E + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            keywords: Vec::new(),
+            detail: Rc::new(move |cx| detail(cx).into_any_element()),
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// The extra words to match the search query, e.g.: the names of the settings in the detail.
+    pub fn keywords(mut self, keywords: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || !match_positions(&self.label, query).is_empty()
+            || self
+                .keywords
+                .iter()
+                .any(|keyword| !match_positions(keyword, query).is_empty())
+    }
+}
+
+pub enum MasterDetailEvent {
+    /// The item at the index is selected.
+    Select(usize),
+}
+
+pub struct MasterDetail {
+    focus_handle: FocusHandle,
+    items: Vec<MasterDetailItem>,
+    selected_ix: usize,
+    query_input: View<TextInput>,
+    query: SharedString,
+    nav_width: Pixels,
+    breakpoint: Pixels,
+    width: Pixels,
+    /// Whether to show the detail in the single column mode.
+    show_detail: bool,
+}
+
+impl MasterDetail {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let query_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .appearance(false)
+                .prefix(|_| IconName::Search)
+                .placeholder(t!("MasterDetail.Search"))
+                .cleanable()
+        });
+        cx.subscribe(&query_input, Self::on_query_input_event)
+            .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: Vec::new(),
+            selected_ix: 0,
+            query_input,
+            query: SharedString::default(),
+            nav_width: px(220.),
+            breakpoint: DEFAULT_BREAKPOINT,
+            width: px(0.),
+            show_detail: false,
+        }
+    }
+
+    pub fn item(mut self, item: MasterDetailItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Set the width of the navigation list, default is 220px.
+    pub fn nav_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.nav_width = width.into();
+        self
+    }
+
+    /// Set the width to collapse to a single column, default is 600px.
+    pub fn breakpoint(mut self, breakpoint: impl Into<Pixels>) -> Self {
+        self.breakpoint = breakpoint.into();
+        self
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_ix
+    }
+
+    pub fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.items.len() {
+            return;
+        }
+
+        self.selected_ix = ix;
+        self.show_detail = true;
+        cx.emit(MasterDetailEvent::Select(ix));
+        cx.notify();
+    }
+
+    fn is_single_column(&self) -> bool {
+        self.width < self.breakpoint
+    }
+
+    fn on_query_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let InputEvent::Change(text) = event {
+            self.query = text.trim().to_string().into();
+            cx.notify();
+        }
+    }
+
+    fn render_nav(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let query = self.query.clone();
+        let single_column = self.is_single_column();
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.matches(&query))
+            .map(|(ix, item)| {
+                ListItem::new(ix)
+                    .selected(!single_column && ix == self.selected_ix)
+                    .when_some(item.icon.clone(), |this, icon| {
+                        this.leading(Icon::new(icon).small())
+                    })
+                    .child(HighlightedLabel::with_query(item.label.clone(), &query))
+                    .on_click(cx.listener(move |this, _, cx| this.set_selected_index(ix, cx)))
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .size_full()
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(self.query_input.clone()),
+            )
+            .child(
+                v_flex()
+                    .id("master-detail-nav")
+                    .flex_1()
+                    .p_1()
+                    .overflow_y_scroll()
+                    .map(|this| {
+                        if items.is_empty() {
+                            this.child(
+                                h_flex()
+                                    .justify_center()
+                                    .py_4()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(t!("MasterDetail.No results").to_string()),
+                            )
+                        } else {
+                            this.children(items)
+                        }
+                    }),
+            )
+    }
+
+    fn render_detail(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let detail = self
+            .items
+            .get(self.selected_ix)
+            .map(|item| item.detail.clone());
+
+        v_flex()
+            .id("master-detail-detail")
+            .flex_1()
+            .min_w_0()
+            .size_full()
+            .overflow_y_scroll()
+            .when(self.is_single_column(), |this| {
+                this.child(
+                    h_flex().p_1().child(
+                        Button::new("back", cx)
+                            .ghost()
+                            .small()
+                            .icon(IconName::ArrowLeft)
+                            .label(t!("MasterDetail.Back"))
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.show_detail = false;
+                                cx.notify();
+                            })),
+                    ),
+                )
+            })
+            .when_some(detail, |this, detail| {
+                this.child(div().p_4().child(detail(cx)))
+            })
+    }
+}
+
+impl EventEmitter<MasterDetailEvent> for MasterDetail {}
+
+impl FocusableView for MasterDetail {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for MasterDetail {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let single_column = self.is_single_column();
+
+        h_flex()
+            .track_focus(&self.focus_handle)
+            .relative()
+            .size_full()
+            .items_start()
+            .map(|this| {
+                if !single_column {
+                    this.child(
+                        div()
+                            .flex_shrink_0()
+                            .h_full()
+                            .w(self.nav_width)
+                            .border_r_1()
+                            .border_color(cx.theme().border)
+                            .child(self.render_nav(cx)),
+                    )
+                    .child(self.render_detail(cx))
+                } else if self.show_detail {
+                    this.child(self.render_detail(cx))
+                } else {
+                    this.child(div().size_full().child(self.render_nav(cx)))
+                }
+            })
+            .child(
+                // Measure the width to switch between the single and the two columns.
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |this, cx| {
+                            if this.width != bounds.size.width {
+                                this.width = bounds.size.width;
+                                cx.notify();
+                            }
+                        })
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::div;
+
+    use super::MasterDetailItem;
+
+    #[test]
+    fn test_item_matches() {
+        let item = MasterDetailItem::new("Appearance", |_| div()).keywords(["Theme", "Font size"]);
+        assert!(item.matches(""));
+        assert!(item.matches("appear"));
+        assert!(item.matches("font"));
+        assert!(!item.matches("network"));
+    }
+}
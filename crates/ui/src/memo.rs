@@ -0,0 +1,156 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use gpui::{
+    AnyElement, AnyView, IntoElement, Render, StyleRefinement, View, ViewContext, WindowContext,
+};
+
+fn hash_key(key: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A view to cache the expensive static content, e.g.: rendered markdown or charts.
+///
+/// The content is only rebuilt when the key changed (see [`Memo::set_key`]) or
+/// [`Memo::invalidate`] is called, otherwise the last painted frame will be reused.
+///
+/// ```ignore
+/// let memo = cx.new_view(|_| Memo::new(&text, move |cx| render_markdown(&text, cx)));
+/// // in the parent render
+/// div().child(memo.memoized(StyleRefinement::default()))
+/// ```
+pub struct Memo {
+    key: u64,
+    builder: Rc<dyn Fn(&mut WindowContext) -> AnyElement>,
+}
+
+impl Memo {
+    pub fn new<E>(key: impl Hash, builder: impl Fn(&mut WindowContext) -> E + 'static) -> Self
+    where
+        E: IntoElement,
+    {
+        Self {
+            key: hash_key(key),
+            builder: Rc::new(move |cx| builder(cx).into_any_element()),
+        }
+    }
+
+    /// Set the invalidation key, the content will be rebuilt in the next frame if the key changed.
+    pub fn set_key(&mut self, key: impl Hash, cx: &mut ViewContext<Self>) {
+        let key = hash_key(key);
+        if self.key == key {
+            return;
+        }
+
+        self.key = key;
+        cx.notify();
+    }
+
+    /// Replace the builder and the key of the content.
+    pub fn set_builder<E>(
+        &mut self,
+        key: impl Hash,
+        builder: impl Fn(&mut WindowContext) -> E + 'static,
+        cx: &mut ViewContext<Self>,
+    ) where
+        E: IntoElement,
+    {
+        self.builder = Rc::new(move |cx| builder(cx).into_any_element());
+        self.key = hash_key(key);
+        cx.notify();
+    }
+
+    /// Force to rebuild the content in the next frame.
+    pub fn invalidate(&mut self, cx: &mut ViewContext<Self>) {
+        cx.notify();
+    }
+}
+
+impl Render for Memo {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let builder = self.builder.clone();
+        builder(cx)
+    }
+}
+
+pub trait MemoExt {
+    /// Render the [`Memo`] view as cached, the `style` is used for the layout of the cached content.
+    fn memoized(&self, style: StyleRefinement) -> AnyView;
+}
+
+impl MemoExt for View<Memo> {
+    fn memoized(&self, style: StyleRefinement) -> AnyView {
+        AnyView::from(self.clone()).cached(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::{
+        div, IntoElement, ParentElement as _, Render, StyleRefinement, TestAppContext, View,
+        ViewContext, VisualContext as _,
+    };
+
+    use super::{hash_key, Memo, MemoExt as _};
+
+    struct Parent {
+        memo: View<Memo>,
+    }
+
+    impl Render for Parent {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div().child(self.memo.memoized(StyleRefinement::default()))
+        }
+    }
+
+    #[test]
+    fn test_hash_key() {
+        assert_eq!(hash_key("foo"), hash_key("foo"));
+        assert_ne!(hash_key("foo"), hash_key("bar"));
+        assert_eq!(hash_key((1, "a")), hash_key((1, "a")));
+    }
+
+    #[gpui::test]
+    fn test_rebuild_only_when_key_changed(cx: &mut TestAppContext) {
+        let builds = Rc::new(Cell::new(0));
+        let (parent, cx) = cx.add_window_view(|cx| {
+            let builds = builds.clone();
+            let memo = cx.new_view(|_| {
+                Memo::new("a", move |_| {
+                    builds.set(builds.get() + 1);
+                    div()
+                })
+            });
+            Parent { memo }
+        });
+        cx.run_until_parked();
+        assert_eq!(builds.get(), 1);
+
+        // The parent is rendered again, the cached content is reused.
+        let memo = parent.update(cx, |parent, cx| {
+            cx.notify();
+            parent.memo.clone()
+        });
+        cx.run_until_parked();
+        assert_eq!(builds.get(), 1);
+
+        memo.update(cx, |memo, cx| memo.set_key("a", cx));
+        cx.run_until_parked();
+        assert_eq!(builds.get(), 1);
+
+        memo.update(cx, |memo, cx| memo.set_key("b", cx));
+        cx.run_until_parked();
+        assert_eq!(builds.get(), 2);
+
+        memo.update(cx, |memo, cx| memo.invalidate(cx));
+        cx.run_until_parked();
+        assert_eq!(builds.get(), 3);
+    }
+}
@@ -7,8 +7,10 @@ use gpui::{
 };
 
 use crate::{
-    animation::cubic_bezier, button::Button, theme::ActiveTheme as _, v_flex, ContextModal,
-    IconName, Sizable as _,
+    animation::{cubic_bezier, motion_duration},
+    button::Button,
+    theme::ActiveTheme as _,
+    v_flex, ContextModal, IconName, Sizable as _,
 };
 
 #[derive(IntoElement)]
@@ -183,7 +185,7 @@ impl RenderOnce for Modal {
                         .children(self.footer)
                         .with_animation(
                             "slide-down",
-                            Animation::new(Duration::from_secs_f64(0.25))
+                            Animation::new(motion_duration(Duration::from_secs_f64(0.25), cx))
                                 .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
                             move |this, delta| {
                                 let y_offset = px(0.) + delta * px(30.);
@@ -1,17 +1,38 @@
 use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
 
 use gpui::{
-    div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, DismissEvent, ElementId,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
-    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WindowContext,
+    div, prelude::FluentBuilder, px, rems, AnchorCorner, Animation, AnimationExt, AnyElement,
+    ClickEvent, DismissEvent, ElementId, EventEmitter, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement, Styled, View,
+    ViewContext, VisualContext, WindowContext,
 };
+use rust_i18n::t;
 use smol::Timer;
 
 use crate::{
-    animation::cubic_bezier, button::Button, h_flex, theme::ActiveTheme as _, v_flex, Icon,
-    IconName, Sizable as _, StyledExt,
+    animation::{cubic_bezier, motion_duration},
+    button::Button,
+    cancellation::DropGuard,
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _, StyledExt,
 };
 
+/// The default max lines of the message to show, before it is expanded.
+const DEFAULT_MAX_LINES: usize = 3;
+/// The approximate number of the characters in a line of the message, to check it is long.
+const CHARS_PER_LINE: usize = 48;
+/// The line height of the message in rems.
+const MESSAGE_LINE_HEIGHT: f32 = 1.25;
+/// The max number of the action buttons in a notification.
+const MAX_ACTIONS: usize = 2;
+/// The default duration to show the notification before it is auto hidden.
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+/// The interval to count down the duration of the notification, the countdown is paused on hover.
+const COUNTDOWN_TICK: Duration = Duration::from_millis(100);
+/// The default max number of the notifications to show, before the older ones are stacked.
+const DEFAULT_MAX_VISIBLE: usize = 10;
+
 pub enum NotificationType {
     Info,
     Success,
@@ -37,6 +58,12 @@ impl From<(TypeId, ElementId)> for NotificationId {
     }
 }
 
+/// A button in the notification, e.g.: "Undo" or "View".
+struct NotificationAction {
+    label: SharedString,
+    handler: Arc<dyn Fn(&ClickEvent, &mut WindowContext)>,
+}
+
 pub struct Notification {
     /// The id is used make the notification unique.
     /// Then you push a notification with the same id, the previous notification will be replaced.
@@ -46,10 +73,22 @@ pub struct Notification {
     type_: NotificationType,
     title: Option<SharedString>,
     message: SharedString,
+    /// The custom element to show under the message.
+    content: Option<Arc<dyn Fn(&mut WindowContext) -> AnyElement>>,
     icon: Option<Icon>,
     autohide: bool,
+    /// The duration to show the notification before it is auto hidden.
+    duration: Duration,
+    /// Whether the notification is hovered, to pause the autohide countdown.
+    hovered: bool,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    actions: Vec<NotificationAction>,
     closing: bool,
+    max_lines: usize,
+    /// Whether the long message is expanded to show in full.
+    expanded: bool,
+    /// Cancel the dismiss timer when the notification is released.
+    cancel: DropGuard,
 }
 
 impl From<SharedString> for Notification {
@@ -89,11 +128,18 @@ impl Notification {
             id: id.into(),
             title: None,
             message: message.into(),
+            content: None,
             type_: NotificationType::Info,
             icon: None,
             autohide: true,
+            duration: DEFAULT_DURATION,
+            hovered: false,
             on_click: None,
+            actions: Vec::new(),
             closing: false,
+            max_lines: DEFAULT_MAX_LINES,
+            expanded: false,
+            cancel: DropGuard::default(),
         }
     }
 
@@ -138,6 +184,20 @@ impl Notification {
         self
     }
 
+    /// Set the custom content to show under the message, e.g.: the links, the key-value rows or the images.
+    ///
+    /// The message is hidden if it is empty, the message is still used for the OS notification.
+    ///
+    /// ```ignore
+    /// Notification::new("")
+    ///     .title("Build finished")
+    ///     .content(|_| h_flex().gap_2().child("Duration").child("1m 23s").into_any_element())
+    /// ```
+    pub fn content(mut self, content: impl Fn(&mut WindowContext) -> AnyElement + 'static) -> Self {
+        self.content = Some(Arc::new(content));
+        self
+    }
+
     /// Set the icon of the notification.
     ///
     /// If icon is None, the notification will use the default icon of the type.
@@ -158,6 +218,35 @@ impl Notification {
         self
     }
 
+    /// Set the duration to show the notification before it is auto hidden, default is 5 seconds.
+    ///
+    /// The countdown is paused while the notification is hovered or its message is expanded.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the max lines of the message to show, default is 3.
+    ///
+    /// The longer message will be truncated with a "Show more" toggle to expand it in place.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Return true if the message is longer than the max lines.
+    fn is_long_message(&self) -> bool {
+        self.message.lines().count() > self.max_lines
+            || self.message.chars().count() > self.max_lines * CHARS_PER_LINE
+    }
+
+    fn toggle_expanded(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        // Avoid to trigger the `on_click` of the notification.
+        cx.stop_propagation();
+        self.expanded = !self.expanded;
+        cx.notify();
+    }
+
     /// Set the click callback of the notification.
     pub fn on_click(
         mut self,
@@ -167,13 +256,61 @@ impl Notification {
         self
     }
 
+    /// Add an action button to the notification, up to 2 actions, the more actions are ignored.
+    ///
+    /// The notification is dismissed when the action is clicked, the `on_click` of the notification is not called.
+    ///
+    /// ```ignore
+    /// Notification::new("File deleted").action("Undo", |_, cx| cx.dispatch_action(Box::new(Undo)));
+    /// ```
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        if self.actions.len() < MAX_ACTIONS {
+            self.actions.push(NotificationAction {
+                label: label.into(),
+                handler: Arc::new(handler),
+            });
+        }
+        self
+    }
+
+    fn render_actions(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .pt_1()
+            .children(self.actions.iter().enumerate().map(|(ix, action)| {
+                let handler = action.handler.clone();
+                Button::new(("action", ix), cx)
+                    .outline()
+                    .xsmall()
+                    .label(action.label.clone())
+                    .on_click(cx.listener(move |view, event, cx| {
+                        // Avoid to trigger the `on_click` of the notification.
+                        cx.stop_propagation();
+                        view.dismiss(event, cx);
+                        handler(event, cx);
+                    }))
+            }))
+    }
+
     fn dismiss(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.closing = true;
         cx.notify();
 
         // Dismiss the notification after 0.15s to show the animation.
+        let token = self.cancel.token();
         cx.spawn(|view, mut cx| async move {
-            Timer::after(Duration::from_secs_f32(0.15)).await;
+            if token
+                .run(Timer::after(Duration::from_secs_f32(0.15)))
+                .await
+                .is_none()
+            {
+                return Ok(());
+            }
+
             cx.update(|cx| {
                 if let Some(view) = view.upgrade() {
                     view.update(cx, |view, cx| {
@@ -191,6 +328,8 @@ impl FluentBuilder for Notification {}
 impl Render for Notification {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let closing = self.closing;
+        let is_long_message = self.is_long_message();
+        let collapsed = is_long_message && !self.expanded;
         let icon = match self.icon.clone() {
             Some(icon) => icon,
             None => match self.type_ {
@@ -211,6 +350,7 @@ impl Render for Notification {
             .id("notification")
             .group("")
             .occlude()
+            .on_hover(cx.listener(|view, hovered, _| view.hovered = *hovered))
             .relative()
             .w_96()
             .border_1()
@@ -230,7 +370,48 @@ impl Render for Notification {
                         this.child(div().text_sm().font_semibold().child(title))
                     })
                     .overflow_hidden()
-                    .child(div().text_sm().child(self.message.clone())),
+                    .when(!self.message.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .id("message")
+                                .text_sm()
+                                .line_height(rems(MESSAGE_LINE_HEIGHT))
+                                .map(|this| {
+                                    if collapsed {
+                                        this.max_h(rems(
+                                            MESSAGE_LINE_HEIGHT * self.max_lines as f32,
+                                        ))
+                                        .overflow_hidden()
+                                    } else {
+                                        // Scroll the very long message, e.g.: the stack traces.
+                                        this.max_h(rems(MESSAGE_LINE_HEIGHT * 20.))
+                                            .overflow_y_scroll()
+                                    }
+                                })
+                                .child(self.message.clone()),
+                        )
+                    })
+                    .when_some(self.content.clone(), |this, content| {
+                        this.child(div().text_sm().child(content(cx)))
+                    })
+                    .when(is_long_message, |this| {
+                        this.child(
+                            h_flex().child(
+                                Button::new("toggle-expanded", cx)
+                                    .link()
+                                    .xsmall()
+                                    .label(if self.expanded {
+                                        t!("Notification.Show less")
+                                    } else {
+                                        t!("Notification.Show more")
+                                    })
+                                    .on_click(cx.listener(Self::toggle_expanded)),
+                            ),
+                        )
+                    })
+                    .when(!self.actions.is_empty(), |this| {
+                        this.child(self.render_actions(cx))
+                    }),
             )
             .when_some(self.on_click.clone(), |this, on_click| {
                 this.cursor_pointer()
@@ -258,7 +439,7 @@ impl Render for Notification {
             })
             .with_animation(
                 ElementId::NamedInteger("slide-down".into(), closing as usize),
-                Animation::new(Duration::from_secs_f64(0.15))
+                Animation::new(motion_duration(Duration::from_secs_f64(0.15), cx))
                     .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
                 move |this, delta| {
                     if closing {
@@ -273,11 +454,81 @@ impl Render for Notification {
     }
 }
 
+/// The request to show a notification in the notification center of the OS.
+struct SystemNotification {
+    title: Option<SharedString>,
+    message: SharedString,
+    duration: Duration,
+    /// Sent when the notification is clicked.
+    clicked: smol::channel::Sender<()>,
+}
+
+/// The worker thread to show the system notifications one by one.
+///
+/// It waits for the click of a notification until it is closed, the notification is closed
+/// by the OS after its duration, so the next one is not blocked for long.
+static SYSTEM_NOTIFICATIONS: once_cell::sync::Lazy<smol::channel::Sender<SystemNotification>> =
+    once_cell::sync::Lazy::new(|| {
+        let (tx, rx) = smol::channel::unbounded::<SystemNotification>();
+        // The requests fail to send if the thread can't be spawned, they are dropped.
+        _ = std::thread::Builder::new()
+            .name("system-notifications".into())
+            .spawn(move || {
+                while let Ok(request) = rx.recv_blocking() {
+                    show_system_notification(request);
+                }
+            });
+        tx
+    });
+
+fn show_system_notification(request: SystemNotification) {
+    let mut notification = notify_rust::Notification::new();
+    match &request.title {
+        Some(title) => notification.summary(title).body(&request.message),
+        None => notification.summary(&request.message),
+    };
+    notification.timeout(notify_rust::Timeout::Milliseconds(
+        request.duration.as_millis().min(u32::MAX as u128) as u32,
+    ));
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        notification.action("default", "Open");
+        match notification.show() {
+            Ok(handle) => handle.wait_for_action(|action| {
+                if action == "default" {
+                    _ = request.clicked.send_blocking(());
+                }
+            }),
+            Err(err) => eprintln!("failed to show system notification: {:?}", err),
+        }
+    }
+
+    // The click of the notification can't be received on macOS and Windows,
+    // drop the sender to stop waiting for it.
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    {
+        drop(request.clicked);
+        if let Err(err) = notification.show() {
+            eprintln!("failed to show system notification: {:?}", err);
+        }
+    }
+}
+
 /// A list of notifications.
 pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<View<Notification>>,
+    /// Whether the list is hovered to show the stacked notifications.
     expanded: bool,
+    /// The corner of the window to show the notifications.
+    placement: AnchorCorner,
+    /// The max number of the notifications to show, the older ones are stacked.
+    max_visible: usize,
+    /// Mirror the notifications to the OS notification center, when the window is not active.
+    system_notifications: bool,
+    /// Cancel the autohide timers when the list is released.
+    cancel: DropGuard,
 }
 
 impl NotificationList {
@@ -285,43 +536,108 @@ impl NotificationList {
         Self {
             notifications: VecDeque::new(),
             expanded: false,
+            placement: AnchorCorner::TopRight,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            system_notifications: false,
+            cancel: DropGuard::default(),
         }
     }
 
+    /// Set to mirror the notifications to the OS notification center, default is false.
+    ///
+    /// When enabled, the notifications pushed while the window is unfocused (or minimized)
+    /// are also shown by the OS, clicking it will refocus the window and run the `on_click`.
+    ///
+    /// The click is only received on Linux and the BSDs, on macOS and Windows the notifications
+    /// are shown without running the `on_click`.
+    pub fn set_system_notifications(&mut self, enabled: bool) {
+        self.system_notifications = enabled;
+    }
+
+    /// Set the corner of the window to show the notifications, default is the top right.
+    pub fn set_placement(&mut self, placement: AnchorCorner, cx: &mut ViewContext<Self>) {
+        self.placement = placement;
+        cx.notify();
+    }
+
+    /// Set the max number of the notifications to show, default is 10.
+    ///
+    /// The older notifications are collapsed into a "+N more" stack, hover the list to show them all.
+    pub fn set_max_visible(&mut self, max_visible: usize, cx: &mut ViewContext<Self>) {
+        self.max_visible = max_visible.max(1);
+        cx.notify();
+    }
+
+    fn mirror_to_system(&self, notification: &Notification, cx: &mut ViewContext<Self>) {
+        let (tx, clicked) = smol::channel::bounded(1);
+        _ = SYSTEM_NOTIFICATIONS.send_blocking(SystemNotification {
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            duration: notification.duration,
+            clicked: tx,
+        });
+        let on_click = notification.on_click.clone();
+        let token = self.cancel.token();
+
+        cx.spawn(|_, mut cx| async move {
+            if let Some(Ok(_)) = token.run(clicked.recv()).await {
+                _ = cx.update(|cx| {
+                    cx.activate_window();
+                    if let Some(on_click) = on_click {
+                        on_click(&ClickEvent::default(), cx);
+                    }
+                });
+            }
+        })
+        .detach();
+    }
+
     pub fn push(&mut self, notification: impl Into<Notification>, cx: &mut ViewContext<Self>) {
         let notification = notification.into();
         let id = notification.id.clone();
         let autohide = notification.autohide;
 
+        if self.system_notifications && !cx.is_window_active() {
+            self.mirror_to_system(&notification, cx);
+        }
+
         // Remove the notification by id, for keep unique.
         self.notifications.retain(|note| note.read(cx).id != id);
 
+        let duration = notification.duration;
         let notification = cx.new_view(|_| notification);
-        cx.subscribe(&notification, move |view, _, _: &DismissEvent, cx| {
-            view.notifications.retain(|note| id != note.read(cx).id);
+        // Remove the dismissed view, not by the id, it may be replaced by a new one with the same id.
+        cx.subscribe(&notification, move |view, note, _: &DismissEvent, cx| {
+            view.notifications.retain(|item| *item != note);
+            cx.notify();
         })
         .detach();
 
-        self.notifications.push_back(notification);
         if autohide {
-            // Sleep for 5 seconds to autohide the notification
-            cx.spawn(|view, mut cx| async move {
-                Timer::after(Duration::from_secs(5)).await;
-                let _ = view.update(&mut cx, |view, cx| {
-                    if let Some(ix) = view
-                        .notifications
-                        .iter()
-                        .position(|note| note.read(cx).autohide)
-                    {
-                        if let Some(note) = view.notifications.get(ix) {
-                            note.update(cx, |note, cx| note.dismiss(&ClickEvent::default(), cx));
-                        }
+            let note = notification.downgrade();
+            let token = self.cancel.token();
+            cx.spawn(|_, mut cx| async move {
+                let mut remaining = duration;
+                while !remaining.is_zero() {
+                    if token.run(Timer::after(COUNTDOWN_TICK)).await.is_none() {
+                        return;
                     }
-                    cx.notify()
-                });
+
+                    // Pause when the user is reading it, stop when it is dismissed or replaced.
+                    let Ok(paused) = note.update(&mut cx, |note, _| note.hovered || note.expanded)
+                    else {
+                        return;
+                    };
+                    if !paused {
+                        remaining = remaining.saturating_sub(COUNTDOWN_TICK);
+                    }
+                }
+
+                _ = note.update(&mut cx, |note, cx| note.dismiss(&ClickEvent::default(), cx));
             })
             .detach();
         }
+        self.notifications.push_back(notification);
         cx.notify();
     }
 
@@ -335,30 +651,91 @@ impl NotificationList {
     }
 }
 
+impl NotificationList {
+    /// Render the stack of the older notifications that are not shown.
+    fn render_more(&self, count: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let card = || {
+            div()
+                .border_1()
+                .border_color(cx.theme().border)
+                .bg(cx.theme().popover)
+                .rounded_md()
+        };
+
+        div()
+            .relative()
+            .flex_none()
+            .w_96()
+            .h_9()
+            .child(card().absolute().top_1p5().left_3().right_3().h_full())
+            .child(
+                card()
+                    .relative()
+                    .h_full()
+                    .shadow_md()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t!("Notification.More", count = count).to_string()),
+            )
+    }
+}
+
 impl Render for NotificationList {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
         let size = cx.viewport_size();
-        let items = self.notifications.iter().rev().take(10).rev().cloned();
+        let hidden_count = if self.expanded {
+            0
+        } else {
+            self.notifications.len().saturating_sub(self.max_visible)
+        };
+        let items = self.notifications.iter().skip(hidden_count).cloned();
+        let is_left = matches!(
+            self.placement,
+            AnchorCorner::TopLeft | AnchorCorner::BottomLeft
+        );
+        let is_bottom = matches!(
+            self.placement,
+            AnchorCorner::BottomLeft | AnchorCorner::BottomRight
+        );
 
         div()
             .absolute()
             .flex()
             .top_4()
             .bottom_4()
-            .right_4()
-            .justify_end()
+            .map(|this| {
+                if is_left {
+                    this.left_4().justify_start()
+                } else {
+                    this.right_4().justify_end()
+                }
+            })
             .child(
                 v_flex()
                     .id("notification-list")
                     .absolute()
                     .relative()
-                    .right_0()
+                    .map(|this| {
+                        if is_left {
+                            this.left_0()
+                        } else {
+                            this.right_0()
+                        }
+                    })
                     .h(size.height - px(8.))
+                    .when(is_bottom, |this| this.justify_end())
+                    .when(self.expanded, |this| this.overflow_y_scroll())
                     .on_hover(cx.listener(|view, hovered, cx| {
                         view.expanded = *hovered;
                         cx.notify()
                     }))
                     .gap_3()
+                    .when(hidden_count > 0, |this| {
+                        this.child(self.render_more(hidden_count, cx))
+                    })
                     .children(items),
             )
     }
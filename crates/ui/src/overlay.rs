@@ -0,0 +1,71 @@
+//! The shared dismiss behavior of the overlays, e.g.: PopupMenu, Dropdown, DatePicker, ColorPicker.
+use std::rc::Rc;
+
+use gpui::{
+    canvas, DispatchPhase, InteractiveElement, KeyDownEvent, ParentElement, ScrollWheelEvent,
+    Styled, WindowContext,
+};
+
+/// The reason of the overlay is dismissed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DismissReason {
+    /// Mouse down outside of the overlay.
+    OutsideClick,
+    /// Pressed `escape`, even the overlay is not focused.
+    Escape,
+    /// The window is deactivated.
+    WindowBlur,
+    /// Scroll outside of the overlay, the overlay will be misplaced after scroll.
+    Scroll,
+}
+
+pub trait OverlayExt: InteractiveElement + ParentElement + Sized {
+    /// Dismiss the overlay when click outside, press `escape`, the window is deactivated
+    /// or scroll outside of it.
+    fn on_dismiss(
+        mut self,
+        handler: impl Fn(&DismissReason, &mut WindowContext) + 'static,
+    ) -> Self {
+        let handler = Rc::new(handler);
+
+        self.interactivity().on_mouse_down_out({
+            let handler = handler.clone();
+            move |_, cx| handler(&DismissReason::OutsideClick, cx)
+        });
+
+        self.child(
+            canvas(
+                |_, _| {},
+                move |bounds, _, cx| {
+                    if !cx.is_window_active() {
+                        let handler = handler.clone();
+                        cx.defer(move |cx| handler(&DismissReason::WindowBlur, cx));
+                        return;
+                    }
+
+                    cx.on_key_event({
+                        let handler = handler.clone();
+                        move |event: &KeyDownEvent, phase, cx| {
+                            if phase == DispatchPhase::Bubble && event.keystroke.key == "escape" {
+                                handler(&DismissReason::Escape, cx);
+                            }
+                        }
+                    });
+
+                    cx.on_mouse_event({
+                        let handler = handler.clone();
+                        move |event: &ScrollWheelEvent, phase, cx| {
+                            if phase == DispatchPhase::Bubble && !bounds.contains(&event.position) {
+                                handler(&DismissReason::Scroll, cx);
+                            }
+                        }
+                    });
+                },
+            )
+            .absolute()
+            .size_full(),
+        )
+    }
+}
+
+impl<E: InteractiveElement + ParentElement> OverlayExt for E {}
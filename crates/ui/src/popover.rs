@@ -1,22 +1,75 @@
 use gpui::{
-    actions, anchored, deferred, div, prelude::FluentBuilder as _, AnchorCorner, AnyElement,
-    AppContext, Bounds, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter, FocusHandle,
-    FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding,
-    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
-    Style, Styled, View, ViewContext, VisualContext, WindowContext,
+    actions, anchored, canvas, deferred, div, point, prelude::FluentBuilder as _, px, AnchorCorner,
+    AnyElement, AppContext, Bounds, DefiniteLength, DismissEvent, DispatchPhase, Element,
+    ElementId, EventEmitter, FocusHandle, FocusableView, GlobalElementId, Hitbox,
+    InteractiveElement as _, IntoElement, KeyBinding, LayoutId, Length, ManagedView, MouseButton,
+    MouseDownEvent, ParentElement, Path, Pixels, Point, Render, Size, Style, Styled, View,
+    ViewContext, VisualContext, WindowContext,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
-use std::{cell::RefCell, rc::Rc};
 
-use crate::{Selectable, StyledExt as _};
+use crate::{
+    position::{compute_position, Placement, PositionOptions},
+    theme::ActiveTheme as _,
+    Disableable, Selectable, StyledExt as _,
+};
 
 const CONTEXT: &str = "Popover";
-
+/// The gap between the trigger and the popover.
+const GAP: Pixels = px(6.);
+/// The height of the arrow, the width is double of it.
+const ARROW_SIZE: Pixels = px(6.);
 actions!(popover, [Escape]);
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([KeyBinding::new("escape", Escape, Some(CONTEXT))])
 }
 
+/// The width of a popup, e.g.: the menu of a [`crate::dropdown::Dropdown`], a [`crate::popup_menu::PopupMenu`]
+/// or the calendar of a [`crate::date_picker::DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PopupWidth {
+    /// Match the width of the trigger.
+    MatchTrigger,
+    /// The intrinsic width of the content, but not narrower than the width.
+    Min(Pixels),
+    /// The intrinsic width of the content.
+    Content,
+    /// A definite width.
+    Fixed(DefiniteLength),
+}
+
+impl PopupWidth {
+    /// Apply the width to the popup `element`, that is opened by the trigger of the `trigger_width`.
+    pub(crate) fn apply<E: Styled>(self, element: E, trigger_width: Pixels) -> E {
+        match self {
+            PopupWidth::MatchTrigger => element.w(trigger_width),
+            PopupWidth::Min(width) => element.min_w(width),
+            PopupWidth::Content => element,
+            PopupWidth::Fixed(width) => element.w(width),
+        }
+    }
+}
+
+impl From<Pixels> for PopupWidth {
+    fn from(width: Pixels) -> Self {
+        PopupWidth::Fixed(width.into())
+    }
+}
+
+/// The `Length::Auto` is to match the width of the trigger.
+impl From<Length> for PopupWidth {
+    fn from(width: Length) -> Self {
+        match width {
+            Length::Auto => PopupWidth::MatchTrigger,
+            Length::Definite(width) => PopupWidth::Fixed(width),
+        }
+    }
+}
+
 pub struct PopoverContent {
     focus_handle: FocusHandle,
     content: Rc<dyn Fn(&mut WindowContext) -> AnyElement>,
@@ -68,7 +121,11 @@ pub struct Popover<M: ManagedView> {
     trigger: Option<Box<dyn FnOnce(bool, &WindowContext) -> AnyElement + 'static>>,
     content: Option<Rc<dyn Fn(&mut WindowContext) -> View<M> + 'static>>,
     mouse_button: MouseButton,
+    placement: Option<Placement>,
+    arrow: bool,
     no_style: bool,
+    disabled: bool,
+    popup_width: PopupWidth,
 }
 
 impl<M> Popover<M>
@@ -83,7 +140,11 @@ where
             trigger: None,
             content: None,
             mouse_button: MouseButton::Left,
+            placement: None,
+            arrow: false,
             no_style: false,
+            disabled: false,
+            popup_width: PopupWidth::Content,
         }
     }
 
@@ -92,6 +153,26 @@ where
         self
     }
 
+    /// Set the side of the trigger to place the popover, instead of the `anchor` corner.
+    ///
+    /// The popover will be flipped to the opposite side if there is not enough space.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    /// Show an arrow that points to the trigger, it only works with the `placement`.
+    pub fn arrow(mut self) -> Self {
+        self.arrow = true;
+        self
+    }
+
+    /// Set the width of the popover, default is the intrinsic width of the content.
+    pub fn popup_width(mut self, width: impl Into<PopupWidth>) -> Self {
+        self.popup_width = width.into();
+        self
+    }
+
     /// Set the mouse button to trigger the popover, default is `MouseButton::Left`.
     pub fn mouse_button(mut self, mouse_button: MouseButton) -> Self {
         self.mouse_button = mouse_button;
@@ -152,6 +233,69 @@ where
         .corner(bounds)
     }
 
+    /// Render the arrow at the edge of the popover that faces the trigger.
+    ///
+    /// The `offset` is the center of the arrow from the start of the edge.
+    fn render_arrow(placement: Placement, offset: Pixels, cx: &WindowContext) -> impl IntoElement {
+        let bg = cx.theme().popover;
+        let border = cx.theme().border;
+        let (width, height) = match placement {
+            Placement::Top | Placement::Bottom => (ARROW_SIZE * 2., ARROW_SIZE),
+            Placement::Left | Placement::Right => (ARROW_SIZE, ARROW_SIZE * 2.),
+        };
+
+        canvas(
+            |_, _| {},
+            move |bounds, _, cx| {
+                // The points of the triangle, the tip is pointing to the trigger.
+                let triangle = |inset: Pixels| {
+                    let Bounds { origin, size } = bounds;
+                    let (x, y, w, h) = (origin.x, origin.y, size.width, size.height);
+                    match placement {
+                        Placement::Bottom => [
+                            point(x + inset, y + h),
+                            point(x + w / 2., y + inset),
+                            point(x + w - inset, y + h),
+                        ],
+                        Placement::Top => [
+                            point(x + inset, y),
+                            point(x + w / 2., y + h - inset),
+                            point(x + w - inset, y),
+                        ],
+                        Placement::Right => [
+                            point(x + w, y + inset),
+                            point(x + inset, y + h / 2.),
+                            point(x + w, y + h - inset),
+                        ],
+                        Placement::Left => [
+                            point(x, y + inset),
+                            point(x + w - inset, y + h / 2.),
+                            point(x, y + h - inset),
+                        ],
+                    }
+                };
+
+                for (inset, color) in [(px(0.), border), (px(1.), bg)] {
+                    let [start, tip, end] = triangle(inset);
+                    let mut path = Path::new(start);
+                    path.line_to(tip);
+                    path.line_to(end);
+                    path.line_to(start);
+                    cx.paint_path(path, color);
+                }
+            },
+        )
+        .absolute()
+        .w(width)
+        .h(height)
+        .map(|this| match placement {
+            Placement::Bottom => this.top(-ARROW_SIZE).left(offset - ARROW_SIZE),
+            Placement::Top => this.bottom(-ARROW_SIZE).left(offset - ARROW_SIZE),
+            Placement::Right => this.left(-ARROW_SIZE).top(offset - ARROW_SIZE),
+            Placement::Left => this.right(-ARROW_SIZE).top(offset - ARROW_SIZE),
+        })
+    }
+
     fn with_element_state<R>(
         &mut self,
         id: &GlobalElementId,
@@ -169,6 +313,17 @@ where
     }
 }
 
+impl<M> Disableable for Popover<M>
+where
+    M: ManagedView,
+{
+    /// Set the disabled state, the popover will not open when the trigger clicked.
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl<M> IntoElement for Popover<M>
 where
     M: ManagedView,
@@ -188,6 +343,8 @@ pub struct PopoverElementState<M> {
     content_view: Rc<RefCell<Option<View<M>>>>,
     /// Trigger bounds for positioning the popover.
     trigger_bounds: Option<Bounds<Pixels>>,
+    /// The size of the popover content in the last frame, for flip the placement.
+    content_size: Rc<Cell<Option<Size<Pixels>>>>,
 }
 
 impl<M> Default for PopoverElementState<M> {
@@ -199,6 +356,7 @@ impl<M> Default for PopoverElementState<M> {
             trigger_element: None,
             content_view: Rc::new(RefCell::new(None)),
             trigger_bounds: None,
+            content_size: Rc::new(Cell::new(None)),
         }
     }
 }
@@ -230,30 +388,85 @@ impl<M: ManagedView> Element for Popover<M> {
             if let Some(content_view) = element_state.content_view.borrow_mut().as_mut() {
                 is_open = true;
 
-                let mut anchored = anchored().snap_to_window().anchor(view.anchor);
-                if let Some(trigger_bounds) = element_state.trigger_bounds {
-                    anchored = anchored.position(view.resolved_corner(trigger_bounds));
+                let gap = if view.arrow { GAP + ARROW_SIZE } else { GAP };
+                let position = view.placement.zip(element_state.trigger_bounds).map(
+                    |(placement, trigger_bounds)| {
+                        let size = element_state.content_size.get().unwrap_or_default();
+                        let options = PositionOptions::new(placement)
+                            .offset(gap)
+                            .arrow_padding(ARROW_SIZE * 2.);
+                        compute_position(trigger_bounds, size, cx.viewport_size(), options)
+                    },
+                );
+
+                let mut anchored = anchored().snap_to_window();
+                match position {
+                    Some(position) => {
+                        anchored = anchored
+                            .position(position.origin)
+                            .anchor(AnchorCorner::TopLeft);
+                    }
+                    None => {
+                        anchored = anchored.anchor(view.anchor);
+                        if let Some(trigger_bounds) = element_state.trigger_bounds {
+                            anchored = anchored.position(view.resolved_corner(trigger_bounds));
+                        }
+                    }
                 }
 
                 let mut element = {
                     let content_view_mut = element_state.content_view.clone();
+                    let content_size = element_state.content_size.clone();
                     let anchor = view.anchor;
+                    let arrow = view.arrow;
                     let no_style = view.no_style;
+                    let popup_width = view.popup_width;
+                    let trigger_width =
+                        element_state.trigger_bounds.map(|bounds| bounds.size.width);
                     deferred(
                         anchored.child(
                             div()
                                 .size_full()
                                 .occlude()
+                                .when_some(trigger_width, |this, trigger_width| {
+                                    popup_width.apply(this, trigger_width)
+                                })
                                 .when(!no_style, |this| this.popover_style(cx))
-                                .map(|this| match anchor {
-                                    AnchorCorner::TopLeft | AnchorCorner::TopRight => {
-                                        this.top_1p5()
-                                    }
-                                    AnchorCorner::BottomLeft | AnchorCorner::BottomRight => {
-                                        this.bottom_1p5()
-                                    }
+                                .map(|this| match position {
+                                    Some(_) => this,
+                                    None => match anchor {
+                                        AnchorCorner::TopLeft | AnchorCorner::TopRight => {
+                                            this.top_1p5()
+                                        }
+                                        AnchorCorner::BottomLeft | AnchorCorner::BottomRight => {
+                                            this.bottom_1p5()
+                                        }
+                                    },
                                 })
                                 .child(content_view.clone())
+                                .when_some(position, |this, position| {
+                                    this.when(arrow && !no_style, |this| {
+                                        this.child(Self::render_arrow(
+                                            position.placement,
+                                            position.arrow,
+                                            cx,
+                                        ))
+                                    })
+                                    // Measure the content to compute the position in the next frame.
+                                    .child(
+                                        canvas(
+                                            move |bounds, cx| {
+                                                if content_size.get() != Some(bounds.size) {
+                                                    content_size.set(Some(bounds.size));
+                                                    cx.refresh();
+                                                }
+                                            },
+                                            |_, _, _| {},
+                                        )
+                                        .absolute()
+                                        .size_full(),
+                                    )
+                                })
                                 .when(!no_style, |this| {
                                     this.on_mouse_down_out(move |_, cx| {
                                         // Update the element_state.content_view to `None`,
@@ -344,6 +557,10 @@ impl<M: ManagedView> Element for Popover<M> {
                 return;
             }
 
+            if this.disabled {
+                return;
+            }
+
             // When mouse click down in the trigger bounds, open the popover.
             let Some(content_build) = this.content.take() else {
                 return;
@@ -10,8 +10,14 @@ use gpui::{anchored, canvas, rems, AnchorCorner, Bounds, FocusableView, WeakView
 
 use crate::StyledExt;
 use crate::{
-    button::Button, h_flex, list::ListItem, popover::Popover, theme::ActiveTheme, v_flex, Icon,
-    IconName, Selectable, Sizable as _,
+    button::Button,
+    h_flex,
+    list::ListItem,
+    overlay::OverlayExt as _,
+    popover::Popover,
+    position::{compute_position, Placement, PositionOptions},
+    theme::ActiveTheme,
+    v_flex, Disableable as _, Icon, IconName, Selectable, Sizable as _,
 };
 
 actions!(menu, [Confirm, Dismiss, SelectNext, SelectPrev]);
@@ -27,17 +33,27 @@ pub fn init(cx: &mut AppContext) {
 }
 
 pub trait PopupMenuExt: Selectable + IntoElement + 'static {
+    /// Return true if the trigger is disabled, then the popup menu will not open.
+    fn is_disabled(&self) -> bool {
+        false
+    }
+
     fn popup_menu(
         self,
         f: impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static,
     ) -> Popover<PopupMenu> {
         Popover::new("popup-menu")
             .no_style()
+            .disabled(self.is_disabled())
             .trigger(self)
             .content(move |cx| PopupMenu::build(cx, |menu, cx| f(menu, cx)))
     }
 }
-impl PopupMenuExt for Button {}
+impl PopupMenuExt for Button {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+}
 
 enum PopupMenuItem {
     Separator,
@@ -75,6 +91,8 @@ pub struct PopupMenu {
     max_width: Pixels,
     hovered_menu_ix: Option<usize>,
     bounds: Bounds<Pixels>,
+    /// The bounds of the hovered submenu item, to place its submenu beside it.
+    hovered_item_bounds: Bounds<Pixels>,
     _subscriptions: [gpui::Subscription; 1],
 }
 
@@ -99,6 +117,7 @@ impl PopupMenu {
                 has_icon: false,
                 hovered_menu_ix: None,
                 bounds: Bounds::default(),
+                hovered_item_bounds: Bounds::default(),
                 _subscriptions: [_on_blur_subscription],
             };
             cx.refresh();
@@ -383,8 +402,7 @@ impl Render for PopupMenu {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
         let view = cx.view().clone();
         let has_icon = self.menu_items.iter().any(|item| item.has_icon());
-        let max_width = self.max_width;
-        let bounds = self.bounds;
+        let hovered_item_bounds = self.hovered_item_bounds;
 
         v_flex()
             .key_context("PopupMenu")
@@ -393,7 +411,7 @@ impl Render for PopupMenu {
             .on_action(cx.listener(Self::select_prev))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::dismiss))
-            .on_mouse_down_out(cx.listener(|this, _, cx| this.dismiss(&Dismiss, cx)))
+            .on_dismiss(cx.listener(|this, _, cx| this.dismiss(&Dismiss, cx)))
             .max_h(self.max_width)
             .min_w(self.min_width)
             .p_1()
@@ -403,8 +421,16 @@ impl Render for PopupMenu {
             .text_color(cx.theme().popover_foreground)
             .relative()
             .child({
+                let view = view.clone();
                 canvas(
-                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    move |bounds, cx| {
+                        // The parent menu places this submenu by its size, in the next frame.
+                        let resized = view.read(cx).bounds.size != bounds.size;
+                        view.update(cx, |r, _| r.bounds = bounds);
+                        if resized {
+                            cx.refresh();
+                        }
+                    },
                     |_, _, _| {},
                 )
                 .absolute()
@@ -484,29 +510,35 @@ impl Render for PopupMenu {
                                                 .child(IconName::ChevronRight),
                                         ),
                                 )
-                                .when_some(self.hovered_menu_ix, |this, hovered_ix| {
-                                    let (anchor, left) =
-                                        if cx.bounds().size.width - bounds.origin.x < max_width {
-                                            (AnchorCorner::TopRight, -px(15.))
-                                        } else {
-                                            (AnchorCorner::TopLeft, bounds.size.width - px(10.))
-                                        };
-
-                                    let top = if bounds.origin.y + bounds.size.height
-                                        > cx.bounds().size.height
-                                    {
-                                        px(32.)
-                                    } else {
-                                        -px(10.)
-                                    };
-
-                                    if hovered_ix == ix {
-                                        this.child(anchored().anchor(anchor).child(
-                                            div().occlude().top(top).left(left).child(menu.clone()),
-                                        ))
-                                    } else {
-                                        this
-                                    }
+                                .when(self.hovered_menu_ix == Some(ix), |this| {
+                                    let view = view.clone();
+                                    let size = menu.read(cx).bounds.size;
+                                    let position = compute_position(
+                                        hovered_item_bounds,
+                                        size,
+                                        cx.viewport_size(),
+                                        PositionOptions::new(Placement::Right).offset(px(4.)),
+                                    );
+
+                                    this.child(
+                                        canvas(
+                                            move |bounds, cx| {
+                                                view.update(cx, |r, _| {
+                                                    r.hovered_item_bounds = bounds
+                                                })
+                                            },
+                                            |_, _, _| {},
+                                        )
+                                        .absolute()
+                                        .size_full(),
+                                    )
+                                    .child(
+                                        anchored()
+                                            .position(position.origin)
+                                            .anchor(AnchorCorner::TopLeft)
+                                            .snap_to_window()
+                                            .child(div().occlude().child(menu.clone())),
+                                    )
                                 }),
                         ),
                 }
@@ -0,0 +1,213 @@
+//! Compute the position of the floating elements, e.g.: Popover, Dropdown menu,
+//! to keep them in the window.
+use gpui::{point, px, Bounds, Pixels, Point, Size};
+
+/// The min space between the floating element and the window edges.
+const VIEWPORT_PADDING: Pixels = px(4.);
+
+/// The side of the trigger to place the floating element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    pub fn flip(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+
+    /// Return true if the element with the `size` fits in the `viewport` at this side of the `trigger`.
+    fn fits(
+        self,
+        trigger: Bounds<Pixels>,
+        size: Size<Pixels>,
+        viewport: Size<Pixels>,
+        offset: Pixels,
+    ) -> bool {
+        match self {
+            Self::Top => trigger.top() - offset - size.height >= VIEWPORT_PADDING,
+            Self::Bottom => {
+                trigger.bottom() + offset + size.height <= viewport.height - VIEWPORT_PADDING
+            }
+            Self::Left => trigger.left() - offset - size.width >= VIEWPORT_PADDING,
+            Self::Right => {
+                trigger.right() + offset + size.width <= viewport.width - VIEWPORT_PADDING
+            }
+        }
+    }
+}
+
+/// The options to compute the position, default to flip and shift to keep in the window.
+#[derive(Debug, Clone, Copy)]
+pub struct PositionOptions {
+    placement: Placement,
+    offset: Pixels,
+    flip: bool,
+    shift: bool,
+    arrow_padding: Pixels,
+}
+
+impl PositionOptions {
+    pub fn new(placement: Placement) -> Self {
+        Self {
+            placement,
+            offset: px(0.),
+            flip: true,
+            shift: true,
+            arrow_padding: px(0.),
+        }
+    }
+
+    /// Set the gap between the trigger and the element.
+    pub fn offset(mut self, offset: impl Into<Pixels>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Set true to flip to the opposite side when there is not enough space, default is true.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Set true to shift along the trigger edge to keep in the window, default is true.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Set the min space between the arrow and the corners of the element.
+    pub fn arrow_padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.arrow_padding = padding.into();
+        self
+    }
+}
+
+/// The computed position of the floating element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// The top left of the element in the window.
+    pub origin: Point<Pixels>,
+    /// The placement after flipped.
+    pub placement: Placement,
+    /// The center of the arrow along the edge that faces the trigger, from the start of the edge.
+    pub arrow: Pixels,
+}
+
+/// Compute the position of the element with the `size` that is placed at the `trigger` in the `viewport`.
+pub fn compute_position(
+    trigger: Bounds<Pixels>,
+    size: Size<Pixels>,
+    viewport: Size<Pixels>,
+    options: PositionOptions,
+) -> Position {
+    let offset = options.offset;
+    let mut placement = options.placement;
+    if options.flip
+        && !placement.fits(trigger, size, viewport, offset)
+        && placement.flip().fits(trigger, size, viewport, offset)
+    {
+        placement = placement.flip();
+    }
+
+    let mut origin = match placement {
+        Placement::Top => point(trigger.left(), trigger.top() - offset - size.height),
+        Placement::Bottom => point(trigger.left(), trigger.bottom() + offset),
+        Placement::Left => point(trigger.left() - offset - size.width, trigger.top()),
+        Placement::Right => point(trigger.right() + offset, trigger.top()),
+    };
+
+    if options.shift {
+        if placement.is_vertical() {
+            origin.x = clamp(
+                origin.x,
+                VIEWPORT_PADDING,
+                viewport.width - size.width - VIEWPORT_PADDING,
+            );
+        } else {
+            origin.y = clamp(
+                origin.y,
+                VIEWPORT_PADDING,
+                viewport.height - size.height - VIEWPORT_PADDING,
+            );
+        }
+    }
+
+    // Point the arrow to the center of the trigger.
+    let (arrow, edge) = if placement.is_vertical() {
+        (trigger.center().x - origin.x, size.width)
+    } else {
+        (trigger.center().y - origin.y, size.height)
+    };
+    let arrow = clamp(arrow, options.arrow_padding, edge - options.arrow_padding);
+
+    Position {
+        origin,
+        placement,
+        arrow,
+    }
+}
+
+/// Clamp the value, the `min` wins if the `max` is less than it.
+fn clamp(value: Pixels, min: Pixels, max: Pixels) -> Pixels {
+    let value = if value > max { max } else { value };
+    if value < min {
+        min
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::{compute_position, Placement, PositionOptions};
+
+    #[test]
+    fn test_compute_position() {
+        let viewport = size(px(800.), px(600.));
+        let popover = size(px(200.), px(100.));
+        let options = PositionOptions::new(Placement::Bottom)
+            .offset(px(6.))
+            .arrow_padding(px(12.));
+
+        let trigger = Bounds::new(point(px(100.), px(100.)), size(px(80.), px(20.)));
+        let position = compute_position(trigger, popover, viewport, options);
+        assert_eq!(position.placement, Placement::Bottom);
+        assert_eq!(position.origin, point(px(100.), px(126.)));
+        assert_eq!(position.arrow, px(40.));
+
+        // Flip to the top at the bottom of the window.
+        let trigger = Bounds::new(point(px(100.), px(540.)), size(px(80.), px(20.)));
+        let position = compute_position(trigger, popover, viewport, options);
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!(position.origin, point(px(100.), px(434.)));
+
+        // Not flip if there is not enough space at the both sides.
+        let position = compute_position(trigger, size(px(200.), px(580.)), viewport, options);
+        assert_eq!(position.placement, Placement::Bottom);
+
+        // Shift to the left at the right of the window, the arrow still points to the trigger.
+        let trigger = Bounds::new(point(px(700.), px(100.)), size(px(80.), px(20.)));
+        let position = compute_position(trigger, popover, viewport, options);
+        assert_eq!(position.origin, point(px(596.), px(126.)));
+        assert_eq!(position.arrow, px(144.));
+
+        let position = compute_position(trigger, popover, viewport, options.shift(false));
+        assert_eq!(position.origin, point(px(700.), px(126.)));
+        assert_eq!(position.arrow, px(40.));
+    }
+}
@@ -1,13 +1,15 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder, relative, svg, CursorStyle, ElementId, InteractiveElement,
-    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
-    WindowContext,
+    div, prelude::FluentBuilder, relative, svg, CursorStyle, ElementId, FocusHandle,
+    InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, WindowContext,
 };
 
 use crate::{
     h_flex,
     theme::{ActiveTheme, Colorize},
-    IconName,
+    v_flex, Disableable, FocusCycle, IconName, StyledExt as _,
 };
 
 #[derive(IntoElement)]
@@ -16,6 +18,7 @@ pub struct Radio {
     label: Option<SharedString>,
     checked: bool,
     disabled: bool,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
@@ -26,6 +29,7 @@ impl Radio {
             label: None,
             checked: false,
             disabled: false,
+            focus_handle: None,
             on_click: None,
         }
     }
@@ -40,8 +44,9 @@ impl Radio {
         self
     }
 
-    pub fn disabled(mut self, disabled: bool) -> Self {
-        self.disabled = disabled;
+    /// Set the focus handle to track the focus, and show the focus ring when it focused by keyboard.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
         self
     }
 
@@ -51,6 +56,13 @@ impl Radio {
     }
 }
 
+impl Disableable for Radio {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl RenderOnce for Radio {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let color = if self.disabled {
@@ -59,7 +71,7 @@ impl RenderOnce for Radio {
             cx.theme().primary
         };
 
-        h_flex()
+        let radio = h_flex()
             .id(self.id)
             .gap_x_2()
             .cursor(CursorStyle::PointingHand)
@@ -74,6 +86,9 @@ impl RenderOnce for Radio {
                     .border_1()
                     .border_color(color)
                     .when(self.checked, |this| this.bg(color))
+                    .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                        this.focus_ring(focus_handle, cx)
+                    })
                     .child(
                         svg()
                             .absolute()
@@ -106,6 +121,135 @@ impl RenderOnce for Radio {
                         on_click(&!self.checked, cx);
                     })
                 },
-            )
+            );
+
+        match self.focus_handle {
+            Some(focus_handle) => radio.track_focus(&focus_handle).into_any_element(),
+            None => radio.into_any_element(),
+        }
+    }
+}
+
+/// A group of [`Radio`], only one of them can be checked.
+///
+/// Use the [`FocusCycle`] to move the focus between the radios by arrow keys, the focused radio is selected.
+/// The group is entered by the Tab key at the checked radio.
+#[derive(IntoElement)]
+pub struct RadioGroup {
+    id: ElementId,
+    radios: Vec<Radio>,
+    vertical: bool,
+    selected_index: Option<usize>,
+    focus_cycle: Option<FocusCycle>,
+    on_change: Option<Rc<dyn Fn(&usize, &mut WindowContext) + 'static>>,
+}
+
+impl RadioGroup {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            radios: vec![],
+            vertical: false,
+            selected_index: None,
+            focus_cycle: None,
+            on_change: None,
+        }
+    }
+
+    /// Add a radio to the group, the checked and on_click of the radio will be managed by the group.
+    pub fn child(mut self, radio: Radio) -> Self {
+        self.radios.push(radio);
+        self
+    }
+
+    /// Add radios to the group.
+    pub fn children(mut self, radios: impl IntoIterator<Item = Radio>) -> Self {
+        self.radios.extend(radios);
+        self
+    }
+
+    /// Layout the radios vertically, default is horizontal.
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+
+    pub fn selected_index(mut self, ix: Option<usize>) -> Self {
+        self.selected_index = ix;
+        self
+    }
+
+    /// Set the FocusCycle for the radios, the length of the cycle should match the radios.
+    pub fn focus_cycle(mut self, focus_cycle: &FocusCycle) -> Self {
+        self.focus_cycle = Some(focus_cycle.clone());
+        self
+    }
+
+    /// Set the handler when the selected radio changed.
+    pub fn on_change(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for RadioGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let on_change = self.on_change;
+        let selected_index = self.selected_index;
+        let focus_cycle = self.focus_cycle;
+        let disabled = self
+            .radios
+            .iter()
+            .map(|radio| radio.disabled)
+            .collect::<Vec<_>>();
+
+        // Keep the active radio of the cycle at the focused one, or at the checked one to enter the group.
+        if let Some(cycle) = focus_cycle.as_ref() {
+            if let Some(ix) = cycle.focused_index(cx).or(selected_index) {
+                cycle.set_active_index(ix);
+            }
+        }
+
+        let radios = self.radios.into_iter().enumerate().map(|(ix, radio)| {
+            let cycle = focus_cycle.clone().filter(|cycle| ix < cycle.len());
+            let on_change = on_change.clone();
+
+            radio
+                .checked(selected_index == Some(ix))
+                .when_some(cycle.as_ref(), |this, cycle| {
+                    this.focus_handle(cycle.handle(ix))
+                })
+                .when(cycle.is_some() || on_change.is_some(), |this| {
+                    this.on_click(move |_, cx| {
+                        if let Some(cycle) = cycle.as_ref() {
+                            cycle.focus(ix, cx);
+                        }
+                        if let Some(on_change) = on_change.as_ref() {
+                            on_change(&ix, cx);
+                        }
+                    })
+                })
+        });
+
+        let group = if self.vertical {
+            v_flex().gap_3()
+        } else {
+            h_flex().gap_x_4().flex_wrap()
+        }
+        .id(self.id)
+        .children(radios);
+
+        match focus_cycle {
+            Some(focus_cycle) => focus_cycle
+                .bind_with(group, move |ix, cx| {
+                    if disabled.get(ix) == Some(&false) {
+                        if let Some(on_change) = on_change.as_ref() {
+                            on_change(&ix, cx);
+                        }
+                    }
+                })
+                .into_any_element(),
+            None => group.into_any_element(),
+        }
     }
 }
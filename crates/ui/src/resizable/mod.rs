@@ -1,8 +1,12 @@
-use gpui::{Axis, ViewContext};
+use gpui::{AppContext, Axis, ViewContext};
 
 mod panel;
 pub use panel::*;
 
+pub fn init(cx: &mut AppContext) {
+    panel::init(cx);
+}
+
 pub fn h_resizable(cx: &mut ViewContext<ResizablePanelGroup>) -> ResizablePanelGroup {
     ResizablePanelGroup::new(cx).axis(Axis::Horizontal)
 }
@@ -1,29 +1,103 @@
-use std::rc::Rc;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use gpui::{
-    canvas, div, prelude::FluentBuilder, px, Along, AnyElement, AnyView, Axis, Bounds, Element,
-    EntityId, InteractiveElement as _, IntoElement, MouseMoveEvent, MouseUpEvent, ParentElement,
-    Pixels, Render, StatefulInteractiveElement, Style, Styled, View, ViewContext,
+    actions, canvas, div, prelude::FluentBuilder, px, Along, AnyElement, AnyView, AppContext, Axis,
+    Bounds, ClickEvent, Element, EntityId, EventEmitter, FocusHandle, Hsla,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyContext, MouseMoveEvent, MouseUpEvent,
+    ParentElement, Pixels, Render, StatefulInteractiveElement, Style, Styled, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme, v_flex, AxisExt};
+use crate::{
+    animation::{cubic_bezier, motion_duration},
+    button::Button,
+    focusable::is_focus_visible,
+    h_flex,
+    theme::ActiveTheme,
+    v_flex, AxisExt, IconName, Sizable as _,
+};
 
 const PANEL_MIN_SIZE: Pixels = px(100.);
 const HANDLE_PADDING: Pixels = px(4.);
+/// The larger padding of the handle to hit by touch or pen, see `Theme::touch_mode`.
+const TOUCH_HANDLE_PADDING: Pixels = px(12.);
+const GRIP_DOT_SIZE: Pixels = px(3.);
+/// The step to move the focused handle by the arrow keys, and the larger step with the shift key.
+const KEYBOARD_STEP: Pixels = px(10.);
+const KEYBOARD_LARGE_STEP: Pixels = px(50.);
+const HANDLE_CONTEXT: &str = "ResizeHandle";
+/// The size of the strip of a collapsed panel, to show the expand button.
+const COLLAPSED_SIZE: Pixels = px(24.);
+/// The duration of the panels to animate into the equalized sizes.
+const EQUALIZE_DURATION: Duration = Duration::from_millis(200);
+/// The interval of the frames of the equalize animation.
+const EQUALIZE_FRAME: Duration = Duration::from_millis(16);
+
+actions!(
+    resizable,
+    [
+        ShrinkPanel,
+        GrowPanel,
+        ShrinkPanelMore,
+        GrowPanelMore,
+        FocusResizeHandle
+    ]
+);
+
+pub(super) fn init(cx: &mut AppContext) {
+    let horizontal = Some("ResizeHandle && axis == horizontal");
+    let vertical = Some("ResizeHandle && axis == vertical");
+    cx.bind_keys([
+        KeyBinding::new("left", ShrinkPanel, horizontal),
+        KeyBinding::new("right", GrowPanel, horizontal),
+        KeyBinding::new("shift-left", ShrinkPanelMore, horizontal),
+        KeyBinding::new("shift-right", GrowPanelMore, horizontal),
+        KeyBinding::new("up", ShrinkPanel, vertical),
+        KeyBinding::new("down", GrowPanel, vertical),
+        KeyBinding::new("shift-up", ShrinkPanelMore, vertical),
+        KeyBinding::new("shift-down", GrowPanelMore, vertical),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-r", FocusResizeHandle, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-r", FocusResizeHandle, None),
+    ]);
+}
+
+/// The sizes of the child panels of a group, the group publishes them once per frame,
+/// and the panels read their sizes in their layout, so a resize doesn't update every panel.
+type SharedSizes = Rc<RefCell<Vec<Pixels>>>;
 
 #[derive(Clone, Render)]
 pub struct DragPanel(pub (EntityId, usize, Axis));
 
+pub enum ResizablePanelEvent {
+    /// The sizes of the panels are changed by the user, emitted at the end of the resizing.
+    SizesChanged(Vec<Pixels>),
+}
+
 #[derive(Clone)]
 pub struct ResizablePanelGroup {
     panels: Vec<View<ResizablePanel>>,
     sizes: Vec<Pixels>,
+    /// The `sizes` published to the panels when render.
+    shared_sizes: SharedSizes,
     axis: Axis,
-    handle_size: Pixels,
+    /// The size of the resize handle, `None` to use the theme `resize_handle_size`.
+    handle_size: Option<Pixels>,
     size: Option<Pixels>,
     bounds: Bounds<Pixels>,
     resizing_panel_ix: Option<usize>,
+    /// The latest `(ix, size)` dragged by the mouse, it is applied once per frame.
+    pending_resize: Option<(usize, Pixels)>,
+    /// The focus handles of the resize handles, to move them by the arrow keys.
+    handle_focus_handles: Vec<FocusHandle>,
+    on_resize: Option<Rc<dyn Fn(&[Pixels], &mut WindowContext)>>,
+    /// Increased to cancel the running equalize animation, e.g.: by a resize.
+    equalize_epoch: usize,
 }
 
 impl ResizablePanelGroup {
@@ -31,14 +105,99 @@ impl ResizablePanelGroup {
         Self {
             axis: Axis::Horizontal,
             sizes: Vec::new(),
+            shared_sizes: SharedSizes::default(),
             panels: Vec::new(),
-            handle_size: px(1.),
+            handle_size: None,
             size: None,
             bounds: Bounds::default(),
             resizing_panel_ix: None,
+            pending_resize: None,
+            handle_focus_handles: Vec::new(),
+            on_resize: None,
+            equalize_epoch: 0,
         }
     }
 
+    /// Return the sizes of the child panels.
+    pub(crate) fn sizes(&self) -> &[Pixels] {
+        &self.sizes
+    }
+
+    /// Return the bounds of the group, it is updated when render.
+    pub(crate) fn bounds(&self) -> Bounds<Pixels> {
+        self.bounds
+    }
+
+    /// Return the rendered size of the child panel at `ix`.
+    pub(crate) fn child_size(&self, ix: usize, cx: &AppContext) -> Option<Pixels> {
+        self.panels.get(ix).map(|panel| panel.read(cx).size)
+    }
+
+    /// Set the size of the child panel at `ix`.
+    pub(crate) fn set_child_size(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
+        if ix >= self.panels.len() {
+            return;
+        }
+
+        self.sizes[ix] = size;
+        cx.notify();
+    }
+
+    /// Resize the child panels into the equal sizes of the group, animated from the current sizes.
+    pub(crate) fn equalize_sizes(&mut self, cx: &mut ViewContext<Self>) {
+        if self.panels.is_empty() {
+            return;
+        }
+
+        self.sync_real_panel_sizes(cx);
+        let from = self.sizes.clone();
+        let to = equal_sizes(self.bounds.size.along(self.axis), self.panels.len())
+            .into_iter()
+            .zip(self.size_limits(cx))
+            .map(|(size, (min, max))| size.clamp(min, max))
+            .collect::<Vec<_>>();
+        if from == to {
+            return;
+        }
+
+        self.equalize_epoch += 1;
+        let epoch = self.equalize_epoch;
+        let duration = motion_duration(EQUALIZE_DURATION, cx);
+        let easing = cubic_bezier(0.4, 0., 0.2, 1.);
+
+        cx.spawn(|view, mut cx| async move {
+            let start = Instant::now();
+            loop {
+                let delta = (start.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.);
+                let t = easing(delta);
+                let sizes = from
+                    .iter()
+                    .zip(&to)
+                    .map(|(from, to)| *from + (*to - *from) * t)
+                    .collect::<Vec<_>>();
+
+                let canceled = view
+                    .update(&mut cx, |view, cx| {
+                        if view.equalize_epoch != epoch || view.panels.len() != sizes.len() {
+                            return true;
+                        }
+
+                        for (ix, size) in sizes.into_iter().enumerate() {
+                            view.set_child_size(ix, size, cx);
+                        }
+                        false
+                    })
+                    .unwrap_or(true);
+                if canceled || delta >= 1. {
+                    break;
+                }
+
+                cx.background_executor().timer(EQUALIZE_FRAME).await;
+            }
+        })
+        .detach();
+    }
+
     pub fn load(&mut self, sizes: Vec<Pixels>, panels: Vec<View<ResizablePanel>>) {
         self.sizes = sizes;
         self.panels = panels;
@@ -55,11 +214,20 @@ impl ResizablePanelGroup {
         cx.notify();
     }
 
-    /// Set the size of the resize handle, default is 3px.
+    /// Set the size of the resize handle, default is the `resize_handle_size` of the theme.
     ///
     /// The handle size will inherit the parent group handle size, if you insert a group into another group.
     pub fn handle_size(mut self, size: Pixels) -> Self {
-        self.handle_size = size;
+        self.handle_size = Some(size);
+        self
+    }
+
+    /// Set the callback when the sizes of the panels are changed by the user, e.g.: to persist them.
+    ///
+    /// It is called at the end of the drag of a handle, the arrow keys on a focused handle,
+    /// and the collapse of a panel, along with the [`ResizablePanelEvent::SizesChanged`] event.
+    pub fn on_resize(mut self, handler: impl Fn(&[Pixels], &mut WindowContext) + 'static) -> Self {
+        self.on_resize = Some(Rc::new(handler));
         self
     }
 
@@ -142,11 +310,53 @@ impl ResizablePanelGroup {
 
     fn render_resize_handle(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let axis = self.axis;
-        let neg_offset = -HANDLE_PADDING + px(1.);
+        let handle_size = self
+            .handle_size
+            .unwrap_or_else(|| px(cx.theme().resize_handle_size));
+        let padding = if cx.theme().touch_mode {
+            TOUCH_HANDLE_PADDING
+        } else {
+            HANDLE_PADDING
+        };
+        let neg_offset = -padding + handle_size;
         let view = cx.view().clone();
+        let focus_handle = self.handle_focus_handles[ix].clone();
+        let is_resizing = self.resizing_panel_ix == Some(ix) || is_focus_visible(&focus_handle, cx);
+        let color = if is_resizing {
+            cx.theme().resize_handle_hover
+        } else {
+            cx.theme().resize_handle
+        };
+        let hover_color = cx.theme().resize_handle_hover;
+
+        let mut context = KeyContext::default();
+        context.add(HANDLE_CONTEXT);
+        context.set(
+            "axis",
+            if axis.is_horizontal() {
+                "horizontal"
+            } else {
+                "vertical"
+            },
+        );
 
         div()
             .id(("resizable-handle", ix))
+            .key_context(context)
+            .track_focus(&focus_handle)
+            .on_action(cx.listener(move |view, _: &ShrinkPanel, cx| {
+                view.resize_by_keyboard(ix, -KEYBOARD_STEP, cx)
+            }))
+            .on_action(cx.listener(move |view, _: &GrowPanel, cx| {
+                view.resize_by_keyboard(ix, KEYBOARD_STEP, cx)
+            }))
+            .on_action(cx.listener(move |view, _: &ShrinkPanelMore, cx| {
+                view.resize_by_keyboard(ix, -KEYBOARD_LARGE_STEP, cx)
+            }))
+            .on_action(cx.listener(move |view, _: &GrowPanelMore, cx| {
+                view.resize_by_keyboard(ix, KEYBOARD_LARGE_STEP, cx)
+            }))
+            .group("resizable-handle")
             .occlude()
             .absolute()
             .flex_shrink_0()
@@ -155,27 +365,37 @@ impl ResizablePanelGroup {
                     .top_0()
                     .right(neg_offset)
                     .h_full()
-                    .w(px(1.))
-                    .px(HANDLE_PADDING)
+                    .w(handle_size)
+                    .px(padding)
             })
             .when(self.axis.is_vertical(), |this| {
                 this.cursor_row_resize()
                     .bottom(neg_offset)
                     .left_0()
                     .w_full()
-                    .h(px(1.))
-                    .py(HANDLE_PADDING)
+                    .h(handle_size)
+                    .py(padding)
             })
             .child(
                 div()
-                    .bg(cx.theme().border)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(color)
+                    .group_hover("resizable-handle", |this| this.bg(hover_color))
                     .when(self.axis.is_horizontal(), |this| {
-                        this.h_full().w(self.handle_size)
+                        this.h_full().w(handle_size)
                     })
-                    .when(self.axis.is_vertical(), |this| {
-                        this.w_full().h(self.handle_size)
+                    .when(self.axis.is_vertical(), |this| this.w_full().h(handle_size))
+                    .when(cx.theme().resize_handle_grip, |this| {
+                        this.child(Self::render_grip(axis, color, hover_color))
                     }),
             )
+            .on_click(cx.listener(move |view, event: &ClickEvent, cx| {
+                if event.up.click_count == 2 {
+                    view.toggle_collapse_at_handle(ix, cx);
+                }
+            }))
             .on_drag(
                 DragPanel((cx.entity_id(), ix, axis)),
                 move |drag_panel, cx| {
@@ -189,75 +409,245 @@ impl ResizablePanelGroup {
             )
     }
 
+    /// The button in the strip of the collapsed panel at `ix`, to expand it.
+    fn render_expand_button(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_last = ix == self.panels.len() - 1;
+        let icon = match (self.axis, is_last) {
+            (Axis::Horizontal, false) => IconName::ChevronRight,
+            (Axis::Horizontal, true) => IconName::ChevronLeft,
+            (Axis::Vertical, false) => IconName::ChevronDown,
+            (Axis::Vertical, true) => IconName::ChevronUp,
+        };
+
+        Button::new(("resizable-expand", ix), cx)
+            .icon(icon)
+            .ghost()
+            .xsmall()
+            .on_click(cx.listener(move |view, _, cx| view.toggle_collapse(ix, cx)))
+    }
+
+    /// The grip dots in the middle of the handle, to make the split discoverable.
+    fn render_grip(axis: Axis, color: Hsla, hover_color: Hsla) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_shrink_0()
+            .gap(px(2.))
+            .when(axis.is_horizontal(), |this| this.flex_col())
+            .children((0..3).map(|_| {
+                div()
+                    .flex_shrink_0()
+                    .size(GRIP_DOT_SIZE)
+                    .rounded_full()
+                    .bg(color)
+                    .group_hover("resizable-handle", |this| this.bg(hover_color))
+            }))
+    }
+
     fn sync_real_panel_sizes(&mut self, cx: &WindowContext) {
         for (i, panel) in self.panels.iter().enumerate() {
             self.sizes[i] = panel.read(cx).bounds.size.along(self.axis)
         }
     }
 
-    /// The `ix`` is the index of the panel to resize,
-    /// and the `size` is the new size for the panel.
-    fn resize_panels(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        let mut ix = ix;
-        // Only resize the left panels.
-        if ix >= self.panels.len() - 1 {
+    /// Return the `(min, max)` size limits of the child panels.
+    fn size_limits(&self, cx: &AppContext) -> Vec<(Pixels, Pixels)> {
+        self.panels
+            .iter()
+            .map(|panel| panel.read(cx).size_limits())
+            .collect()
+    }
+
+    /// Collapse or expand the child panel at `ix`, if it is collapsible.
+    ///
+    /// The collapsed panel is shrunk into a strip with a button to expand it,
+    /// and it is restored to the size before collapsed when expanded.
+    pub fn toggle_collapse(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+        let (collapsible, collapsed, expanded_size) = {
+            let panel = panel.read(cx);
+            (panel.collapsible, panel.collapsed, panel.expanded_size)
+        };
+        if !collapsible {
+            return;
+        }
+
+        self.equalize_epoch += 1;
+        self.sync_real_panel_sizes(cx);
+        let size = if collapsed {
+            expanded_size
+        } else {
+            COLLAPSED_SIZE
+        };
+        let current_size = self.sizes[ix];
+        panel.update(cx, |panel, _| {
+            if !collapsed {
+                panel.expanded_size = current_size;
+            }
+            panel.collapsed = !collapsed;
+        });
+
+        self.resize_child_to(ix, size, cx);
+        self.notify_sizes_changed(cx);
+        cx.notify();
+    }
+
+    /// Move the handle at `ix` by the `delta`, by the arrow keys.
+    fn resize_by_keyboard(&mut self, ix: usize, delta: Pixels, cx: &mut ViewContext<Self>) {
+        if ix + 1 >= self.panels.len() {
             return;
         }
-        let size = size.floor();
-        let container_size = self.bounds.size.along(self.axis);
 
         self.sync_real_panel_sizes(cx);
+        self.resize_panels(ix, self.sizes[ix] + delta, cx);
+        self.notify_sizes_changed(cx);
+        cx.notify();
+    }
+
+    /// Emit the sizes changed event and call the `on_resize` callback, it is deferred to allow to update this group.
+    fn notify_sizes_changed(&self, cx: &mut ViewContext<Self>) {
+        cx.emit(ResizablePanelEvent::SizesChanged(self.sizes.clone()));
+        if let Some(handler) = self.on_resize.clone() {
+            let sizes = self.sizes.clone();
+            cx.defer(move |_, cx| handler(&sizes, cx));
+        }
+    }
 
-        let mut changed = size - self.sizes[ix];
-        let is_expand = changed > px(0.);
+    /// Return true if the child panel at `ix` is collapsed.
+    pub fn is_collapsed(&self, ix: usize, cx: &AppContext) -> bool {
+        self.panels
+            .get(ix)
+            .map_or(false, |panel| panel.read(cx).collapsed)
+    }
 
-        let main_ix = ix;
-        let mut new_sizes = self.sizes.clone();
+    /// Toggle the collapse of a collapsible panel beside the handle at `ix`, by the double click.
+    fn toggle_collapse_at_handle(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let collapsible_ix = [ix, ix + 1].into_iter().find(|ix| {
+            self.panels
+                .get(*ix)
+                .map_or(false, |panel| panel.read(cx).collapsible)
+        });
 
-        if is_expand {
-            new_sizes[ix] = size;
+        if let Some(ix) = collapsible_ix {
+            self.toggle_collapse(ix, cx);
+        }
+    }
 
-            // Now to expand logic is correct.
-            while changed > px(0.) && ix < self.panels.len() - 1 {
-                ix += 1;
-                let available_size = (new_sizes[ix] - PANEL_MIN_SIZE).max(px(0.));
-                let to_reduce = changed.min(available_size);
-                new_sizes[ix] -= to_reduce;
-                changed -= to_reduce;
-            }
+    /// Resize the child panel at `ix` into the `size` by moving its handle,
+    /// or the handle before it for the last panel.
+    fn resize_child_to(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
+        let limits = self.size_limits(cx);
+        let delta = size - self.sizes[ix];
+        let new_sizes = if ix + 1 < self.panels.len() {
+            resize_sizes(&self.sizes, &limits, ix, delta)
+        } else if ix > 0 {
+            resize_sizes(&self.sizes, &limits, ix - 1, -delta)
         } else {
-            let new_size = size.max(PANEL_MIN_SIZE);
-            new_sizes[ix] = new_size;
-            changed = size - PANEL_MIN_SIZE;
-            new_sizes[ix + 1] += self.sizes[ix] - new_size;
-
-            while changed < px(0.) && ix > 0 {
-                ix -= 1;
-                let available_size = self.sizes[ix] - PANEL_MIN_SIZE;
-                let to_increase = (changed).min(available_size);
-                new_sizes[ix] += to_increase;
-                changed += to_increase;
+            vec![size]
+        };
+
+        self.set_sizes(new_sizes, &limits, cx);
+    }
+
+    /// Update the bounds of the group in the prepaint, the sizes of the child panels are fitted
+    /// into the container when it is resized, e.g.: the window is resized smaller.
+    fn on_container_resize(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
+        let resized = self.bounds.size.along(self.axis) != bounds.size.along(self.axis);
+        self.bounds = bounds;
+        if !resized || self.sizes.is_empty() {
+            return;
+        }
+
+        let limits = self.size_limits(cx);
+        let sizes = fit_sizes(&self.sizes, &limits, bounds.size.along(self.axis));
+        if sizes != self.sizes {
+            // The sizes are applied in the next frame, the layout of this frame is done.
+            self.sizes = sizes;
+            cx.notify();
+        }
+    }
+
+    /// Set the sizes of the child panels, fit into the container within the `limits`.
+    fn set_sizes(
+        &mut self,
+        sizes: Vec<Pixels>,
+        limits: &[(Pixels, Pixels)],
+        cx: &mut ViewContext<Self>,
+    ) {
+        let container_size = self.bounds.size.along(self.axis);
+        self.sizes = fit_sizes(&sizes, limits, container_size);
+        cx.notify();
+    }
+
+    /// Collapse the collapsible panel beside the handle at `ix`, when the handle is dragged
+    /// over the half of its min size, or expand it when dragged out of its min size.
+    ///
+    /// Return true if a panel is collapsed or expanded.
+    fn collapse_by_drag(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) -> bool {
+        let next_size = self.sizes[ix] + self.sizes[ix + 1] - size;
+        for (panel_ix, size) in [(ix, size), (ix + 1, next_size)] {
+            let panel = self.panels[panel_ix].read(cx);
+            if !panel.collapsible {
+                continue;
+            }
+
+            let should_toggle = if panel.collapsed {
+                size >= panel.min_size
+            } else {
+                size < panel.min_size / 2.
+            };
+            if should_toggle {
+                self.toggle_collapse(panel_ix, cx);
+                return true;
             }
         }
 
-        // If total size exceeds container size, adjust the main panel
-        let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
-        if total_size > container_size {
-            let overflow = total_size - container_size;
-            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(PANEL_MIN_SIZE);
+        false
+    }
+
+    /// Apply the latest size dragged by the mouse, the mouse moves in a frame are coalesced into one resize.
+    fn flush_pending_resize(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some((ix, size)) = self.pending_resize.take() {
+            self.resize_panels(ix, size, cx);
         }
+    }
 
-        self.sizes = new_sizes;
-        for (i, panel) in self.panels.iter().enumerate() {
-            let size = self.sizes[i];
-            panel.update(cx, |this, _| this.size = size);
+    /// The `ix`` is the index of the panel to resize,
+    /// and the `size` is the new size for the panel.
+    fn resize_panels(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
+        // Only resize the left panels.
+        if ix >= self.panels.len() - 1 {
+            return;
+        }
+        let size = size.floor();
+        // The handle takes over the sizes from the equalize animation.
+        self.equalize_epoch += 1;
+
+        self.sync_real_panel_sizes(cx);
+        if self.collapse_by_drag(ix, size, cx) {
+            return;
         }
+
+        let limits = self.size_limits(cx);
+        let new_sizes = resize_sizes(&self.sizes, &limits, ix, size - self.sizes[ix]);
+        self.set_sizes(new_sizes, &limits, cx);
     }
 }
 
+impl EventEmitter<ResizablePanelEvent> for ResizablePanelGroup {}
+
 impl Render for ResizablePanelGroup {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
+        let handles_count = self.panels.len().saturating_sub(1);
+        while self.handle_focus_handles.len() < handles_count {
+            self.handle_focus_handles.push(cx.focus_handle());
+        }
+
+        self.flush_pending_resize(cx);
+        self.shared_sizes.borrow_mut().clone_from(&self.sizes);
+
         let container = if self.axis.is_horizontal() {
             h_flex()
         } else {
@@ -267,18 +657,33 @@ impl Render for ResizablePanelGroup {
         container
             .size_full()
             .children(self.panels.iter().enumerate().map(|(ix, panel)| {
-                if ix < self.panels.len() - 1 {
-                    let handle = self.render_resize_handle(ix, cx);
-                    panel.update(cx, |view, _| {
-                        view.resize_handle = Some(handle.into_any_element())
-                    });
-                }
+                let handle = (ix < self.panels.len() - 1)
+                    .then(|| self.render_resize_handle(ix, cx).into_any_element());
+                let collapsed = panel.read(cx).collapsed;
+                let button =
+                    collapsed.then(|| self.render_expand_button(ix, cx).into_any_element());
+                let shared_sizes = self.shared_sizes.clone();
+                // The handle after the panel, or before it for the last panel.
+                let handle_focus_handle = self
+                    .handle_focus_handles
+                    .get(ix)
+                    .or_else(|| {
+                        ix.checked_sub(1)
+                            .and_then(|ix| self.handle_focus_handles.get(ix))
+                    })
+                    .cloned();
+                panel.update(cx, |view, _| {
+                    view.shared_sizes = Some((shared_sizes, ix));
+                    view.handle_focus_handle = handle_focus_handle;
+                    view.resize_handle = handle;
+                    view.expand_button = button;
+                });
 
                 panel.clone()
             }))
             .child({
                 canvas(
-                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    move |bounds, cx| view.update(cx, |r, cx| r.on_container_resize(bounds, cx)),
                     |_, _, _| {},
                 )
                 .absolute()
@@ -292,24 +697,46 @@ impl Render for ResizablePanelGroup {
 }
 
 pub struct ResizablePanel {
+    /// The initial size, and the rendered size of the panel.
     size: Pixels,
+    /// The sizes of the group and the index of the panel, to read its size in the layout.
+    shared_sizes: Option<(SharedSizes, usize)>,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    collapsible: bool,
+    collapsed: bool,
+    /// The size to restore when the collapsed panel is expanded.
+    expanded_size: Pixels,
     axis: Axis,
     content_builder: Option<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
     content_view: Option<AnyView>,
     /// The bounds of the resizable panel, when render the bounds will be updated.
     bounds: Bounds<Pixels>,
+    on_resize: Option<Rc<dyn Fn(Bounds<Pixels>, &mut WindowContext)>>,
     resize_handle: Option<AnyElement>,
+    /// The focus handle of the nearest resize handle, to focus it by [`FocusResizeHandle`].
+    handle_focus_handle: Option<FocusHandle>,
+    expand_button: Option<AnyElement>,
 }
 
 impl ResizablePanel {
     pub(super) fn new() -> Self {
         Self {
             size: PANEL_MIN_SIZE,
+            shared_sizes: None,
+            min_size: PANEL_MIN_SIZE,
+            max_size: None,
+            collapsible: false,
+            collapsed: false,
+            expanded_size: PANEL_MIN_SIZE,
             axis: Axis::Horizontal,
             content_builder: None,
             content_view: None,
             bounds: Bounds::default(),
+            on_resize: None,
             resize_handle: None,
+            handle_focus_handle: None,
+            expand_button: None,
         }
     }
 
@@ -330,6 +757,45 @@ impl ResizablePanel {
         self.size = size;
         self
     }
+
+    /// Set the min size of the panel along the axis of the group, default is 100px.
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Set the max size of the panel along the axis of the group, default is unlimited.
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
+    /// Set true to allow to collapse the panel by double clicking the handle,
+    /// or dragging the handle over the half of its min size, default is false.
+    ///
+    /// See [`ResizablePanelGroup::toggle_collapse`].
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    fn size_limits(&self) -> (Pixels, Pixels) {
+        if self.collapsed {
+            return (COLLAPSED_SIZE, COLLAPSED_SIZE);
+        }
+
+        let max_size = self.max_size.unwrap_or(px(f32::MAX)).max(self.min_size);
+        (self.min_size, max_size)
+    }
+
+    /// Set the callback when the bounds of the panel are changed, e.g.: resized by the handle or the window.
+    pub fn on_resize(
+        mut self,
+        handler: impl Fn(Bounds<Pixels>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl FluentBuilder for ResizablePanel {}
@@ -338,34 +804,153 @@ impl Render for ResizablePanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
         let axis = self.axis;
-        let size = self.size.max(PANEL_MIN_SIZE);
+        let (min_size, max_size) = self.size_limits();
+        let size = self
+            .shared_sizes
+            .as_ref()
+            .and_then(|(sizes, ix)| sizes.borrow().get(*ix).copied())
+            .unwrap_or(self.size)
+            .clamp(min_size, max_size);
+        let on_resize = self.on_resize.clone();
+        let has_max_size = self.max_size.is_some() || self.collapsed;
+        let expand_button = self.expand_button.take().filter(|_| self.collapsed);
 
         div()
             .flex()
             .flex_grow()
             .relative()
             .overflow_hidden()
-            .when(self.axis.is_vertical(), |this| this.w_full().h(size))
-            .when(self.axis.is_horizontal(), |this| this.h_full().w(size))
+            // The innermost panel of the focus handles it, to focus its nearest resize handle.
+            .on_action(cx.listener(|this, _: &FocusResizeHandle, cx| {
+                match this.handle_focus_handle.as_ref() {
+                    Some(handle) => cx.focus(handle),
+                    None => cx.propagate(),
+                }
+            }))
+            .when(self.axis.is_vertical(), |this| {
+                this.w_full()
+                    .h(size)
+                    .min_h(min_size)
+                    .when(has_max_size, |this| this.max_h(max_size))
+            })
+            .when(self.axis.is_horizontal(), |this| {
+                this.h_full()
+                    .w(size)
+                    .min_w(min_size)
+                    .when(has_max_size, |this| this.max_w(max_size))
+            })
             .child({
                 canvas(
                     move |bounds, cx| {
-                        view.update(cx, |r, _| {
+                        let changed = view.update(cx, |r, _| {
+                            let changed = r.bounds != bounds;
                             r.size = bounds.size.along(axis);
                             r.bounds = bounds;
-                        })
+                            changed
+                        });
+
+                        // Deferred, the handler may update the views in this frame.
+                        if let Some(on_resize) = on_resize.filter(|_| changed) {
+                            cx.defer(move |cx| on_resize(bounds, cx));
+                        }
                     },
                     |_, _, _| {},
                 )
                 .absolute()
                 .size_full()
             })
-            .when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
-            .when_some(self.content_view.clone(), |this, c| this.child(c))
+            .map(|this| match expand_button {
+                Some(button) => this.items_center().justify_center().child(button),
+                None => this
+                    .when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
+                    .when_some(self.content_view.clone(), |this, c| this.child(c)),
+            })
             .when_some(self.resize_handle.take(), |this, c| this.child(c))
     }
 }
 
+/// Move the end of the panel at `ix` by `delta`, return the new sizes within the `limits`.
+///
+/// The panels on the shrinking side are shrunk from the nearest one until their min sizes,
+/// and the panels on the growing side are grown from the nearest one until their max sizes.
+fn resize_sizes(
+    sizes: &[Pixels],
+    limits: &[(Pixels, Pixels)],
+    ix: usize,
+    delta: Pixels,
+) -> Vec<Pixels> {
+    let mut new_sizes = sizes.to_vec();
+    let before = (0..=ix).rev().collect::<Vec<_>>();
+    let after = (ix + 1..sizes.len()).collect::<Vec<_>>();
+    let (shrink, grow, amount) = if delta > px(0.) {
+        (after, before, delta)
+    } else {
+        (before, after, -delta)
+    };
+
+    let shrinkable: f32 = shrink
+        .iter()
+        .map(|&i| (sizes[i] - limits[i].0).max(px(0.)).0)
+        .sum();
+    let growable: f32 = grow
+        .iter()
+        .map(|&i| (limits[i].1 - sizes[i]).max(px(0.)).0)
+        .sum();
+    let amount = amount.min(px(shrinkable)).min(px(growable));
+
+    let mut remaining = amount;
+    for i in shrink {
+        let to_reduce = remaining.min((sizes[i] - limits[i].0).max(px(0.)));
+        new_sizes[i] -= to_reduce;
+        remaining -= to_reduce;
+    }
+    let mut remaining = amount;
+    for i in grow {
+        let to_increase = remaining.min((limits[i].1 - sizes[i]).max(px(0.)));
+        new_sizes[i] += to_increase;
+        remaining -= to_increase;
+    }
+
+    new_sizes
+}
+
+/// Shrink the `sizes` proportionally to fit the `container_size`, without going below their min sizes.
+fn fit_sizes(sizes: &[Pixels], limits: &[(Pixels, Pixels)], container_size: Pixels) -> Vec<Pixels> {
+    let mut sizes = sizes.to_vec();
+    loop {
+        let total: f32 = sizes.iter().map(|size| size.0).sum();
+        let overflow = total - container_size.0;
+        let shrinkable = sizes
+            .iter()
+            .zip(limits)
+            .filter(|(size, (min, _))| *size > min)
+            .map(|(size, _)| size.0)
+            .sum::<f32>();
+        if overflow < 0.5 || shrinkable <= 0. {
+            return sizes;
+        }
+
+        let ratio = (overflow / shrinkable).min(1.);
+        for (size, (min, _)) in sizes.iter_mut().zip(limits) {
+            if *size > *min {
+                *size = (*size - *size * ratio).max(*min);
+            }
+        }
+    }
+}
+
+/// Split the `container_size` into `count` equal sizes, the last one takes the rounding remainder.
+fn equal_sizes(container_size: Pixels, count: usize) -> Vec<Pixels> {
+    if count == 0 {
+        return vec![];
+    }
+
+    let each_size = (container_size / count as f32).floor();
+    let mut sizes = vec![each_size; count];
+    sizes[count - 1] = container_size - each_size * (count - 1) as f32;
+    sizes
+}
+
 struct ResizePanelGroupElement {
     axis: Axis,
     view: View<ResizablePanelGroup>,
@@ -427,14 +1012,12 @@ impl Element for ResizePanelGroupElement {
                                 .expect("BUG: invalid panel index")
                                 .read(cx);
 
-                            match axis {
-                                Axis::Horizontal => {
-                                    view.resize_panels(ix, e.position.x - panel.bounds.left(), cx)
-                                }
-                                Axis::Vertical => {
-                                    view.resize_panels(ix, e.position.y - panel.bounds.top(), cx);
-                                }
-                            }
+                            let size = match axis {
+                                Axis::Horizontal => e.position.x - panel.bounds.left(),
+                                Axis::Vertical => e.position.y - panel.bounds.top(),
+                            };
+                            view.pending_resize = Some((ix, size));
+                            cx.notify();
                         })
                     }
                 }
@@ -446,9 +1029,75 @@ impl Element for ResizePanelGroupElement {
             let view = self.view.clone();
             move |_: &MouseUpEvent, phase, cx| {
                 if phase.bubble() {
-                    view.update(cx, |view, _| view.resizing_panel_ix = None);
+                    view.update(cx, |view, cx| {
+                        if view.resizing_panel_ix.take().is_some() {
+                            view.flush_pending_resize(cx);
+                            view.notify_sizes_changed(cx);
+                        }
+                    });
                 }
             }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{equal_sizes, fit_sizes, resize_sizes};
+
+    #[test]
+    fn test_equal_sizes() {
+        assert_eq!(equal_sizes(px(300.), 0), vec![]);
+        assert_eq!(equal_sizes(px(300.), 3), vec![px(100.), px(100.), px(100.)]);
+        assert_eq!(equal_sizes(px(100.), 3), vec![px(33.), px(33.), px(34.)]);
+    }
+
+    #[test]
+    fn test_resize_sizes() {
+        let unlimited = (px(100.), px(f32::MAX));
+        let limits = [unlimited; 3];
+        let sizes = [px(300.), px(300.), px(300.)];
+
+        // Expand the first panel, the next panels are shrunk from the nearest one.
+        assert_eq!(
+            resize_sizes(&sizes, &limits, 0, px(250.)),
+            vec![px(550.), px(100.), px(250.)]
+        );
+        // Shrink the second panel, the first panel is shrunk when it reaches the min size.
+        assert_eq!(
+            resize_sizes(&sizes, &limits, 1, px(-300.)),
+            vec![px(200.), px(100.), px(600.)]
+        );
+        // Can not shrink below the min sizes.
+        assert_eq!(
+            resize_sizes(&sizes, &limits, 0, px(1000.)),
+            vec![px(700.), px(100.), px(100.)]
+        );
+        // Can not grow over the max size.
+        let limits = [(px(100.), px(400.)), unlimited, unlimited];
+        assert_eq!(
+            resize_sizes(&sizes, &limits, 0, px(200.)),
+            vec![px(400.), px(200.), px(300.)]
+        );
+    }
+
+    #[test]
+    fn test_fit_sizes() {
+        let limits = [(px(100.), px(f32::MAX)); 3];
+        assert_eq!(
+            fit_sizes(&[px(200.), px(400.), px(400.)], &limits, px(1000.)),
+            vec![px(200.), px(400.), px(400.)]
+        );
+        assert_eq!(
+            fit_sizes(&[px(200.), px(400.), px(400.)], &limits, px(500.)),
+            vec![px(100.), px(200.), px(200.)]
+        );
+        // The panel at the min size is kept, the others are shrunk.
+        assert_eq!(
+            fit_sizes(&[px(100.), px(400.), px(400.)], &limits, px(500.)),
+            vec![px(100.), px(200.), px(200.)]
+        );
+    }
+}
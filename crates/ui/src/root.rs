@@ -1,6 +1,6 @@
 use gpui::{
     div, AnyView, FocusHandle, InteractiveElement, ParentElement as _, Render, Styled, View,
-    ViewContext, VisualContext as _, WindowContext,
+    ViewContext, VisualContext as _, WeakView, WindowContext,
 };
 use std::{
     ops::{Deref, DerefMut},
@@ -9,8 +9,10 @@ use std::{
 
 use crate::{
     drawer::Drawer,
+    focusable::set_keyboard_modality,
     modal::Modal,
     notification::{Notification, NotificationList},
+    shortcuts_overlay::{ShortcutsOverlay, ToggleShortcutsOverlay},
     theme::ActiveTheme,
 };
 
@@ -51,7 +53,10 @@ impl<'a> ContextModal for WindowContext<'a> {
         F: Fn(Drawer, &mut WindowContext) -> Drawer + 'static,
     {
         Root::update(self, move |root, cx| {
-            root.previous_focus_handle = cx.focused();
+            // Keep the focus before the first drawer, not the focus in the drawer that is replaced.
+            if root.active_drawer.is_none() {
+                root.drawer_focus_handle = cx.focused();
+            }
             root.active_drawer = Some(Rc::new(build));
             cx.notify();
         })
@@ -64,7 +69,7 @@ impl<'a> ContextModal for WindowContext<'a> {
     fn close_drawer(&mut self) {
         Root::update(self, |root, cx| {
             root.active_drawer = None;
-            root.focus_back(cx);
+            Root::focus_back(root.drawer_focus_handle.take(), cx);
             cx.notify();
         })
     }
@@ -74,7 +79,9 @@ impl<'a> ContextModal for WindowContext<'a> {
         F: Fn(Modal, &mut WindowContext) -> Modal + 'static,
     {
         Root::update(self, move |root, cx| {
-            root.previous_focus_handle = cx.focused();
+            if root.active_modal.is_none() {
+                root.modal_focus_handle = cx.focused();
+            }
             root.active_modal = Some(Rc::new(build));
             cx.notify();
         })
@@ -87,7 +94,7 @@ impl<'a> ContextModal for WindowContext<'a> {
     fn close_modal(&mut self) {
         Root::update(self, |root, cx| {
             root.active_modal = None;
-            root.focus_back(cx);
+            Root::focus_back(root.modal_focus_handle.take(), cx);
             cx.notify();
         })
     }
@@ -159,22 +166,29 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
 ///
 /// It is used to manage the Drawer, Modal, and Notification.
 pub struct Root {
-    /// Used to store the focus handle of the previus revious view.
-    /// When the Modal, Drawer closes, we will focus back to the previous view.
-    previous_focus_handle: Option<FocusHandle>,
+    /// The focus before the Drawer is opened, to focus back when it is closed.
+    ///
+    /// The Modal and the Drawer keep their own focus, a Modal may be opened from a Drawer.
+    drawer_focus_handle: Option<FocusHandle>,
+    /// The focus before the Modal is opened, to focus back when it is closed.
+    modal_focus_handle: Option<FocusHandle>,
     pub active_drawer: Option<Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>>,
     pub active_modal: Option<Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>>,
     pub notification: View<NotificationList>,
+    /// The shortcuts overlay opened as the modal, to close it only if it is the active modal.
+    pub(crate) shortcuts_overlay: Option<WeakView<ShortcutsOverlay>>,
     child: AnyView,
 }
 
 impl Root {
     pub fn new(child: AnyView, cx: &mut ViewContext<Self>) -> Self {
         Self {
-            previous_focus_handle: None,
+            drawer_focus_handle: None,
+            modal_focus_handle: None,
             active_drawer: None,
             active_modal: None,
             notification: cx.new_view(NotificationList::new),
+            shortcuts_overlay: None,
             child,
         }
     }
@@ -202,8 +216,8 @@ impl Root {
         root.read(cx)
     }
 
-    fn focus_back(&mut self, cx: &mut WindowContext) {
-        if let Some(handle) = self.previous_focus_handle.take() {
+    fn focus_back(handle: Option<FocusHandle>, cx: &mut WindowContext) {
+        if let Some(handle) = handle {
             cx.focus(&handle);
         }
     }
@@ -215,6 +229,9 @@ impl Render for Root {
             .id("root")
             .size_full()
             .text_color(cx.theme().foreground)
+            .on_action(|_: &ToggleShortcutsOverlay, cx| ShortcutsOverlay::toggle(cx))
+            .capture_key_down(|_, cx| set_keyboard_modality(true, cx))
+            .capture_any_mouse_down(|_, cx| set_keyboard_modality(false, cx))
             .child(self.child.clone())
     }
 }
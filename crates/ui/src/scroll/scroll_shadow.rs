@@ -0,0 +1,167 @@
+use gpui::{
+    fill, point, px, relative, size, Bounds, ContentMask, Element, IntoElement, Pixels, Position,
+    ScrollHandle, Style,
+};
+
+use crate::theme::{ActiveTheme, Colorize};
+
+use super::ScrollbarAxis;
+
+/// The length of the shadow along the scroll axis.
+const SHADOW_SIZE: Pixels = Pixels(12.);
+/// The number of strips to paint the fade, more steps for a smoother gradient.
+const SHADOW_STEPS: usize = 6;
+/// The max opacity of the strip nearest to the edge.
+const SHADOW_OPACITY: f32 = 0.16;
+
+/// An element to paint a fade shadow on the edges of a scroll area,
+/// only on the edges that still have more content to scroll.
+pub struct ScrollShadow {
+    axis: ScrollbarAxis,
+    scroll_handle: ScrollHandle,
+    scroll_size: gpui::Size<Pixels>,
+}
+
+impl ScrollShadow {
+    pub fn new(
+        axis: ScrollbarAxis,
+        scroll_handle: &ScrollHandle,
+        scroll_size: gpui::Size<Pixels>,
+    ) -> Self {
+        Self {
+            axis,
+            scroll_handle: scroll_handle.clone(),
+            scroll_size,
+        }
+    }
+}
+
+/// The edge of the scroll area to paint the shadow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Edge {
+    /// Returns the bounds of the strip at `step`, counting from the edge.
+    fn strip_bounds(&self, container: Bounds<Pixels>, step: usize) -> Bounds<Pixels> {
+        let strip = SHADOW_SIZE / SHADOW_STEPS as f32;
+        let inset = strip * step as f32;
+        let origin = container.origin;
+        let container_size = container.size;
+
+        match self {
+            Edge::Top => Bounds {
+                origin: point(origin.x, origin.y + inset),
+                size: size(container_size.width, strip),
+            },
+            Edge::Bottom => Bounds {
+                origin: point(origin.x, origin.y + container_size.height - inset - strip),
+                size: size(container_size.width, strip),
+            },
+            Edge::Left => Bounds {
+                origin: point(origin.x + inset, origin.y),
+                size: size(strip, container_size.height),
+            },
+            Edge::Right => Bounds {
+                origin: point(origin.x + container_size.width - inset - strip, origin.y),
+                size: size(strip, container_size.height),
+            },
+        }
+    }
+}
+
+impl IntoElement for ScrollShadow {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ScrollShadow {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<gpui::ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        cx: &mut gpui::WindowContext,
+    ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.position = Position::Absolute;
+        style.size.width = relative(1.).into();
+        style.size.height = relative(1.).into();
+
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        _: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        _: &mut gpui::WindowContext,
+    ) -> Self::PrepaintState {
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&gpui::GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut gpui::WindowContext,
+    ) {
+        let offset = self.scroll_handle.offset();
+        let mut edges = vec![];
+
+        if self.axis.has_vertical() && self.scroll_size.height > bounds.size.height {
+            if offset.y < px(0.) {
+                edges.push(Edge::Top);
+            }
+            if -offset.y + bounds.size.height < self.scroll_size.height {
+                edges.push(Edge::Bottom);
+            }
+        }
+
+        if self.axis.has_horizontal() && self.scroll_size.width > bounds.size.width {
+            if offset.x < px(0.) {
+                edges.push(Edge::Left);
+            }
+            if -offset.x + bounds.size.width < self.scroll_size.width {
+                edges.push(Edge::Right);
+            }
+        }
+
+        if edges.is_empty() {
+            return;
+        }
+
+        let color = if cx.theme().mode.is_dark() {
+            gpui::black()
+        } else {
+            cx.theme().foreground
+        };
+
+        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            for edge in edges {
+                for step in 0..SHADOW_STEPS {
+                    let opacity =
+                        SHADOW_OPACITY * (SHADOW_STEPS - step) as f32 / SHADOW_STEPS as f32;
+                    cx.paint_quad(fill(
+                        edge.strip_bounds(bounds, step),
+                        color.opacity(opacity),
+                    ));
+                }
+            }
+        });
+    }
+}
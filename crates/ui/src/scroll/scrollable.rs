@@ -1,10 +1,11 @@
 use std::{cell::Cell, rc::Rc};
 
-use super::{Scrollbar, ScrollbarAxis, ScrollbarState};
+use super::{ScrollShadow, Scrollbar, ScrollbarAxis, ScrollbarState};
 use gpui::{
-    canvas, div, relative, AnyElement, Div, Element, ElementId, EntityId, GlobalElementId,
-    InteractiveElement, IntoElement, ParentElement, Pixels, Position, ScrollHandle, SharedString,
-    Size, Stateful, StatefulInteractiveElement, Style, StyleRefinement, Styled, WindowContext,
+    canvas, div, prelude::FluentBuilder as _, relative, AnyElement, Div, Element, ElementId,
+    EntityId, GlobalElementId, InteractiveElement, IntoElement, ParentElement, Pixels, Position,
+    ScrollHandle, SharedString, Size, Stateful, StatefulInteractiveElement, Style, StyleRefinement,
+    Styled, WindowContext,
 };
 
 /// A scroll view is a container that allows the user to scroll through a large amount of content.
@@ -13,6 +14,7 @@ pub struct Scrollable<E> {
     element: Option<E>,
     view_id: EntityId,
     axis: ScrollbarAxis,
+    shadow: bool,
     /// This is a fake element to handle Styled, InteractiveElement, not used.
     _element: Stateful<Div>,
 }
@@ -34,6 +36,7 @@ where
             id,
             view_id,
             axis,
+            shadow: false,
         }
     }
 
@@ -50,6 +53,12 @@ where
         self
     }
 
+    /// Show a fade shadow on the edges that have more content to scroll, default is false.
+    pub fn scroll_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     /// Set the axis of the scroll view.
     pub fn set_axis(&mut self, axis: ScrollbarAxis) {
         self.axis = axis;
@@ -157,6 +166,7 @@ where
         style.size.height = relative(1.0).into();
 
         let axis = self.axis;
+        let shadow = self.shadow;
         let view_id = self.view_id;
 
         let scroll_id = self.id.clone();
@@ -185,6 +195,9 @@ where
                                 .size_full()
                         })),
                 )
+                .when(shadow, |this| {
+                    this.child(ScrollShadow::new(axis, &handle, scroll_size.get()))
+                })
                 .child(
                     div()
                         .absolute()
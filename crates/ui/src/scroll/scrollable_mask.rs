@@ -14,6 +14,17 @@ pub enum ScrollableAxis {
     Vertical,
 }
 
+/// Return the horizontal delta of the wheel event, the vertical delta is used when `shift` is pressed,
+/// because only the macOS converts the `shift` + wheel to the horizontal scrolling.
+pub(crate) fn horizontal_wheel_delta(event: &ScrollWheelEvent, line_height: Pixels) -> Pixels {
+    let delta = event.delta.pixel_delta(line_height);
+    if delta.x.is_zero() && event.modifiers.shift {
+        delta.y
+    } else {
+        delta.x
+    }
+}
+
 /// Make a scrollable mask element to cover the parent view with the mouse wheel event listening.
 ///
 /// When the mouse wheel is scrolled, will move the `scroll_handle` scrolling with the `axis` direction.
@@ -133,14 +144,15 @@ impl Element for ScrollableMask {
                     if bounds.contains(&mouse_position) {
                         let delta = event.delta.pixel_delta(line_height);
 
-                        if is_horizontal && !delta.x.is_zero() {
+                        let delta_x = horizontal_wheel_delta(event, line_height);
+                        if is_horizontal && !delta_x.is_zero() {
                             // When is horizontal scroll, move the horizontal scroll handle to make scrolling.
                             let mut offset = scroll_handle.offset();
-                            offset.x += delta.x;
+                            offset.x += delta_x;
                             scroll_handle.set_offset(offset);
                         }
 
-                        if !is_horizontal && !delta.y.is_zero() {
+                        if !is_horizontal && !event.modifiers.shift && !delta.y.is_zero() {
                             // When is vertical scroll, move the vertical scroll handle to make scrolling.
                             let mut offset = scroll_handle.offset();
                             offset.y += delta.y;
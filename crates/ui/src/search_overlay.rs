@@ -0,0 +1,256 @@
+//! A find bar that a panel can host to search its own content by `cmd-f` (`ctrl-f`).
+//!
+//! ```ignore
+//! impl Searchable for MyPanel { ... }
+//!
+//! let search = cx.new_view(|cx| SearchOverlay::new(&cx.view().clone(), cx));
+//!
+//! div()
+//!     .relative()
+//!     .track_focus(&self.focus_handle)
+//!     .on_action(cx.listener(|this, _: &Find, cx| {
+//!         this.search.update(cx, |search, cx| search.show(cx))
+//!     }))
+//!     .child(self.search.clone())
+//! ```
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, AppContext, DismissEvent, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyBinding, ParentElement, Render,
+    SharedString, Styled, View, ViewContext, VisualContext as _, WeakView, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme as _,
+    IconName, Sizable as _,
+};
+
+actions!(
+    search_overlay,
+    [Find, SelectNextMatch, SelectPrevMatch, CloseSearch]
+);
+
+const CONTEXT: &str = "SearchOverlay";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-f", Find, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-f", Find, None),
+        KeyBinding::new("shift-enter", SelectPrevMatch, Some(CONTEXT)),
+        KeyBinding::new("escape", CloseSearch, Some(CONTEXT)),
+    ]);
+}
+
+/// The content that can be searched by the [`SearchOverlay`].
+pub trait Searchable: Render {
+    /// Search the `query` and highlight the matches, return the number of the matches.
+    ///
+    /// The `query` is empty when the search is cleared.
+    fn search(&mut self, query: &str, cx: &mut ViewContext<Self>) -> usize;
+
+    /// Activate the match at the `ix`, e.g.: scroll it into view and highlight it as the current match.
+    fn activate_match(&mut self, ix: usize, cx: &mut ViewContext<Self>);
+}
+
+trait SearchableHandle: 'static {
+    fn search(&self, query: &str, cx: &mut WindowContext) -> usize;
+    fn activate_match(&self, ix: usize, cx: &mut WindowContext);
+}
+
+impl<T: Searchable> SearchableHandle for WeakView<T> {
+    fn search(&self, query: &str, cx: &mut WindowContext) -> usize {
+        self.update(cx, |view, cx| view.search(query, cx))
+            .unwrap_or_default()
+    }
+
+    fn activate_match(&self, ix: usize, cx: &mut WindowContext) {
+        _ = self.update(cx, |view, cx| view.activate_match(ix, cx));
+    }
+}
+
+pub struct SearchOverlay {
+    target: Box<dyn SearchableHandle>,
+    input: View<TextInput>,
+    open: bool,
+    query: SharedString,
+    match_count: usize,
+    active_ix: usize,
+}
+
+impl SearchOverlay {
+    /// Create the overlay to search the `target`, it is hidden until [`SearchOverlay::show`].
+    pub fn new<T: Searchable>(target: &View<T>, cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .small()
+                .prefix(|_| IconName::Search)
+                .placeholder(t!("SearchOverlay.Find"))
+        });
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            target: Box::new(target.downgrade()),
+            input,
+            open: false,
+            query: SharedString::default(),
+            match_count: 0,
+            active_ix: 0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the overlay and focus the input, the previous query is searched again.
+    pub fn show(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        self.input.focus_handle(cx).focus(cx);
+        // Deferred, because the target is usually updating when it handles the `Find` action.
+        cx.defer(|this, cx| {
+            let query = this.query.clone();
+            this.search(&query, cx);
+        });
+        cx.notify();
+    }
+
+    /// Hide the overlay and clear the highlights of the matches.
+    pub fn hide(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        cx.defer(|this, cx| {
+            this.target.search("", cx);
+        });
+        cx.emit(DismissEvent);
+        cx.notify();
+    }
+
+    fn search(&mut self, query: &str, cx: &mut ViewContext<Self>) {
+        self.query = query.to_string().into();
+        self.match_count = self.target.search(query, cx);
+        self.active_ix = 0;
+        if self.match_count > 0 {
+            self.target.activate_match(0, cx);
+        }
+        cx.notify();
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => self.search(&text.clone(), cx),
+            InputEvent::PressEnter => self.select_next(&SelectNextMatch, cx),
+            _ => {}
+        }
+    }
+
+    fn select_next(&mut self, _: &SelectNextMatch, cx: &mut ViewContext<Self>) {
+        if self.match_count == 0 {
+            return;
+        }
+
+        self.active_ix = (self.active_ix + 1) % self.match_count;
+        self.target.activate_match(self.active_ix, cx);
+        cx.notify();
+    }
+
+    fn select_prev(&mut self, _: &SelectPrevMatch, cx: &mut ViewContext<Self>) {
+        if self.match_count == 0 {
+            return;
+        }
+
+        self.active_ix = (self.active_ix + self.match_count - 1) % self.match_count;
+        self.target.activate_match(self.active_ix, cx);
+        cx.notify();
+    }
+
+    fn close(&mut self, _: &CloseSearch, cx: &mut ViewContext<Self>) {
+        self.hide(cx);
+    }
+}
+
+impl EventEmitter<DismissEvent> for SearchOverlay {}
+
+impl FocusableView for SearchOverlay {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for SearchOverlay {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !self.open {
+            return div();
+        }
+
+        let status = if self.query.is_empty() {
+            String::new()
+        } else if self.match_count == 0 {
+            t!("SearchOverlay.No results").to_string()
+        } else {
+            format!("{}/{}", self.active_ix + 1, self.match_count)
+        };
+
+        div().absolute().top_2().right_4().child(
+            h_flex()
+                .key_context(CONTEXT)
+                .on_action(cx.listener(Self::select_next))
+                .on_action(cx.listener(Self::select_prev))
+                .on_action(cx.listener(Self::close))
+                .gap_1()
+                .p_1()
+                .rounded_md()
+                .border_1()
+                .border_color(cx.theme().border)
+                .bg(cx.theme().popover)
+                .shadow_md()
+                .child(div().w_48().child(self.input.clone()))
+                .child(
+                    div()
+                        .min_w_16()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(status),
+                )
+                .child(
+                    Button::new("prev", cx)
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::ChevronUp)
+                        .when(self.match_count == 0, |this| this.disabled(true))
+                        .on_click(
+                            cx.listener(|this, _, cx| this.select_prev(&SelectPrevMatch, cx)),
+                        ),
+                )
+                .child(
+                    Button::new("next", cx)
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::ChevronDown)
+                        .when(self.match_count == 0, |this| this.disabled(true))
+                        .on_click(
+                            cx.listener(|this, _, cx| this.select_next(&SelectNextMatch, cx)),
+                        ),
+                )
+                .child(
+                    Button::new("close", cx)
+                        .ghost()
+                        .xsmall()
+                        .icon(IconName::Close)
+                        .on_click(cx.listener(|this, _, cx| this.hide(cx))),
+                ),
+        )
+    }
+}
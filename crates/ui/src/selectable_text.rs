@@ -0,0 +1,298 @@
+//! The static text that can be selected by the mouse and copied by `cmd-c` (`ctrl-c`).
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use gpui::{
+    fill, point, px, AvailableSpace, Bounds, ClipboardItem, CursorStyle, DispatchPhase, Element,
+    ElementId, GlobalElementId, Hitbox, IntoElement, KeyDownEvent, LayoutId, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels, Point, SharedString, Size, Style,
+    WindowContext, WrappedLine,
+};
+use smallvec::SmallVec;
+
+use crate::theme::ActiveTheme as _;
+
+#[derive(Default)]
+struct SelectionState {
+    /// The byte range of the selected text.
+    range: Range<usize>,
+    /// The index where the selection is started.
+    anchor: usize,
+    selecting: bool,
+}
+
+pub struct PrepaintState {
+    lines: SmallVec<[WrappedLine; 1]>,
+    line_height: Pixels,
+    hitbox: Hitbox,
+}
+
+/// A text element that can be selected, it is used by `Label::selectable`.
+pub struct SelectableText {
+    id: ElementId,
+    text: SharedString,
+}
+
+impl SelectableText {
+    pub fn new(id: impl Into<ElementId>, text: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+        }
+    }
+
+    fn shape_lines(
+        text: &SharedString,
+        wrap_width: Option<Pixels>,
+        cx: &mut WindowContext,
+    ) -> (SmallVec<[WrappedLine; 1]>, Pixels) {
+        let style = cx.text_style();
+        let font_size = style.font_size.to_pixels(cx.rem_size());
+        let line_height = style.line_height_in_pixels(cx.rem_size());
+        let run = style.to_run(text.len());
+        let lines = cx
+            .text_system()
+            .shape_text(text.clone(), font_size, &[run], wrap_width)
+            .unwrap_or_default();
+
+        (lines, line_height)
+    }
+
+    fn with_element_state<R>(
+        id: &GlobalElementId,
+        cx: &mut WindowContext,
+        f: impl FnOnce(&Rc<RefCell<SelectionState>>, &mut WindowContext) -> R,
+    ) -> R {
+        cx.with_optional_element_state::<Rc<RefCell<SelectionState>>, _>(
+            Some(id),
+            |element_state, cx| {
+                let state = element_state.unwrap().unwrap_or_default();
+                let result = f(&state, cx);
+                (result, Some(state))
+            },
+        )
+    }
+}
+
+/// Return the byte index of the text at the `position`, the lines are split by `\n`.
+fn index_for_position(
+    lines: &[WrappedLine],
+    origin: Point<Pixels>,
+    line_height: Pixels,
+    position: Point<Pixels>,
+) -> usize {
+    let mut offset = 0;
+    let mut top = origin.y;
+    for (ix, line) in lines.iter().enumerate() {
+        let height = line.size(line_height).height;
+        if position.y < top + height || ix == lines.len() - 1 {
+            let local = point(position.x - origin.x, (position.y - top).max(px(0.)));
+            let local_ix = match line.index_for_position(local, line_height) {
+                Ok(ix) | Err(ix) => ix,
+            };
+            return offset + local_ix.min(line.len());
+        }
+
+        offset += line.len() + 1;
+        top += height;
+    }
+
+    offset
+}
+
+/// Return the bounds to highlight the selected `range` of the lines.
+fn selection_bounds(
+    lines: &[WrappedLine],
+    bounds: Bounds<Pixels>,
+    line_height: Pixels,
+    range: &Range<usize>,
+) -> Vec<Bounds<Pixels>> {
+    let mut result = vec![];
+    let mut offset = 0;
+    let mut top = bounds.top();
+    for line in lines {
+        let line_range = offset..offset + line.len();
+        let start = range.start.max(line_range.start);
+        let end = range.end.min(line_range.end);
+        if start < end {
+            let start = line.position_for_index(start - offset, line_height);
+            let end = line.position_for_index(end - offset, line_height);
+            if let (Some(start), Some(end)) = (start, end) {
+                let mut y = start.y;
+                while y <= end.y {
+                    let left = if y == start.y { start.x } else { px(0.) };
+                    let right = if y == end.y { end.x } else { bounds.size.width };
+                    result.push(Bounds::from_corners(
+                        point(bounds.left() + left, top + y),
+                        point(bounds.left() + right, top + y + line_height),
+                    ));
+                    y += line_height;
+                }
+            }
+        }
+
+        offset += line.len() + 1;
+        top += line.size(line_height).height;
+    }
+
+    result
+}
+
+impl IntoElement for SelectableText {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for SelectableText {
+    type RequestLayoutState = ();
+    type PrepaintState = PrepaintState;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let text = self.text.clone();
+        let layout_id =
+            cx.request_measured_layout(Style::default(), move |known, available, cx| {
+                let wrap_width = known.width.or(match available.width {
+                    AvailableSpace::Definite(width) => Some(width),
+                    _ => None,
+                });
+
+                let (lines, line_height) = Self::shape_lines(&text, wrap_width, cx);
+                let mut size = Size::<Pixels>::default();
+                for line in &lines {
+                    let line_size = line.size(line_height);
+                    size.width = size.width.max(line_size.width);
+                    size.height += line_size.height;
+                }
+                size
+            });
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let (lines, line_height) = Self::shape_lines(&self.text, Some(bounds.size.width), cx);
+        let hitbox = cx.insert_hitbox(bounds, false);
+
+        PrepaintState {
+            lines,
+            line_height,
+            hitbox,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let lines = Rc::new(std::mem::take(&mut prepaint.lines));
+        let line_height = prepaint.line_height;
+        let hitbox = prepaint.hitbox.clone();
+        let text = self.text.clone();
+        cx.set_cursor_style(CursorStyle::IBeam, &hitbox);
+
+        Self::with_element_state(id.unwrap(), cx, |state, cx| {
+            let selection_color = cx.theme().selection;
+            for bounds in selection_bounds(&lines, bounds, line_height, &state.borrow().range) {
+                cx.paint_quad(fill(bounds, selection_color));
+            }
+
+            let mut origin = bounds.origin;
+            for line in lines.iter() {
+                _ = line.paint(origin, line_height, cx);
+                origin.y += line.size(line_height).height;
+            }
+
+            cx.on_mouse_event({
+                let state = state.clone();
+                let lines = lines.clone();
+                move |event: &MouseDownEvent, phase, cx| {
+                    if phase != DispatchPhase::Bubble || event.button != MouseButton::Left {
+                        return;
+                    }
+
+                    let mut state = state.borrow_mut();
+                    if hitbox.is_hovered(cx) {
+                        let ix =
+                            index_for_position(&lines, bounds.origin, line_height, event.position);
+                        state.anchor = ix;
+                        state.range = ix..ix;
+                        state.selecting = true;
+                        cx.refresh();
+                    } else if !state.range.is_empty() {
+                        // Clear the selection when click outside.
+                        state.range = 0..0;
+                        cx.refresh();
+                    }
+                }
+            });
+
+            cx.on_mouse_event({
+                let state = state.clone();
+                let lines = lines.clone();
+                move |event: &MouseMoveEvent, phase, cx| {
+                    let mut state = state.borrow_mut();
+                    if phase != DispatchPhase::Bubble || !state.selecting {
+                        return;
+                    }
+
+                    let ix = index_for_position(&lines, bounds.origin, line_height, event.position);
+                    let range = state.anchor.min(ix)..state.anchor.max(ix);
+                    if state.range != range {
+                        state.range = range;
+                        cx.refresh();
+                    }
+                }
+            });
+
+            cx.on_mouse_event({
+                let state = state.clone();
+                move |_: &MouseUpEvent, phase, _| {
+                    if phase == DispatchPhase::Bubble {
+                        state.borrow_mut().selecting = false;
+                    }
+                }
+            });
+
+            cx.on_key_event({
+                let state = state.clone();
+                move |event: &KeyDownEvent, phase, cx| {
+                    let modifiers = event.keystroke.modifiers;
+                    let secondary = if cfg!(target_os = "macos") {
+                        modifiers.platform
+                    } else {
+                        modifiers.control
+                    };
+                    if phase != DispatchPhase::Bubble || !secondary || event.keystroke.key != "c" {
+                        return;
+                    }
+
+                    let range = state.borrow().range.clone();
+                    if let Some(selected) = text.get(range).filter(|s| !s.is_empty()) {
+                        cx.write_to_clipboard(ClipboardItem::new_string(selected.to_string()));
+                        cx.stop_propagation();
+                    }
+                }
+            });
+        });
+    }
+}
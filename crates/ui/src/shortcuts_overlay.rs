@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyBindingContextPredicate,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    Subscription, View, ViewContext, VisualContext as _, WindowContext,
+};
+
+use crate::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    kbd::Kbd,
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, Root, StyledExt as _,
+};
+
+actions!(shortcuts_overlay, [ToggleShortcutsOverlay]);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("?", ToggleShortcutsOverlay, Some("!Input")),
+        KeyBinding::new("cmd-/", ToggleShortcutsOverlay, None),
+    ]);
+}
+
+/// The group of the bindings without a context.
+const GLOBAL_CONTEXT: &str = "Global";
+
+/// A bound action with the keybindings in a key context.
+struct ShortcutEntry {
+    /// The key context of the bindings, e.g.: `Dropdown`
+    context: SharedString,
+    /// The humanized action name, e.g.: `Select Next`
+    name: SharedString,
+    bindings: Vec<KeyBinding>,
+}
+
+impl ShortcutEntry {
+    fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || self.name.to_lowercase().contains(query)
+            || self.context.to_lowercase().contains(query)
+    }
+}
+
+/// A modal overlay to list all the bound actions of the current focused context.
+///
+/// Use [`ToggleShortcutsOverlay`] action (`?` or `cmd-/`) to open it.
+pub struct ShortcutsOverlay {
+    input: View<TextInput>,
+    entries: Vec<ShortcutEntry>,
+    query: SharedString,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ShortcutsOverlay {
+    fn new(entries: Vec<ShortcutEntry>, cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).placeholder("Search shortcuts..."));
+        let _subscriptions = vec![cx.subscribe(&input, |this, input, event, cx| {
+            if let InputEvent::Change(_) = event {
+                this.query = input.read(cx).text().to_lowercase().into();
+                cx.notify();
+            }
+        })];
+
+        Self {
+            input,
+            entries,
+            query: SharedString::default(),
+            _subscriptions,
+        }
+    }
+
+    /// Open the overlay with the actions available in the current focused context,
+    /// or close it if it is open, the other modals are kept open.
+    pub fn toggle(cx: &mut WindowContext) {
+        if cx.has_active_modal() {
+            if Self::is_open(cx) {
+                cx.close_modal();
+            }
+            return;
+        }
+
+        let entries = Self::collect_entries(cx);
+        let view = cx.new_view(|cx| Self::new(entries, cx));
+        let focus_handle = view.read(cx).input.focus_handle(cx);
+        let overlay = view.downgrade();
+        Root::update(cx, move |root, _| root.shortcuts_overlay = Some(overlay));
+
+        cx.open_modal(move |modal, _| {
+            modal
+                .title("Keyboard Shortcuts")
+                .width(px(560.))
+                .child(view.clone())
+        });
+        focus_handle.focus(cx);
+    }
+
+    /// Return true if the overlay is the active modal, its view is released when the modal is closed or replaced.
+    fn is_open(cx: &WindowContext) -> bool {
+        Root::read(cx)
+            .shortcuts_overlay
+            .as_ref()
+            .map_or(false, |overlay| overlay.upgrade().is_some())
+    }
+
+    fn collect_entries(cx: &WindowContext) -> Vec<ShortcutEntry> {
+        let mut entries: BTreeMap<(SharedString, String), ShortcutEntry> = BTreeMap::new();
+        let mut seen = BTreeSet::new();
+
+        for action in cx.available_actions() {
+            let full_name = action.name().to_string();
+            if !seen.insert(full_name.clone()) {
+                continue;
+            }
+
+            let name = full_name
+                .rsplit_once("::")
+                .map_or(full_name.as_str(), |(_, name)| name);
+            for binding in cx.bindings_for_action(action.as_ref()) {
+                let context = binding
+                    .predicate()
+                    .map(context_name)
+                    .unwrap_or_else(|| GLOBAL_CONTEXT.into());
+                entries
+                    .entry((context.clone(), full_name.clone()))
+                    .or_insert_with(|| ShortcutEntry {
+                        context,
+                        name: humanize(name).into(),
+                        bindings: vec![],
+                    })
+                    .bindings
+                    .push(binding);
+            }
+        }
+
+        entries.into_values().collect()
+    }
+
+    fn render_group(
+        &self,
+        context: SharedString,
+        entries: Vec<&ShortcutEntry>,
+        cx: &ViewContext<Self>,
+    ) -> impl IntoElement {
+        v_flex()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(context.to_uppercase()),
+            )
+            .children(entries.into_iter().map(|entry| {
+                h_flex()
+                    .justify_between()
+                    .gap_4()
+                    .py_0p5()
+                    .text_sm()
+                    .child(entry.name.clone())
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .children(entry.bindings.iter().map(|binding| Kbd::binding(binding))),
+                    )
+            }))
+    }
+}
+
+/// Return the name of the context that the binding is active in, e.g.: `Dropdown` for `Dropdown > Input`.
+fn context_name(predicate: &KeyBindingContextPredicate) -> SharedString {
+    match predicate {
+        KeyBindingContextPredicate::Identifier(name) => name.clone(),
+        KeyBindingContextPredicate::Equal(key, _)
+        | KeyBindingContextPredicate::NotEqual(key, _) => key.clone(),
+        KeyBindingContextPredicate::Child(parent, _) => context_name(parent),
+        KeyBindingContextPredicate::Not(_) => GLOBAL_CONTEXT.into(),
+        KeyBindingContextPredicate::And(left, _) | KeyBindingContextPredicate::Or(left, _) => {
+            context_name(left)
+        }
+    }
+}
+
+/// Convert `SelectNext` to `Select Next`.
+fn humanize(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (ix, c) in name.chars().enumerate() {
+        if ix > 0 && c.is_uppercase() {
+            result.push(' ');
+        }
+        result.push(c);
+    }
+    result
+}
+
+impl Render for ShortcutsOverlay {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let query = self.query.clone();
+        let mut groups: BTreeMap<SharedString, Vec<&ShortcutEntry>> = BTreeMap::new();
+        for entry in self.entries.iter().filter(|entry| entry.matches(&query)) {
+            groups.entry(entry.context.clone()).or_default().push(entry);
+        }
+        let is_empty = groups.is_empty();
+
+        v_flex().gap_3().child(self.input.clone()).child(
+            v_flex()
+                .id("shortcuts")
+                .max_h(px(420.))
+                .overflow_y_scroll()
+                .gap_4()
+                .children(
+                    groups
+                        .into_iter()
+                        .map(|(context, entries)| self.render_group(context, entries, cx)),
+                )
+                .when(is_empty, |this| {
+                    this.child(
+                        h_flex()
+                            .justify_center()
+                            .py_6()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("No shortcuts found."),
+                    )
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::KeyBindingContextPredicate;
+
+    use super::{context_name, humanize};
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(humanize("SelectNext"), "Select Next");
+        assert_eq!(humanize("Up"), "Up");
+        assert_eq!(humanize(""), "");
+    }
+
+    #[test]
+    fn test_context_name() {
+        let name = |source: &str| {
+            context_name(&KeyBindingContextPredicate::parse(source).unwrap()).to_string()
+        };
+        assert_eq!(name("Dropdown"), "Dropdown");
+        assert_eq!(name("Dropdown > Input"), "Dropdown");
+        assert_eq!(name("Editor && mode == full"), "Editor");
+        assert_eq!(name("!Input"), "Global");
+    }
+}
@@ -0,0 +1,338 @@
+//! The left navigation sidebar, it can be resized by dragging the right edge,
+//! and collapsed to a rail that only shows the icons.
+use std::time::Duration;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, DragMoveEvent, EntityId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, ParentElement, Pixels,
+    Render, SharedString, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    button::Button,
+    h_flex,
+    theme::ActiveTheme as _,
+    tooltip::Tooltip,
+    util::{throttle, Throttle},
+    v_flex, Icon, IconName, Sizable as _,
+};
+
+const RAIL_WIDTH: Pixels = px(48.);
+const DEFAULT_WIDTH: Pixels = px(240.);
+const MIN_WIDTH: Pixels = px(160.);
+const MAX_WIDTH: Pixels = px(480.);
+
+/// The state of the sidebar to persist, and restore by [`Sidebar::load`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SidebarState {
+    pub width: Pixels,
+    pub collapsed: bool,
+}
+
+impl Default for SidebarState {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_WIDTH,
+            collapsed: false,
+        }
+    }
+}
+
+pub struct SidebarItem {
+    label: SharedString,
+    icon: IconName,
+}
+
+impl SidebarItem {
+    pub fn new(label: impl Into<SharedString>, icon: IconName) -> Self {
+        Self {
+            label: label.into(),
+            icon,
+        }
+    }
+}
+
+pub enum SidebarEvent {
+    /// The item at the index is clicked.
+    Select(usize),
+    /// The width or the collapsed state is changed, save it to restore the sidebar later.
+    StateChanged(SidebarState),
+}
+
+#[derive(Clone)]
+struct DragSidebar(EntityId);
+
+impl Render for DragSidebar {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+    }
+}
+
+pub struct Sidebar {
+    focus_handle: FocusHandle,
+    items: Vec<SidebarItem>,
+    selected_ix: Option<usize>,
+    state: SidebarState,
+    expand_on_hover: bool,
+    hovered: bool,
+    /// Emit the state at most every 200ms while resizing, to persist it.
+    resize_state_changed: Throttle<Self, SidebarState>,
+}
+
+impl Sidebar {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: Vec::new(),
+            selected_ix: None,
+            state: SidebarState::default(),
+            expand_on_hover: false,
+            hovered: false,
+            resize_state_changed: throttle(Duration::from_millis(200), |_, state, cx| {
+                cx.emit(SidebarEvent::StateChanged(state))
+            }),
+        }
+    }
+
+    pub fn item(mut self, item: SidebarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Temporarily expand the collapsed sidebar over the content when hovered, default is false.
+    pub fn expand_on_hover(mut self, expand_on_hover: bool) -> Self {
+        self.expand_on_hover = expand_on_hover;
+        self
+    }
+
+    /// Restore the sidebar from the persisted state.
+    pub fn load(mut self, state: SidebarState) -> Self {
+        self.state = SidebarState {
+            width: state.width.max(MIN_WIDTH).min(MAX_WIDTH),
+            collapsed: state.collapsed,
+        };
+        self
+    }
+
+    /// Return the current state to persist.
+    pub fn state(&self) -> SidebarState {
+        self.state
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_ix
+    }
+
+    pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.selected_ix = ix;
+        cx.notify();
+    }
+
+    pub fn collapsed(&self) -> bool {
+        self.state.collapsed
+    }
+
+    pub fn set_collapsed(&mut self, collapsed: bool, cx: &mut ViewContext<Self>) {
+        if self.state.collapsed == collapsed {
+            return;
+        }
+
+        self.state.collapsed = collapsed;
+        self.hovered = false;
+        cx.emit(SidebarEvent::StateChanged(self.state));
+        cx.notify();
+    }
+
+    pub fn toggle_collapsed(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_collapsed(!self.state.collapsed, cx);
+    }
+
+    fn resize(&mut self, width: Pixels, cx: &mut ViewContext<Self>) {
+        let width = width.max(MIN_WIDTH).min(MAX_WIDTH).floor();
+        if self.state.width == width {
+            return;
+        }
+
+        self.state.width = width;
+        self.resize_state_changed.call(self.state, cx);
+        cx.notify();
+    }
+
+    /// Return true to render the full sidebar, false to render the rail.
+    fn is_expanded(&self) -> bool {
+        !self.state.collapsed || (self.expand_on_hover && self.hovered)
+    }
+
+    fn render_items(&self, expanded: bool, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .id("sidebar-items")
+            .flex_1()
+            .gap_1()
+            .p_2()
+            .overflow_y_scroll()
+            .children(self.items.iter().enumerate().map(|(ix, item)| {
+                let label = item.label.clone();
+                let selected = self.selected_ix == Some(ix);
+
+                h_flex()
+                    .id(ix)
+                    .h_8()
+                    .gap_2()
+                    .px_2()
+                    .rounded_md()
+                    .text_sm()
+                    .cursor_pointer()
+                    .when(!expanded, |this| this.justify_center())
+                    .when(selected, |this| {
+                        this.bg(cx.theme().accent)
+                            .text_color(cx.theme().accent_foreground)
+                    })
+                    .hover(|this| this.bg(cx.theme().accent))
+                    .child(Icon::new(item.icon.clone()).small())
+                    .map(|this| {
+                        if expanded {
+                            this.child(
+                                div()
+                                    .overflow_hidden()
+                                    .text_ellipsis()
+                                    .whitespace_nowrap()
+                                    .child(label),
+                            )
+                        } else {
+                            this.tooltip(move |cx| Tooltip::new(label.clone(), cx))
+                        }
+                    })
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.set_selected_index(Some(ix), cx);
+                        cx.emit(SidebarEvent::Select(ix));
+                    }))
+            }))
+    }
+
+    fn render_resize_handle(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entity_id = cx.entity_id();
+
+        div()
+            .id("sidebar-resize-handle")
+            .group("sidebar-resize-handle")
+            .occlude()
+            .absolute()
+            .top_0()
+            .map(|this| {
+                if cx.theme().touch_mode {
+                    this.right(px(-12.)).w(px(24.))
+                } else {
+                    this.right(px(-4.)).w(px(8.))
+                }
+            })
+            .h_full()
+            .cursor_col_resize()
+            .child(
+                div()
+                    .mx_auto()
+                    .h_full()
+                    .w(px(1.))
+                    .group_hover("sidebar-resize-handle", |this| {
+                        this.bg(cx.theme().resize_handle_hover)
+                    }),
+            )
+            .on_drag(DragSidebar(entity_id), |drag, cx| {
+                cx.stop_propagation();
+                cx.new_view(|_| drag.clone())
+            })
+    }
+}
+
+impl EventEmitter<SidebarEvent> for Sidebar {}
+
+impl FocusableView for Sidebar {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Sidebar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entity_id = cx.entity_id();
+        let collapsed = self.state.collapsed;
+        let expanded = self.is_expanded();
+        let width = if expanded {
+            self.state.width
+        } else {
+            RAIL_WIDTH
+        };
+
+        // The rail keeps its width in the layout, and the hover expanded sidebar floats over the content.
+        div()
+            .id("sidebar")
+            .track_focus(&self.focus_handle)
+            .relative()
+            .flex_shrink_0()
+            .h_full()
+            .w(if collapsed { RAIL_WIDTH } else { width })
+            .on_hover(cx.listener(|this, hovered: &bool, cx| {
+                if this.hovered != *hovered {
+                    this.hovered = *hovered;
+                    cx.notify();
+                }
+            }))
+            .on_drag_move(
+                cx.listener(move |this, e: &DragMoveEvent<DragSidebar>, cx| {
+                    if e.drag(cx).0 != entity_id {
+                        return;
+                    }
+
+                    this.resize(e.event.position.x - e.bounds.left(), cx);
+                }),
+            )
+            .child(
+                v_flex()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .h_full()
+                    .w(width)
+                    .bg(cx.theme().background)
+                    .border_r_1()
+                    .border_color(cx.theme().border)
+                    .when(collapsed && expanded, |this| this.shadow_lg())
+                    .child(self.render_items(expanded, cx))
+                    .child(
+                        h_flex()
+                            .p_2()
+                            .when(!expanded, |this| this.justify_center())
+                            .when(expanded, |this| this.justify_end())
+                            .child(
+                                Button::new("sidebar-toggle", cx)
+                                    .ghost()
+                                    .small()
+                                    .icon(if collapsed {
+                                        IconName::ChevronRight
+                                    } else {
+                                        IconName::ChevronLeft
+                                    })
+                                    .on_click(cx.listener(|this, _, cx| this.toggle_collapsed(cx))),
+                            ),
+                    )
+                    .when(!collapsed, |this| this.child(self.render_resize_handle(cx))),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::SidebarState;
+
+    #[test]
+    fn test_sidebar_state() {
+        let state = SidebarState {
+            width: px(300.),
+            collapsed: true,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<SidebarState>(&json).unwrap(), state);
+    }
+}
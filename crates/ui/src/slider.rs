@@ -1,11 +1,14 @@
 use crate::{
+    a11y::{A11yNode, Accessible, Role},
     theme::{ActiveTheme, Colorize},
     tooltip::Tooltip,
+    Disableable,
 };
 use gpui::{
-    canvas, div, px, relative, Axis, Bounds, DragMoveEvent, EntityId, EventEmitter,
-    InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _, Pixels,
-    Point, Render, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
+    canvas, div, prelude::FluentBuilder as _, px, relative, Axis, Bounds, DragMoveEvent, EntityId,
+    EventEmitter, InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _,
+    Pixels, Point, Render, SharedString, StatefulInteractiveElement as _, Styled, ViewContext,
+    VisualContext as _,
 };
 
 #[derive(Clone, Render)]
@@ -23,6 +26,9 @@ pub struct Slider {
     step: f32,
     value: f32,
     bounds: Bounds<Pixels>,
+    disabled: bool,
+    disabled_reason: Option<SharedString>,
+    a11y_name: Option<SharedString>,
 }
 
 impl Slider {
@@ -34,6 +40,9 @@ impl Slider {
             step: 1.0,
             value: 0.0,
             bounds: Bounds::default(),
+            disabled: false,
+            disabled_reason: None,
+            a11y_name: None,
         }
     }
 
@@ -65,6 +74,18 @@ impl Slider {
         self
     }
 
+    /// Set the reason to show as tooltip when the slider is disabled.
+    pub fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+
+    /// Set the name to announce by the assistive technologies, e.g.: "Volume".
+    pub fn a11y_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.a11y_name = Some(name.into());
+        self
+    }
+
     /// Set the value of the slider.
     pub fn set_value(&mut self, value: f32, cx: &mut gpui::ViewContext<Self>) {
         self.value = value;
@@ -121,22 +142,25 @@ impl Slider {
 
         div()
             .id("slider-thumb")
-            .on_drag(DragThumb(entity_id), |drag, cx| {
-                cx.stop_propagation();
-                cx.new_view(|_| drag.clone())
-            })
-            .on_drag_move(cx.listener(
-                move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
-                    DragThumb(id) => {
-                        if *id != entity_id {
-                            return;
+            .when(!self.disabled, |this| {
+                this.on_drag(DragThumb(entity_id), |drag, cx| {
+                    cx.stop_propagation();
+                    cx.new_view(|_| drag.clone())
+                })
+                .on_drag_move(cx.listener(move |view, e: &DragMoveEvent<DragThumb>, cx| {
+                    match e.drag(cx) {
+                        DragThumb(id) => {
+                            if *id != entity_id {
+                                return;
+                            }
+
+                            // set value by mouse position
+                            view.update_value_by_position(e.event.position, cx)
                         }
-
-                        // set value by mouse position
-                        view.update_value_by_position(e.event.position, cx)
                     }
-                },
-            ))
+                }))
+                .tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+            })
             .absolute()
             .top(px(-5.))
             .left(relative(self.relative_value()))
@@ -147,22 +171,47 @@ impl Slider {
             .border_color(cx.theme().slider_bar.opacity(0.9))
             .shadow_md()
             .bg(cx.theme().slider_thumb)
-            .tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+            .when(self.disabled, |this| this.bg(cx.theme().muted))
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut gpui::ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
         self.update_value_by_position(event.position, cx);
     }
 }
 
 impl EventEmitter<SliderEvent> for Slider {}
 
+impl Accessible for Slider {
+    fn a11y_node(&self) -> A11yNode {
+        A11yNode::new(Role::Slider)
+            .name_opt(self.a11y_name.clone())
+            .disabled(self.disabled)
+            .value(self.value, self.min, self.max)
+    }
+}
+
+impl Disableable for Slider {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl Render for Slider {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         div()
             .id("slider")
             .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
             .h_5()
+            .when(self.disabled, |this| this.cursor_not_allowed().opacity(0.5))
+            .when_some(
+                self.disabled_reason.clone().filter(|_| self.disabled),
+                |this, reason| this.tooltip(move |cx| Tooltip::new(reason.clone(), cx)),
+            )
             .child(
                 div()
                     .id("slider-bar")
@@ -171,7 +220,9 @@ impl Render for Slider {
                     .my_1p5()
                     .h_1p5()
                     .bg(cx.theme().slider_bar.opacity(0.2))
-                    .active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                    .when(!self.disabled, |this| {
+                        this.active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                    })
                     .rounded(px(3.))
                     .child(
                         div()
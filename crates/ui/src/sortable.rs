@@ -0,0 +1,241 @@
+//! Drag to reorder the items of a collection.
+//!
+//! ```ignore
+//! Sortable::new("tags")
+//!     .horizontal()
+//!     .children(tags.iter().map(|tag| Tag::new(tag)))
+//!     .on_move(cx.listener(|this, event: &SortableMoved, cx| {
+//!         event.apply(&mut this.tags);
+//!         cx.notify();
+//!     }))
+//! ```
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Axis, Div, ElementId, InteractiveElement,
+    IntoElement, ParentElement, Render, RenderOnce, StatefulInteractiveElement as _, Styled,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable as _};
+
+/// The event of an item is moved in the Sortable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortableMoved {
+    /// The index of the item before moved.
+    pub from: usize,
+    /// The index of the item after moved.
+    pub to: usize,
+}
+
+impl SortableMoved {
+    /// Move the item in the `items` as the event.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from >= items.len() || self.from == self.to {
+            return;
+        }
+
+        let item = items.remove(self.from);
+        items.insert(self.to.min(items.len()), item);
+    }
+}
+
+/// The dragging item of the Sortable.
+#[derive(Clone)]
+pub struct DragSortItem {
+    sortable_id: ElementId,
+    ix: usize,
+}
+
+impl Render for DragSortItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .p_1()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .bg(cx.theme().background)
+            .shadow_md()
+            .child(Icon::new(IconName::EllipsisVertical).small())
+    }
+}
+
+/// The handle to drag the item in the Sortable, use it with `Sortable::use_handle`.
+#[derive(IntoElement)]
+pub struct DragHandle {
+    sortable_id: ElementId,
+    ix: usize,
+}
+
+impl DragHandle {
+    /// Create a handle for the item at `ix` in the Sortable with the `sortable_id`.
+    pub fn new(sortable_id: impl Into<ElementId>, ix: usize) -> Self {
+        Self {
+            sortable_id: sortable_id.into(),
+            ix,
+        }
+    }
+}
+
+impl RenderOnce for DragHandle {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        div()
+            .id(("drag-handle", self.ix))
+            .flex_shrink_0()
+            .cursor_grab()
+            .text_color(cx.theme().muted_foreground)
+            .child(Icon::new(IconName::EllipsisVertical).small())
+            .on_drag(
+                DragSortItem {
+                    sortable_id: self.sortable_id,
+                    ix: self.ix,
+                },
+                |drag, cx| {
+                    cx.stop_propagation();
+                    cx.new_view(|_| drag.clone())
+                },
+            )
+    }
+}
+
+/// A container to drag to reorder its children, it shows an insertion indicator when dragging over.
+#[derive(IntoElement)]
+pub struct Sortable {
+    id: ElementId,
+    base: Div,
+    axis: Axis,
+    use_handle: bool,
+    children: SmallVec<[AnyElement; 4]>,
+    on_move: Option<Rc<dyn Fn(&SortableMoved, &mut WindowContext)>>,
+}
+
+impl Sortable {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            axis: Axis::Vertical,
+            use_handle: false,
+            children: SmallVec::new(),
+            on_move: None,
+        }
+    }
+
+    /// Layout the children horizontally, default is vertical.
+    pub fn horizontal(mut self) -> Self {
+        self.axis = Axis::Horizontal;
+        self
+    }
+
+    /// Only the `DragHandle` in the children can start dragging, default the whole child is draggable.
+    pub fn use_handle(mut self) -> Self {
+        self.use_handle = true;
+        self
+    }
+
+    /// Set the handler when an item is dropped to a new position.
+    pub fn on_move(
+        mut self,
+        handler: impl Fn(&SortableMoved, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_move = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Styled for Sortable {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Sortable {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl RenderOnce for Sortable {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let drag_border = cx.theme().drag_border;
+        let axis = self.axis;
+        let sortable_id = self.id.clone();
+
+        let items = self.children.into_iter().enumerate().map(|(ix, child)| {
+            let drop_id = sortable_id.clone();
+            let over_id = sortable_id.clone();
+            let on_move = self.on_move.clone();
+
+            div()
+                .id(ix)
+                .when(!self.use_handle, |this| {
+                    this.on_drag(
+                        DragSortItem {
+                            sortable_id: sortable_id.clone(),
+                            ix,
+                        },
+                        |drag, cx| {
+                            cx.stop_propagation();
+                            cx.new_view(|_| drag.clone())
+                        },
+                    )
+                })
+                .drag_over::<DragSortItem>(move |this, drag, _| {
+                    if drag.sortable_id != over_id || drag.ix == ix {
+                        return this;
+                    }
+
+                    // Show the indicator at the side where the item will be inserted.
+                    let this = this.border_color(drag_border);
+                    match (axis, drag.ix < ix) {
+                        (Axis::Vertical, true) => this.border_b(px(2.)),
+                        (Axis::Vertical, false) => this.border_t(px(2.)),
+                        (Axis::Horizontal, true) => this.border_r(px(2.)),
+                        (Axis::Horizontal, false) => this.border_l(px(2.)),
+                    }
+                })
+                .on_drop(move |drag: &DragSortItem, cx| {
+                    if drag.sortable_id != drop_id || drag.ix == ix {
+                        return;
+                    }
+
+                    if let Some(on_move) = on_move.as_ref() {
+                        on_move(
+                            &SortableMoved {
+                                from: drag.ix,
+                                to: ix,
+                            },
+                            cx,
+                        );
+                    }
+                })
+                .child(child)
+        });
+
+        let container = match axis {
+            Axis::Vertical => v_flex(),
+            Axis::Horizontal => h_flex(),
+        };
+
+        self.base.id(self.id).child(container.children(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortableMoved;
+
+    #[test]
+    fn test_apply_moved() {
+        let mut items = vec!["a", "b", "c", "d"];
+        SortableMoved { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, vec!["b", "c", "a", "d"]);
+
+        SortableMoved { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, vec!["d", "b", "c", "a"]);
+
+        SortableMoved { from: 10, to: 0 }.apply(&mut items);
+        assert_eq!(items, vec!["d", "b", "c", "a"]);
+    }
+}
@@ -1,8 +1,10 @@
+use smallvec::smallvec;
 use std::fmt::{self, Display, Formatter};
 
 use crate::{
+    is_focus_visible,
     scroll::{Scrollable, ScrollbarAxis},
-    theme::{ActiveTheme, Colorize},
+    theme::{box_shadow, ActiveTheme, Colorize},
 };
 use gpui::{
     div, px, rems, Axis, Div, Element, EntityId, Fill, FocusHandle, Pixels, Styled, WindowContext,
@@ -102,6 +104,16 @@ pub trait StyledExt: Styled + Sized {
         self.border_color(cx.theme().ring)
     }
 
+    /// Render a 2px ring with the theme focus ring color,
+    /// when the element is focused by keyboard (not by mouse).
+    fn focus_ring(self, focus_handle: &FocusHandle, cx: &WindowContext) -> Self {
+        if is_focus_visible(focus_handle, cx) {
+            self.shadow(smallvec![box_shadow(0., 0., 0., 2., cx.theme().focus_ring)])
+        } else {
+            self
+        }
+    }
+
     /// Wraps the element in a ScrollView.
     ///
     /// Current this is only have a vertical scrollbar.
@@ -1,9 +1,9 @@
 use std::{hash::Hash, ops::Deref, sync::Arc};
 
 use gpui::{
-    px, size, AppContext, Asset, Bounds, Element, Hitbox, ImageCacheError, InteractiveElement,
-    Interactivity, IntoElement, IsZero, Pixels, RenderImage, SharedString, Size, StyleRefinement,
-    Styled, WindowContext,
+    px, size, AppContext, Asset, Bounds, Element, Hitbox, Hsla, ImageCacheError,
+    InteractiveElement, Interactivity, IntoElement, IsZero, Pixels, RenderImage, Rgba,
+    SharedString, Size, StyleRefinement, Styled, WindowContext,
 };
 use image::Frame;
 use smallvec::SmallVec;
@@ -48,6 +48,8 @@ impl Clone for SvgImg {
             interactivity: Interactivity::default(),
             source: self.source.clone(),
             size: self.size,
+            tint: self.tint,
+            scale_to_fit: self.scale_to_fit,
         }
     }
 }
@@ -57,13 +59,22 @@ enum Image {}
 #[derive(Debug, Clone)]
 struct ImageSource {
     source: SvgSource,
+    /// The size in device pixels to rasterize the svg.
     size: Size<Pixels>,
+    tint: Option<Rgba>,
 }
 
 impl Hash for ImageSource {
-    /// Hash to to control the Asset cache
+    /// Hash to to control the Asset cache, the image is cached per source, size and tint color.
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.source.hash(state);
+        self.size.width.0.to_bits().hash(state);
+        self.size.height.0.to_bits().hash(state);
+        if let Some(tint) = self.tint {
+            [tint.r, tint.g, tint.b, tint.a]
+                .map(f32::to_bits)
+                .hash(state);
+        }
     }
 }
 
@@ -75,18 +86,13 @@ impl Asset for Image {
         source: Self::Source,
         cx: &mut AppContext,
     ) -> impl std::future::Future<Output = Self::Output> + Send + 'static {
-        let scale = 2.;
         let asset_source = cx.asset_source().clone();
 
         async move {
-            let size = source.size;
+            let size = source.size.map(|size| size.ceil());
             if size.width.is_zero() || size.height.is_zero() {
                 return Err(usvg::Error::InvalidSize.into());
             }
-            let size = Size {
-                width: (size.width * 2).ceil(),
-                height: (size.height * scale).ceil(),
-            };
 
             let bytes = match source.source {
                 SvgSource::Data(data) => data,
@@ -126,6 +132,17 @@ impl Asset for Image {
                 pixel.swap(0, 2);
             }
 
+            // Use the alpha as a mask to fill the tint color, the pixels are premultiplied.
+            if let Some(tint) = source.tint {
+                for pixel in buffer.chunks_exact_mut(4) {
+                    let alpha = pixel[3] as f32 * tint.a;
+                    pixel[0] = (tint.b * alpha).round() as u8;
+                    pixel[1] = (tint.g * alpha).round() as u8;
+                    pixel[2] = (tint.r * alpha).round() as u8;
+                    pixel[3] = alpha.round() as u8;
+                }
+            }
+
             Ok(Arc::new(RenderImage::new(SmallVec::from_elem(
                 Frame::new(buffer),
                 1,
@@ -143,8 +160,13 @@ pub struct SvgImg {
     interactivity: Interactivity,
     source: Option<SvgSource>,
     size: Size<Pixels>,
+    tint: Option<Rgba>,
+    scale_to_fit: bool,
 }
 
+/// The scale to rasterize the svg image at its source size, see [`SvgImg::scale_to_fit`].
+const SOURCE_SCALE: f32 = 2.;
+
 impl SvgImg {
     /// Create a new svg image element.
     ///
@@ -154,6 +176,8 @@ impl SvgImg {
             interactivity: Interactivity::default(),
             source: None,
             size: Size::default(),
+            tint: None,
+            scale_to_fit: false,
         }
     }
 
@@ -171,6 +195,23 @@ impl SvgImg {
         self.size = size(width.into(), height.into());
         self
     }
+
+    /// Scale the svg image to fit the bounds, and rasterize it at the painted size multiplied by
+    /// the scale factor of the window, e.g.: for the icons that are rendered in any size.
+    ///
+    /// By default, it is rasterized at double of the source size, and not scaled up.
+    #[must_use]
+    pub fn scale_to_fit(mut self) -> Self {
+        self.scale_to_fit = true;
+        self
+    }
+
+    /// Fill the svg image with the color by using its alpha as a mask, e.g.: for monochrome icons.
+    #[must_use]
+    pub fn tint(mut self, color: impl Into<Hsla>) -> Self {
+        self.tint = Some(color.into().to_rgb());
+        self
+    }
 }
 
 impl IntoElement for SvgImg {
@@ -221,51 +262,60 @@ impl Element for SvgImg {
         cx: &mut WindowContext,
     ) {
         let source = self.source.clone();
+        let tint = self.tint;
+        let scale_to_fit = self.scale_to_fit;
 
         self.interactivity
             .paint(global_id, bounds, hitbox.as_ref(), cx, |_style, cx| {
+                let Some(source) = source else {
+                    return;
+                };
                 let size = self.size;
+                if size.width.is_zero() || size.height.is_zero() {
+                    return;
+                }
 
-                let data = if let Some(source) = source {
-                    match cx.use_asset::<Image>(&ImageSource { source, size }) {
-                        Some(Ok(data)) => Some(data),
-                        _ => None,
-                    }
+                // To calculate the ratio of the original image size to the container bounds size.
+                // Scale by shortest side (width or height) to get a fit image.
+                // And center the image in the container bounds.
+                let ratio = if bounds.size.width < bounds.size.height {
+                    bounds.size.width / size.width
                 } else {
-                    None
+                    bounds.size.height / size.height
                 };
+                let ratio = if scale_to_fit { ratio } else { ratio.min(1.0) };
 
-                if let Some(data) = data {
-                    // To calculate the ratio of the original image size to the container bounds size.
-                    // Scale by shortest side (width or height) to get a fit image.
-                    // And center the image in the container bounds.
-                    let ratio = if bounds.size.width < bounds.size.height {
-                        bounds.size.width / size.width
-                    } else {
-                        bounds.size.height / size.height
-                    };
-
-                    let ratio = ratio.min(1.0);
-
-                    let new_size = gpui::Size {
-                        width: size.width * ratio,
-                        height: size.height * ratio,
-                    };
-                    let new_origin = gpui::Point {
-                        x: bounds.origin.x + px(((bounds.size.width - new_size.width) / 2.).into()),
-                        y: bounds.origin.y
-                            + px(((bounds.size.height - new_size.height) / 2.).into()),
-                    };
-
-                    let img_bounds = Bounds {
-                        origin: new_origin.map(|origin| origin.floor()),
-                        size: new_size.map(|size| size.ceil()),
-                    };
-
-                    match cx.paint_image(img_bounds, px(0.).into(), data, 0, false) {
-                        Ok(_) => {}
-                        Err(err) => eprintln!("failed to paint svg image: {:?}", err),
-                    }
+                let new_size = gpui::Size {
+                    width: size.width * ratio,
+                    height: size.height * ratio,
+                };
+                let new_origin = gpui::Point {
+                    x: bounds.origin.x + px(((bounds.size.width - new_size.width) / 2.).into()),
+                    y: bounds.origin.y + px(((bounds.size.height - new_size.height) / 2.).into()),
+                };
+
+                let img_bounds = Bounds {
+                    origin: new_origin.map(|origin| origin.floor()),
+                    size: new_size.map(|size| size.ceil()),
+                };
+
+                let raster_size = if scale_to_fit {
+                    img_bounds.size.map(|size| size * cx.scale_factor())
+                } else {
+                    size.map(|size| size * SOURCE_SCALE)
+                };
+                let data = match cx.use_asset::<Image>(&ImageSource {
+                    source,
+                    size: raster_size,
+                    tint,
+                }) {
+                    Some(Ok(data)) => data,
+                    _ => return,
+                };
+
+                match cx.paint_image(img_bounds, px(0.).into(), data, 0, false) {
+                    Ok(_) => {}
+                    Err(err) => eprintln!("failed to paint svg image: {:?}", err),
                 }
             })
     }
@@ -1,14 +1,15 @@
 use std::time::Duration;
 
 use crate::{
+    animation::motion_duration,
     h_flex,
     theme::{ActiveTheme, Colorize},
-    Disableable, Sizable, Size,
+    Disableable, Sizable, Size, StyledExt as _,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, Div, ElementId,
-    InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString, Stateful,
-    Styled as _, WindowContext,
+    FocusHandle, InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString,
+    Stateful, Styled as _, WindowContext,
 };
 
 type OnClick = Box<dyn Fn(&bool, &mut WindowContext) + 'static>;
@@ -32,6 +33,7 @@ pub struct Switch {
     disabled: bool,
     label: Option<SharedString>,
     label_side: LabelSide,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<OnClick>,
     size: Size,
 }
@@ -45,6 +47,7 @@ impl Switch {
             checked: false,
             disabled: false,
             label: None,
+            focus_handle: None,
             on_click: None,
             label_side: LabelSide::Right,
             size: Size::Medium,
@@ -70,6 +73,12 @@ impl Switch {
         self.label_side = label_side;
         self
     }
+
+    /// Set the focus handle to track the focus, and show the focus ring when it focused by keyboard.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
 }
 
 impl Sizable for Switch {
@@ -111,7 +120,7 @@ impl RenderOnce for Switch {
         };
         let inset = px(2.);
 
-        h_flex()
+        let switch = h_flex()
             .id(self.id)
             .items_center()
             .gap_2()
@@ -128,6 +137,9 @@ impl RenderOnce for Switch {
                     .border_color(theme.transparent)
                     .bg(bg)
                     .when(!self.disabled, |this| this.cursor_pointer())
+                    .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                        this.focus_ring(focus_handle, cx)
+                    })
                     .child(
                         // Switch Toggle
                         div()
@@ -136,7 +148,7 @@ impl RenderOnce for Switch {
                             .size(bar_width)
                             .with_animation(
                                 ElementId::NamedInteger("move".into(), checked as usize),
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                Animation::new(motion_duration(Duration::from_secs_f64(0.15), cx)),
                                 move |this, delta| {
                                     let max_x = bg_width - bar_width - inset * 2;
                                     let x = if checked {
@@ -163,6 +175,11 @@ impl RenderOnce for Switch {
                         on_click(&!self.checked, cx);
                     })
                 },
-            )
+            );
+
+        match self.focus_handle {
+            Some(focus_handle) => switch.track_focus(&focus_handle).into_any_element(),
+            None => switch.into_any_element(),
+        }
     }
 }
@@ -1,9 +1,12 @@
+use crate::a11y::{A11yNode, Accessible, Role};
+use crate::context_menu::ContextMenuExt;
 use crate::theme::{ActiveTheme, Colorize};
-use crate::Selectable;
+use crate::{Selectable, StyledExt as _};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement as _,
-    RenderOnce, Stateful, StatefulInteractiveElement, Styled, WindowContext,
+    div, px, AnyElement, Div, ElementId, FocusHandle, InteractiveElement, IntoElement,
+    ParentElement, RenderOnce, SharedString, Stateful, StatefulInteractiveElement, Styled,
+    WindowContext,
 };
 
 #[derive(IntoElement)]
@@ -14,6 +17,8 @@ pub struct Tab {
     suffix: Option<AnyElement>,
     disabled: bool,
     selected: bool,
+    focus_handle: Option<FocusHandle>,
+    a11y_name: Option<SharedString>,
 }
 
 impl Tab {
@@ -25,6 +30,8 @@ impl Tab {
             selected: false,
             prefix: None,
             suffix: None,
+            focus_handle: None,
+            a11y_name: None,
         }
     }
 
@@ -39,6 +46,27 @@ impl Tab {
         self.suffix = Some(suffix.into());
         self
     }
+
+    /// Set the focus handle to track the focus, and show the focus ring when it focused by keyboard.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Set the name to announce by the assistive technologies, the label of the tab is an element.
+    pub fn a11y_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.a11y_name = Some(name.into());
+        self
+    }
+}
+
+impl Accessible for Tab {
+    fn a11y_node(&self) -> A11yNode {
+        A11yNode::new(Role::Tab)
+            .name_opt(self.a11y_name.clone())
+            .disabled(self.disabled)
+            .selected(self.selected)
+    }
 }
 
 impl Selectable for Tab {
@@ -56,6 +84,14 @@ impl InteractiveElement for Tab {
 
 impl StatefulInteractiveElement for Tab {}
 
+impl ParentElement for Tab {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements)
+    }
+}
+
+impl ContextMenuExt for Tab {}
+
 impl Styled for Tab {
     fn style(&mut self) -> &mut gpui::StyleRefinement {
         self.base.style()
@@ -70,7 +106,8 @@ impl RenderOnce for Tab {
             (false, false) => (cx.theme().muted_foreground, cx.theme().tab),
         };
 
-        self.base
+        let tab = self
+            .base
             .flex()
             .items_center()
             .flex_shrink_0()
@@ -78,6 +115,7 @@ impl RenderOnce for Tab {
             .overflow_hidden()
             .text_color(text_color)
             .bg(bg_color)
+            .when(cx.theme().touch_mode, |this| this.h(px(40.)).px_4())
             .border_x_1()
             .border_color(cx.theme().transparent)
             .when(self.selected, |this| this.border_color(cx.theme().border))
@@ -88,5 +126,13 @@ impl RenderOnce for Tab {
             })
             .child(div().text_ellipsis().child(self.label))
             .when_some(self.suffix, |this, suffix| this.child(suffix))
+            .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                this.focus_ring(focus_handle, cx)
+            });
+
+        match self.focus_handle {
+            Some(focus_handle) => tab.track_focus(&focus_handle).into_any_element(),
+            None => tab.into_any_element(),
+        }
     }
 }
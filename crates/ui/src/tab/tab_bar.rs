@@ -1,11 +1,12 @@
-use crate::h_flex;
+use crate::scroll::horizontal_wheel_delta;
 use crate::theme::ActiveTheme;
+use crate::{h_flex, FocusCycle};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     div, AnyElement, Div, ElementId, IntoElement, ParentElement, RenderOnce, ScrollHandle,
     StatefulInteractiveElement as _, Styled, WindowContext,
 };
-use gpui::{px, InteractiveElement};
+use gpui::{px, InteractiveElement, IsZero as _, ScrollWheelEvent};
 use smallvec::SmallVec;
 
 #[derive(IntoElement)]
@@ -15,6 +16,7 @@ pub struct TabBar {
     scroll_handle: ScrollHandle,
     prefix: Option<AnyElement>,
     suffix: Option<AnyElement>,
+    focus_cycle: Option<FocusCycle>,
     children: SmallVec<[AnyElement; 2]>,
 }
 
@@ -27,6 +29,7 @@ impl TabBar {
             scroll_handle: ScrollHandle::new(),
             prefix: None,
             suffix: None,
+            focus_cycle: None,
         }
     }
 
@@ -47,6 +50,14 @@ impl TabBar {
         self.suffix = Some(suffix.into_any_element());
         self
     }
+
+    /// Use the FocusCycle to move the focus between the tabs by arrow keys.
+    ///
+    /// The tabs should use the [`FocusCycle::handle`] by the same order.
+    pub fn focus_cycle(mut self, focus_cycle: &FocusCycle) -> Self {
+        self.focus_cycle = Some(focus_cycle.clone());
+        self
+    }
 }
 
 impl ParentElement for TabBar {
@@ -61,8 +72,34 @@ impl Styled for TabBar {
     }
 }
 
+impl TabBar {
+    /// Scroll the tabs horizontally by the vertical wheel, the tab bar has no vertical scrolling.
+    fn on_scroll_wheel(
+        scroll_handle: &ScrollHandle,
+        event: &ScrollWheelEvent,
+        cx: &mut WindowContext,
+    ) {
+        let line_height = cx.line_height();
+        let mut delta = horizontal_wheel_delta(event, line_height);
+        if delta.is_zero() {
+            delta = event.delta.pixel_delta(line_height).y;
+        }
+        if delta.is_zero() {
+            return;
+        }
+
+        let mut offset = scroll_handle.offset();
+        offset.x = (offset.x + delta).min(px(0.));
+        scroll_handle.set_offset(offset);
+        cx.stop_propagation();
+        cx.refresh();
+    }
+}
+
 impl RenderOnce for TabBar {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let scroll_handle = self.scroll_handle.clone();
+
         self.base
             .id(self.id)
             .group("tab-bar")
@@ -88,6 +125,10 @@ impl RenderOnce for TabBar {
                     .flex_grow()
                     .overflow_x_scroll()
                     .track_scroll(&self.scroll_handle)
+                    .on_scroll_wheel(move |event, cx| {
+                        Self::on_scroll_wheel(&scroll_handle, event, cx)
+                    })
+                    .when_some(self.focus_cycle, |this, focus_cycle| focus_cycle.bind(this))
                     .children(self.children),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix))
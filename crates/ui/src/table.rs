@@ -1,18 +1,36 @@
-use std::{cell::Cell, ops::Range, rc::Rc};
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    ops::Range,
+    rc::Rc,
+};
 
 use crate::{
+    button::Button,
     h_flex,
+    popover::Popover,
     scroll::{ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
+    skeleton::Skeleton,
     theme::{ActiveTheme, Colorize},
-    v_flex, Icon, IconName,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, AppContext, Bounds, Div,
-    DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
-    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement as _, Styled, UniformListScrollHandle, ViewContext,
-    VisualContext as _, WindowContext,
+    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, AnchorCorner, AppContext,
+    Bounds, ClipboardItem, Div, DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels,
+    Point, Render, ScrollHandle, SharedString, Stateful, StatefulInteractiveElement as _, Styled,
+    UniformListScrollHandle, ViewContext, VisualContext as _, WindowContext,
 };
+use rust_i18n::t;
+
+mod column;
+mod filter;
+mod paged;
+
+pub use column::*;
+use filter::ColFilterPanel;
+pub use filter::{ColFilter, FilterPredicate};
+pub use paged::{PagedRows, PagedSource};
 
 actions!(
     table,
@@ -21,7 +39,14 @@ actions!(
         SelectPrev,
         SelectNext,
         SelectPrevColumn,
-        SelectNextColumn
+        SelectNextColumn,
+        SelectFirst,
+        SelectLast,
+        SelectPageUp,
+        SelectPageDown,
+        Confirm,
+        ToggleRowDetail,
+        Copy
     ]
 );
 
@@ -33,6 +58,16 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("down", SelectNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("pageup", SelectPageUp, context),
+        KeyBinding::new("pagedown", SelectPageDown, context),
+        KeyBinding::new("enter", Confirm, context),
+        KeyBinding::new("space", ToggleRowDetail, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", Copy, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", Copy, context),
     ]);
 }
 
@@ -77,20 +112,105 @@ impl Render for DragCol {
     }
 }
 
+/// Drag the row to reorder the rows, see [`TableDelegate::can_move_row`].
+#[derive(Clone)]
+pub(crate) struct DragRow {
+    pub(crate) entity_id: EntityId,
+    /// The text of the first cell of the row.
+    pub(crate) label: SharedString,
+    pub(crate) row_ix: usize,
+}
+
+impl Render for DragRow {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .px_4()
+            .py_1()
+            .bg(cx.theme().table)
+            .border_1()
+            .border_color(cx.theme().border)
+            .shadow_md()
+            .min_w(px(100.))
+            .max_w(px(450.))
+            .child(self.label.clone())
+    }
+}
+
 #[derive(Clone, Render)]
 pub struct ResizeCol(pub (EntityId, usize));
 
+/// The aggregate of a column to show in the footer of the table, see [`TableDelegate::col_aggregate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColAggregate {
+    /// The sum of the values.
+    Sum,
+    /// The average of the values.
+    Avg,
+    /// The number of the values.
+    Count,
+}
+
+impl ColAggregate {
+    /// Aggregate the `values`, `None` for the average of no values.
+    pub fn compute(&self, values: impl IntoIterator<Item = f64>) -> Option<f64> {
+        let (count, sum) = values
+            .into_iter()
+            .fold((0usize, 0.), |(count, sum), value| (count + 1, sum + value));
+
+        match self {
+            Self::Sum => Some(sum),
+            Self::Avg if count == 0 => None,
+            Self::Avg => Some(sum / count as f64),
+            Self::Count => Some(count as f64),
+        }
+    }
+
+    fn label(&self) -> SharedString {
+        match self {
+            Self::Sum => t!("Table.Sum"),
+            Self::Avg => t!("Table.Avg"),
+            Self::Count => t!("Table.Count"),
+        }
+        .into()
+    }
+}
+
+/// The aggregate and its value of each column, `None` for the columns without an aggregate.
+type Aggregates = Vec<Option<(ColAggregate, Option<f64>)>>;
+
+/// A row to display in the table, see [`TableDelegate::group_key`].
+#[derive(Debug, Clone, PartialEq)]
+enum DisplayRow {
+    /// The header of a group, with the number of the rows in the group,
+    /// the `key` is `None` for the rows without a group key.
+    Group {
+        key: Option<SharedString>,
+        count: usize,
+        collapsed: bool,
+    },
+    /// The row at the index of the delegate.
+    Row(usize),
+    /// The `slot` of the detail of the expanded row, see [`TableDelegate::detail_rows`].
+    Detail { row_ix: usize, slot: usize },
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum SelectionState {
     Column,
     Row,
+    /// The cell at the selected row and the selected column is focused.
+    Cell,
 }
 
 #[derive(Clone)]
 pub enum TableEvent {
     SelectRow(usize),
     SelectCol(usize),
+    /// The cell at the row and the column is focused.
+    FocusCell(usize, usize),
     ColWidthsChanged(Vec<Option<Pixels>>),
+    /// The row is moved from the first index to the second index, the index after it is moved.
+    RowMoved(usize, usize),
 }
 
 pub struct Table<D: TableDelegate> {
@@ -107,9 +227,29 @@ pub struct Table<D: TableDelegate> {
     selection_state: SelectionState,
     selected_row: Option<usize>,
     selected_col: Option<usize>,
+    /// The display range of the rows in the viewport, to move the selection by the page.
+    visible_range: Range<usize>,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
+    /// The filters of the columns, ordered by the column index.
+    filters: Vec<FilterPredicate>,
+
+    /// The rows clustered under the group headers and with the details of the expanded rows,
+    /// `None` if the rows are displayed in the order of the delegate.
+    display_rows: Option<Vec<DisplayRow>>,
+    /// Whether the rows of the delegate are changed, to prepare the `display_rows` and the aggregates again.
+    rows_changed: bool,
+    /// The number of the rows when the `display_rows` are prepared, the rows may be added by the delegate.
+    prepared_rows_count: usize,
+    /// The aggregates of the columns of the last render, see [`TableDelegate::col_aggregate`].
+    aggregates: Option<Option<Aggregates>>,
+    /// The collapsed groups, `None` for the group of the rows without a group key.
+    collapsed_groups: HashSet<Option<SharedString>>,
+    /// The indices of the rows that show their details.
+    expanded_rows: HashSet<usize>,
+    /// The indices of the parent rows that hide their children, see [`TableDelegate::can_nest_rows`].
+    collapsed_rows: HashSet<usize>,
 
     /// Set stripe style of the table.
     stripe: bool,
@@ -124,8 +264,34 @@ pub trait TableDelegate: Sized + 'static {
     /// Return the number of rows in the table.
     fn rows_count(&self) -> usize;
 
+    /// Return the definition of the column at the given index, to render the cells by [`TableDelegate::cell_value`].
+    ///
+    /// The name, the width and the sort of the column are default to the definition.
+    fn column(&self, col_ix: usize) -> Option<&TableColumn> {
+        None
+    }
+
+    /// Return the value of the cell at the given row and column, to render by the [`TableDelegate::column`].
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> CellValue {
+        CellValue::Empty
+    }
+
+    /// Return the text of the cell at the given row and column to copy,
+    /// default to the [`TableDelegate::cell_value`] formatted by the [`TableDelegate::column`].
+    fn cell_text(&self, row_ix: usize, col_ix: usize) -> SharedString {
+        let value = self.cell_value(row_ix, col_ix);
+        match self.column(col_ix) {
+            Some(column) => column.format(&value),
+            None => value.to_string().into(),
+        }
+    }
+
     /// Returns the name of the column at the given index.
-    fn col_name(&self, col_ix: usize) -> SharedString;
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        self.column(col_ix)
+            .map(|column| column.name.clone())
+            .unwrap_or_default()
+    }
 
     /// Returns whether the column at the given index can be resized. Default: true
     fn can_resize_col(&self, col_ix: usize) -> bool {
@@ -141,18 +307,30 @@ pub trait TableDelegate: Sized + 'static {
     /// Return None, use auto width.
     ///
     /// This is only called when the table initializes.
-    fn col_width(&self, col_ix: usize) -> Option<Pixels>;
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        self.column(col_ix).and_then(|column| column.width)
+    }
 
     /// Return the sort state of the column at the given index.
     ///
     /// This is only called when the table initializes.
     fn col_sort(&self, col_ix: usize) -> Option<ColSort> {
-        None
+        self.column(col_ix).and_then(|column| column.sort)
     }
 
     /// Perform sort on the column at the given index.
     fn perform_sort(&mut self, col_ix: usize, sort: ColSort, cx: &mut ViewContext<Table<Self>>) {}
 
+    /// Return the filter of the column at the given index, to filter it by the popover of its header.
+    fn col_filter(&self, col_ix: usize) -> Option<ColFilter> {
+        self.column(col_ix).and_then(|column| column.filter.clone())
+    }
+
+    /// Filter the rows by the `predicates` of the filtered columns, an empty list to show all the rows.
+    ///
+    /// Use [`FilterPredicate::matches`] to match the cells.
+    fn filter(&mut self, predicates: &[FilterPredicate], cx: &mut ViewContext<Table<Self>>) {}
+
     /// Render the header cell at the given column index, default to the column name.
     fn render_th(&self, col_ix: usize, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
         div().size_full().child(self.col_name(col_ix))
@@ -163,13 +341,19 @@ pub trait TableDelegate: Sized + 'static {
         h_flex()
     }
 
-    /// Render cell at the given row and column.
+    /// Render cell at the given row and column, default to render the [`TableDelegate::cell_value`]
+    /// by the [`TableDelegate::column`].
     fn render_td(
         &self,
         row_ix: usize,
         col_ix: usize,
         cx: &mut ViewContext<Table<Self>>,
-    ) -> impl IntoElement;
+    ) -> impl IntoElement {
+        match self.column(col_ix) {
+            Some(column) => column.render_cell(row_ix, self.cell_value(row_ix, col_ix), cx),
+            None => div().into_any_element(),
+        }
+    }
 
     /// Return true to enable loop selection on the table.
     ///
@@ -180,6 +364,126 @@ pub trait TableDelegate: Sized + 'static {
         true
     }
 
+    /// Return true to focus the cells instead of the rows, the arrow keys move the focused cell.
+    ///
+    /// Default: false
+    fn can_focus_cell(&self) -> bool {
+        false
+    }
+
+    /// Return true if the cell can be edited by `enter` when it is focused, see [`TableDelegate::edit_cell`].
+    fn can_edit_cell(&self, row_ix: usize, col_ix: usize) -> bool {
+        false
+    }
+
+    /// Start to edit the focused cell, e.g.: to render an input in [`TableDelegate::render_td`].
+    fn edit_cell(&mut self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Table<Self>>) {}
+
+    /// Return the aggregate of the column at the given index to show in the footer, `None` to leave it empty.
+    ///
+    /// The footer is shown if any column has an aggregate.
+    fn col_aggregate(&self, col_ix: usize) -> Option<ColAggregate> {
+        None
+    }
+
+    /// Return the numeric value of the cell at the given row and column to aggregate,
+    /// `None` to skip the cell, default to the number of the [`TableDelegate::cell_value`].
+    fn col_value(&self, row_ix: usize, col_ix: usize) -> Option<f64> {
+        self.cell_value(row_ix, col_ix).as_f64()
+    }
+
+    /// Render the footer cell at the given column index with the aggregated `value`.
+    fn render_tf(
+        &self,
+        col_ix: usize,
+        aggregate: ColAggregate,
+        value: Option<f64>,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let value = match value {
+            Some(value) if aggregate == ColAggregate::Avg => format!("{:.2}", value),
+            Some(value) => value.to_string(),
+            None => "-".to_string(),
+        };
+
+        h_flex()
+            .gap_1()
+            .child(
+                div()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(aggregate.label()),
+            )
+            .child(value)
+    }
+
+    /// Return true to cluster the rows under the collapsible group headers, by [`TableDelegate::group_key`].
+    ///
+    /// Default: false
+    fn can_group(&self) -> bool {
+        false
+    }
+
+    /// Return the key of the group of the row at the given index,
+    /// the rows with `None` are shown in the last group, with the "Ungrouped" header.
+    ///
+    /// The groups are in the order of their first rows.
+    fn group_key(&self, row_ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Render the group header of the given key, default to the key with the number of the rows.
+    fn render_group(
+        &self,
+        key: &SharedString,
+        count: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        h_flex().gap_2().child(key.clone()).child(
+            div()
+                .text_color(cx.theme().muted_foreground)
+                .child(format!("({})", count)),
+        )
+    }
+
+    /// Return true to show the rows as a tree by [`TableDelegate::row_depth`], the parent rows
+    /// indent their children in the first column and collapse them by the chevron.
+    ///
+    /// The rows must be in the depth-first order, the children follow their parent with a deeper depth.
+    /// The tree is ignored if [`TableDelegate::can_group`] is true.
+    ///
+    /// Default: false
+    fn can_nest_rows(&self) -> bool {
+        false
+    }
+
+    /// Return the depth of the row at the given index in the tree, `0` for the root rows.
+    fn row_depth(&self, row_ix: usize) -> usize {
+        0
+    }
+
+    /// Return true to expand the rows inline by the chevron in the first column, or by `space`,
+    /// to show the details of [`TableDelegate::render_row_detail`].
+    ///
+    /// Default: false
+    fn can_expand_rows(&self) -> bool {
+        false
+    }
+
+    /// Return the height of the detail of the row at the given index, in the number of the rows,
+    /// `0` if the row has no detail to expand.
+    fn detail_rows(&self, row_ix: usize) -> usize {
+        3
+    }
+
+    /// Render the detail of the expanded row, spanning all the columns.
+    fn render_row_detail(
+        &self,
+        row_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        div()
+    }
+
     /// Return true to enable column order change.
     fn can_move_col(&self, col_ix: usize) -> bool {
         false
@@ -188,6 +492,15 @@ pub trait TableDelegate: Sized + 'static {
     /// Move the column at the given `col_ix` to insert before the column at the given `to_ix`.
     fn move_col(&mut self, col_ix: usize, to_ix: usize) {}
 
+    /// Return true to reorder the row by dragging it, the row is dropped before the row under the mouse.
+    fn can_move_row(&self, row_ix: usize) -> bool {
+        false
+    }
+
+    /// Move the row at the given `row_ix` to the given `to_ix`, the index of the row after it is moved,
+    /// e.g.: remove the row and insert it at `to_ix`.
+    fn move_row(&mut self, row_ix: usize, to_ix: usize) {}
+
     /// Return a Element to show when table is empty.
     fn render_empty(&self, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
         h_flex()
@@ -221,6 +534,19 @@ pub trait TableDelegate: Sized + 'static {
     /// This is always called when the table is near the bottom,
     /// so you must check if there is more data to load or lock the loading state.
     fn load_more(&mut self, cx: &mut ViewContext<Table<Self>>) {}
+
+    /// Return false if the row at the given index is not loaded yet, it is shown as a skeleton row
+    /// and [`TableDelegate::load_rows`] is called when it is scrolled into view.
+    ///
+    /// Default: true
+    fn is_row_loaded(&self, row_ix: usize) -> bool {
+        true
+    }
+
+    /// Load the rows in the `range` that are shown but not loaded, see [`PagedRows`] to load them by pages.
+    ///
+    /// This is called on every render while the rows are not loaded, so the pending rows must be tracked.
+    fn load_rows(&mut self, range: Range<usize>, cx: &mut ViewContext<Table<Self>>) {}
 }
 
 impl<D> Table<D>
@@ -238,7 +564,16 @@ where
             selection_state: SelectionState::Row,
             selected_row: None,
             selected_col: None,
+            visible_range: 0..0,
             resizing_col: None,
+            filters: Vec::new(),
+            display_rows: None,
+            rows_changed: true,
+            prepared_rows_count: 0,
+            aggregates: None,
+            collapsed_groups: HashSet::new(),
+            expanded_rows: HashSet::new(),
+            collapsed_rows: HashSet::new(),
             bounds: Bounds::default(),
             stripe: false,
             border: true,
@@ -252,10 +587,24 @@ where
         &self.delegate
     }
 
+    /// Return the delegate to change its rows, the display rows and the aggregates are prepared again.
     pub fn delegate_mut(&mut self) -> &mut D {
+        self.refresh_rows();
         &mut self.delegate
     }
 
+    /// Prepare the display rows and the aggregates again in the next render,
+    /// e.g.: when the rows of the delegate are changed without [`Table::delegate_mut`].
+    pub fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        self.refresh_rows();
+        cx.notify();
+    }
+
+    fn refresh_rows(&mut self) {
+        self.rows_changed = true;
+        self.aggregates = None;
+    }
+
     /// Set to use stripe style of the table, default to false.
     pub fn stripe(mut self, stripe: bool) -> Self {
         self.stripe = stripe;
@@ -279,11 +628,199 @@ where
         cx.notify();
     }
 
-    fn set_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+    /// Return true if the group of the given key is collapsed.
+    pub fn is_group_collapsed(&self, key: &SharedString) -> bool {
+        self.collapsed_groups.contains(&Some(key.clone()))
+    }
+
+    /// Collapse or expand the group of the given key, see [`TableDelegate::group_key`].
+    pub fn toggle_group(&mut self, key: &SharedString, cx: &mut ViewContext<Self>) {
+        self.toggle_group_key(Some(key.clone()), cx);
+    }
+
+    fn toggle_group_key(&mut self, key: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        if !self.collapsed_groups.remove(&key) {
+            self.collapsed_groups.insert(key);
+        }
+        self.prepare_rows();
+        cx.notify();
+    }
+
+    /// Return true if the row at the given index shows its detail.
+    pub fn is_row_expanded(&self, row_ix: usize) -> bool {
+        self.expanded_rows.contains(&row_ix)
+    }
+
+    /// Expand or collapse the detail of the row at the given index, see [`TableDelegate::can_expand_rows`].
+    pub fn toggle_row_expanded(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.expanded_rows.remove(&row_ix) {
+            if !self.delegate.can_expand_rows() || self.delegate.detail_rows(row_ix) == 0 {
+                return;
+            }
+            self.expanded_rows.insert(row_ix);
+        }
+        self.prepare_rows();
+        cx.notify();
+    }
+
+    /// Return true if the row at the given index hides its children, see [`TableDelegate::can_nest_rows`].
+    pub fn is_row_collapsed(&self, row_ix: usize) -> bool {
+        self.collapsed_rows.contains(&row_ix)
+    }
+
+    /// Collapse or expand the children of the row at the given index,
+    /// the selected row is moved to the parent if it is hidden.
+    pub fn toggle_row_collapsed(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.collapsed_rows.remove(&row_ix) {
+            let children = self.children_range(row_ix);
+            if children.is_empty() {
+                return;
+            }
+            self.collapsed_rows.insert(row_ix);
+            if self.selected_row.map_or(false, |ix| children.contains(&ix)) {
+                self.set_selected_row(row_ix, cx);
+            }
+        }
+        self.prepare_rows();
+        cx.notify();
+    }
+
+    /// Return the range of the descendant rows of the row in the tree.
+    fn children_range(&self, row_ix: usize) -> Range<usize> {
+        let start = row_ix + 1;
+        if !self.delegate.can_nest_rows() {
+            return start..start;
+        }
+
+        let depth = self.delegate.row_depth(row_ix);
+        let end = (start..self.delegate.rows_count())
+            .find(|ix| self.delegate.row_depth(*ix) <= depth)
+            .unwrap_or(self.delegate.rows_count());
+        start..end
+    }
+
+    /// Return the filters of the columns, ordered by the column index.
+    pub fn filters(&self) -> &[FilterPredicate] {
+        &self.filters
+    }
+
+    /// Set the filter of the column, `None` to clear it, the rows are filtered by [`TableDelegate::filter`].
+    pub fn set_filter(
+        &mut self,
+        col_ix: usize,
+        predicate: Option<FilterPredicate>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.filters.retain(|filter| filter.col_ix() != col_ix);
+        if let Some(predicate) = predicate {
+            let ix = self
+                .filters
+                .partition_point(|filter| filter.col_ix() < predicate.col_ix());
+            self.filters.insert(ix, predicate);
+        }
+        self.perform_filter(cx);
+    }
+
+    /// Clear the filters of all the columns.
+    pub fn clear_filters(&mut self, cx: &mut ViewContext<Self>) {
+        self.filters.clear();
+        self.perform_filter(cx);
+    }
+
+    /// The rows are changed by the filters, so the selected row is cleared.
+    fn perform_filter(&mut self, cx: &mut ViewContext<Self>) {
+        let filters = self.filters.clone();
+        self.delegate_mut().filter(&filters, cx);
+        self.selected_row = None;
+        self.expanded_rows.clear();
+        self.collapsed_rows.clear();
+        if self.selection_state == SelectionState::Cell {
+            self.selection_state = SelectionState::Row;
+        }
+        self.vertical_scroll_handle.scroll_to_item(0);
+        cx.notify();
+    }
+
+    /// Cluster the rows of the delegate by their group keys if the delegate can group,
+    /// or hide the children of the collapsed rows if the rows are nested,
+    /// and insert the details after the expanded rows.
+    fn prepare_rows(&mut self) {
+        let rows_count = self.delegate.rows_count();
+        self.rows_changed = false;
+        self.prepared_rows_count = rows_count;
+        self.expanded_rows.retain(|row_ix| *row_ix < rows_count);
+        self.collapsed_rows.retain(|row_ix| *row_ix < rows_count);
+
+        let rows = if self.delegate.can_group() {
+            let keys = (0..rows_count)
+                .map(|row_ix| self.delegate.group_key(row_ix))
+                .collect();
+            Some(group_rows(keys, &self.collapsed_groups))
+        } else if self.delegate.can_nest_rows() && !self.collapsed_rows.is_empty() {
+            let depths = (0..rows_count)
+                .map(|row_ix| self.delegate.row_depth(row_ix))
+                .collect::<Vec<_>>();
+            Some(tree_rows(&depths, &self.collapsed_rows))
+        } else {
+            None
+        };
+
+        self.display_rows = if self.delegate.can_expand_rows() && !self.expanded_rows.is_empty() {
+            let rows = rows.unwrap_or_else(|| (0..rows_count).map(DisplayRow::Row).collect());
+            Some(insert_detail_rows(rows, |row_ix| {
+                if self.expanded_rows.contains(&row_ix) {
+                    self.delegate.detail_rows(row_ix)
+                } else {
+                    0
+                }
+            }))
+        } else {
+            rows
+        };
+    }
+
+    /// Return the number of the rows to display, including the group headers.
+    fn display_rows_count(&self) -> usize {
+        match &self.display_rows {
+            Some(rows) => rows.len(),
+            None => self.delegate.rows_count(),
+        }
+    }
+
+    fn display_row(&self, ix: usize) -> DisplayRow {
+        match &self.display_rows {
+            Some(rows) => rows[ix].clone(),
+            None => DisplayRow::Row(ix),
+        }
+    }
+
+    /// Return the display index of the row of the delegate, `None` if its group is collapsed.
+    fn display_ix(&self, row_ix: usize) -> Option<usize> {
+        match &self.display_rows {
+            Some(rows) => rows.iter().position(|row| *row == DisplayRow::Row(row_ix)),
+            None => Some(row_ix),
+        }
+    }
+
+    /// Return the row indices of the delegate in the display order, without the collapsed rows.
+    fn visible_rows(&self) -> Vec<usize> {
+        match &self.display_rows {
+            Some(rows) => rows
+                .iter()
+                .filter_map(|row| match row {
+                    DisplayRow::Row(row_ix) => Some(*row_ix),
+                    DisplayRow::Group { .. } | DisplayRow::Detail { .. } => None,
+                })
+                .collect(),
+            None => (0..self.delegate.rows_count()).collect(),
+        }
+    }
+
+    pub fn set_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = Some(row_ix);
-        if let Some(row_ix) = self.selected_row {
-            self.vertical_scroll_handle.scroll_to_item(row_ix);
+        if let Some(ix) = self.display_ix(row_ix) {
+            self.vertical_scroll_handle.scroll_to_item(ix);
         }
         cx.emit(TableEvent::SelectRow(row_ix));
         cx.notify();
@@ -299,6 +836,51 @@ where
         cx.notify();
     }
 
+    /// Return the focused cell as `(row_ix, col_ix)`, see [`TableDelegate::can_focus_cell`].
+    pub fn focused_cell(&self) -> Option<(usize, usize)> {
+        match (self.selection_state, self.selected_row, self.selected_col) {
+            (SelectionState::Cell, Some(row_ix), Some(col_ix)) => Some((row_ix, col_ix)),
+            _ => None,
+        }
+    }
+
+    /// Focus the cell at the row and the column, and scroll it into view.
+    pub fn set_focused_cell(&mut self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) {
+        self.selection_state = SelectionState::Cell;
+        self.selected_row = Some(row_ix);
+        self.selected_col = Some(col_ix);
+        if let Some(ix) = self.display_ix(row_ix) {
+            self.vertical_scroll_handle.scroll_to_item(ix);
+        }
+        self.horizontal_scroll_handle.scroll_to_item(col_ix);
+        cx.emit(TableEvent::FocusCell(row_ix, col_ix));
+        cx.notify();
+    }
+
+    /// Select the row at the `pos` of the visible `rows`, or focus its cell in the selected column.
+    fn select_visible_row(&mut self, rows: &[usize], pos: usize, cx: &mut ViewContext<Self>) {
+        if self.delegate.can_focus_cell() {
+            self.set_focused_cell(rows[pos], self.selected_col.unwrap_or(0), cx);
+        } else {
+            self.set_selected_row(rows[pos], cx);
+        }
+    }
+
+    /// Select the column, or focus its cell in the selected row.
+    fn select_col(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.delegate.can_focus_cell() {
+            self.set_selected_col(col_ix, cx);
+            return;
+        }
+
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return;
+        }
+        let pos = self.selected_pos(&rows);
+        self.set_focused_cell(rows[pos], col_ix, cx);
+    }
+
     fn on_row_click(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.set_selected_row(row_ix, cx)
     }
@@ -318,31 +900,47 @@ where
         cx.notify();
     }
 
+    /// Return the position of the selected row in the visible `rows`, default to the first row.
+    fn selected_pos(&self, rows: &[usize]) -> usize {
+        self.selected_row
+            .and_then(|selected_row| rows.iter().position(|row_ix| *row_ix == selected_row))
+            .unwrap_or(0)
+    }
+
     fn action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let mut selected_row = self.selected_row.unwrap_or(0);
-        let rows_count = self.delegate.rows_count();
-        if selected_row > 0 {
-            selected_row = selected_row - 1;
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return;
+        }
+
+        let mut pos = self.selected_pos(&rows);
+        if pos > 0 {
+            pos -= 1;
         } else {
             if self.delegate.can_loop_select() {
-                selected_row = rows_count - 1;
+                pos = rows.len() - 1;
             }
         }
 
-        self.set_selected_row(selected_row, cx);
+        self.select_visible_row(&rows, pos, cx);
     }
 
     fn action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        let mut selected_row = self.selected_row.unwrap_or(0);
-        if selected_row < self.delegate.rows_count() - 1 {
-            selected_row += 1;
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return;
+        }
+
+        let mut pos = self.selected_pos(&rows);
+        if pos < rows.len() - 1 {
+            pos += 1;
         } else {
             if self.delegate.can_loop_select() {
-                selected_row = 0;
+                pos = 0;
             }
         }
 
-        self.set_selected_row(selected_row, cx);
+        self.select_visible_row(&rows, pos, cx);
     }
 
     fn action_select_prev_col(&mut self, _: &SelectPrevColumn, cx: &mut ViewContext<Self>) {
@@ -355,7 +953,7 @@ where
                 selected_col = cols_count - 1;
             }
         }
-        self.set_selected_col(selected_col, cx);
+        self.select_col(selected_col, cx);
     }
 
     fn action_select_next_col(&mut self, _: &SelectNextColumn, cx: &mut ViewContext<Self>) {
@@ -368,7 +966,115 @@ where
             }
         }
 
-        self.set_selected_col(selected_col, cx);
+        self.select_col(selected_col, cx);
+    }
+
+    /// Move to the first column of the focused row, or to the first row.
+    fn action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        if self.delegate.can_focus_cell() {
+            self.select_col(0, cx);
+            return;
+        }
+
+        let rows = self.visible_rows();
+        if !rows.is_empty() {
+            self.select_visible_row(&rows, 0, cx);
+        }
+    }
+
+    /// Move to the last column of the focused row, or to the last row.
+    fn action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        if self.delegate.can_focus_cell() {
+            self.select_col(self.delegate.cols_count().saturating_sub(1), cx);
+            return;
+        }
+
+        let rows = self.visible_rows();
+        if !rows.is_empty() {
+            self.select_visible_row(&rows, rows.len() - 1, cx);
+        }
+    }
+
+    /// Move the selection by the number of the rows in the viewport.
+    fn select_by_page(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return;
+        }
+
+        let page = self.visible_range.len().saturating_sub(1).max(1) as isize;
+        let delta = if forward { page } else { -page };
+        let pos = step_pos(self.selected_pos(&rows), delta, rows.len());
+        self.select_visible_row(&rows, pos, cx);
+    }
+
+    fn action_select_page_up(&mut self, _: &SelectPageUp, cx: &mut ViewContext<Self>) {
+        self.select_by_page(false, cx);
+    }
+
+    fn action_select_page_down(&mut self, _: &SelectPageDown, cx: &mut ViewContext<Self>) {
+        self.select_by_page(true, cx);
+    }
+
+    /// Edit the focused cell if it is editable, see [`TableDelegate::can_edit_cell`].
+    fn action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        let Some((row_ix, col_ix)) = self
+            .focused_cell()
+            .filter(|(row_ix, col_ix)| self.delegate.can_edit_cell(*row_ix, *col_ix))
+        else {
+            cx.propagate();
+            return;
+        };
+
+        self.delegate_mut().edit_cell(row_ix, col_ix, cx);
+        cx.notify();
+    }
+
+    fn action_toggle_row_detail(&mut self, _: &ToggleRowDetail, cx: &mut ViewContext<Self>) {
+        let Some(row_ix) = self
+            .selected_row
+            .filter(|_| self.delegate.can_expand_rows())
+        else {
+            cx.propagate();
+            return;
+        };
+
+        self.toggle_row_expanded(row_ix, cx);
+    }
+
+    /// Copy the focused cell, the selected row, or the selected column with its name, as the tab-separated text.
+    fn action_copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
+        let rows = match self.selection_state {
+            SelectionState::Cell => {
+                let Some((row_ix, col_ix)) = self.focused_cell() else {
+                    return;
+                };
+                vec![vec![self.delegate.cell_text(row_ix, col_ix)]]
+            }
+            SelectionState::Row => {
+                let Some(row_ix) = self.selected_row else {
+                    return;
+                };
+                vec![(0..self.delegate.cols_count())
+                    .map(|col_ix| self.delegate.cell_text(row_ix, col_ix))
+                    .collect::<Vec<_>>()]
+            }
+            SelectionState::Column => {
+                let Some(col_ix) = self.selected_col else {
+                    return;
+                };
+                std::iter::once(self.delegate.col_name(col_ix))
+                    .chain(
+                        self.visible_rows()
+                            .into_iter()
+                            .map(|row_ix| self.delegate.cell_text(row_ix, col_ix)),
+                    )
+                    .map(|text| vec![text])
+                    .collect()
+            }
+        };
+
+        cx.write_to_clipboard(ClipboardItem::new_string(to_tsv(&rows)));
     }
 
     fn render_cell(&self, col_ix: usize, _cx: &mut ViewContext<Self>) -> Div {
@@ -382,6 +1088,203 @@ where
             .px_2()
     }
 
+    /// Render the cell of the row, with the focused style and to focus it by click if the cells can be focused.
+    fn render_body_cell(&self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) -> Div {
+        let focused = self.focused_cell() == Some((row_ix, col_ix));
+
+        self.render_cell(col_ix, cx)
+            .flex_shrink_0()
+            .relative()
+            .when(self.delegate.can_focus_cell(), |this| {
+                this.on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _, cx| {
+                        cx.stop_propagation();
+                        cx.focus(&this.focus_handle);
+                        this.set_focused_cell(row_ix, col_ix, cx);
+                    }),
+                )
+            })
+            .when(
+                col_ix == 0 && self.delegate.can_nest_rows() && !self.delegate.can_group(),
+                |this| {
+                    this.flex()
+                        .items_center()
+                        .child(self.render_tree_toggle(row_ix, cx))
+                },
+            )
+            .when(col_ix == 0 && self.delegate.can_expand_rows(), |this| {
+                this.flex()
+                    .items_center()
+                    .child(self.render_expand_toggle(row_ix, cx))
+            })
+            .when(focused, |this| {
+                this.bg(cx.theme().table_active).child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .size_full()
+                        .border_1()
+                        .border_color(cx.theme().ring),
+                )
+            })
+            .map(|this| {
+                if self.delegate.is_row_loaded(row_ix) {
+                    this.child(self.delegate.render_td(row_ix, col_ix, cx))
+                } else {
+                    this.child(Skeleton::new().my_0p5())
+                }
+            })
+    }
+
+    /// Render the indent of the row in the tree, with the chevron to collapse its children if it has.
+    fn render_tree_toggle(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        const INDENT: Pixels = px(16.);
+
+        let depth = self.delegate.row_depth(row_ix);
+        let toggle = div().flex_shrink_0().size_4().mr_1();
+        let toggle = if self.children_range(row_ix).is_empty() {
+            toggle
+        } else {
+            let icon = if self.is_row_collapsed(row_ix) {
+                IconName::ChevronRight
+            } else {
+                IconName::ChevronDown
+            };
+
+            toggle
+                .cursor_pointer()
+                .text_color(cx.theme().muted_foreground)
+                .child(Icon::new(icon).size_4())
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _, cx| {
+                        cx.stop_propagation();
+                        this.toggle_row_collapsed(row_ix, cx);
+                    }),
+                )
+        };
+
+        h_flex()
+            .flex_shrink_0()
+            .pl(INDENT * depth as f32)
+            .child(toggle)
+    }
+
+    /// Render the chevron to expand the detail of the row, or a spacer if the row has no detail.
+    fn render_expand_toggle(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let toggle = div().flex_shrink_0().size_4().mr_1();
+        if self.delegate.detail_rows(row_ix) == 0 {
+            return toggle;
+        }
+
+        let icon = if self.is_row_expanded(row_ix) {
+            IconName::ChevronDown
+        } else {
+            IconName::ChevronRight
+        };
+
+        toggle
+            .cursor_pointer()
+            .text_color(cx.theme().muted_foreground)
+            .child(Icon::new(icon).size_4())
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _, cx| {
+                    cx.stop_propagation();
+                    this.toggle_row_expanded(row_ix, cx);
+                }),
+            )
+    }
+
+    /// Render the `slot` of the detail of the expanded row, each slot has the height of a row
+    /// and clips its part of the detail, to keep the rows of the uniform list in the same height.
+    fn render_detail_row(
+        &self,
+        row_ix: usize,
+        slot: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let row_height = self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .last_item_height
+            .unwrap_or_default();
+        let count = self.delegate.detail_rows(row_ix);
+
+        div()
+            .relative()
+            .w_full()
+            .h(row_height)
+            .overflow_hidden()
+            .bg(cx.theme().table_even)
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .top(-row_height * slot as f32)
+                    .w_full()
+                    .h(row_height * count as f32)
+                    .px_2()
+                    .py_1()
+                    .child(self.delegate.render_row_detail(row_ix, cx)),
+            )
+    }
+
+    /// Make the row draggable to reorder the rows, the row under the dragged row shows
+    /// the insertion line on its top.
+    fn render_row_drag(
+        &self,
+        row: Stateful<Div>,
+        row_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Stateful<Div> {
+        let drag = DragRow {
+            entity_id: cx.entity_id(),
+            label: self.delegate.cell_text(row_ix, 0),
+            row_ix,
+        };
+
+        row.on_drag(drag, |drag, cx| {
+            cx.stop_propagation();
+            cx.new_view(|_| drag.clone())
+        })
+        .drag_over::<DragRow>(|this, _, cx| this.border_t_2().border_color(cx.theme().drag_border))
+        .on_drop(cx.listener(move |table, drag: &DragRow, cx| {
+            // Only reorder the rows of the same table.
+            if drag.entity_id != cx.entity_id() {
+                return;
+            }
+
+            table.move_row(drag.row_ix, insert_before_ix(drag.row_ix, row_ix), cx);
+        }))
+    }
+
+    /// The drop zone after the last row, to move the dragged row to the end.
+    fn render_rows_end_drop(
+        &self,
+        rows_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        div()
+            .id("table-rows-end")
+            .flex_shrink_0()
+            .w_full()
+            .h(px(8.))
+            .drag_over::<DragRow>(|this, _, cx| {
+                this.border_t_2().border_color(cx.theme().drag_border)
+            })
+            .on_drop(cx.listener(move |table, drag: &DragRow, cx| {
+                if drag.entity_id != cx.entity_id() {
+                    return;
+                }
+
+                table.move_row(drag.row_ix, insert_before_ix(drag.row_ix, rows_count), cx);
+            }))
+    }
+
     /// Show Column selection style, when the column is selected and the selection state is Column.
     fn col_wrap(&self, col_ix: usize, cx: &mut ViewContext<Self>) -> Div {
         if self.delegate().can_select_col(col_ix)
@@ -408,7 +1311,7 @@ where
                     cx.view().entity_id(),
                     state,
                     self.vertical_scroll_handle.clone(),
-                    self.delegate.rows_count(),
+                    self.display_rows_count(),
                 )),
         )
     }
@@ -559,6 +1462,9 @@ where
         }
 
         self.delegate_mut().perform_sort(col_ix, sort, cx);
+        // The rows are reordered, the expanded and collapsed row indices are out of date.
+        self.expanded_rows.clear();
+        self.collapsed_rows.clear();
 
         cx.notify();
     }
@@ -600,6 +1506,117 @@ where
         )
     }
 
+    /// Render the button to open the filter popover of the column, if the column has a filter.
+    fn render_filter_button(
+        &self,
+        col_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<impl IntoElement> {
+        let filter = self.delegate.col_filter(col_ix)?;
+        let predicate = self
+            .filters
+            .iter()
+            .find(|filter| filter.col_ix() == col_ix)
+            .cloned();
+        let icon_color = if predicate.is_some() {
+            cx.theme().primary
+        } else {
+            cx.theme().secondary_foreground
+        };
+        let table = cx.view().downgrade();
+
+        Some(
+            div()
+                .ml_1()
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                .child(
+                    Popover::new(("table-filter", col_ix))
+                        .anchor(AnchorCorner::TopRight)
+                        .trigger(
+                            Button::new(("filter-button", col_ix), cx)
+                                .ghost()
+                                .xsmall()
+                                .compact()
+                                .icon(Icon::new(IconName::Filter).text_color(icon_color)),
+                        )
+                        .content(move |cx| {
+                            let table = table.clone();
+                            cx.new_view(|cx| {
+                                ColFilterPanel::new(
+                                    col_ix,
+                                    filter.clone(),
+                                    predicate.clone(),
+                                    move |predicate, cx| {
+                                        _ = table.update(cx, |table, cx| {
+                                            table.set_filter(col_ix, predicate, cx)
+                                        });
+                                    },
+                                    cx,
+                                )
+                            })
+                        }),
+                ),
+        )
+    }
+
+    /// Render the chips of the filters above the header, to clear them one by one or all.
+    fn render_filter_chips(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.filters.is_empty() {
+            return None;
+        }
+
+        let chips = self
+            .filters
+            .iter()
+            .map(|predicate| {
+                let col_ix = predicate.col_ix();
+                h_flex()
+                    .id(("filter-chip", col_ix))
+                    .gap_1()
+                    .items_center()
+                    .px_2()
+                    .py_0p5()
+                    .rounded_md()
+                    .text_xs()
+                    .bg(cx.theme().secondary)
+                    .text_color(cx.theme().secondary_foreground)
+                    .child(format!(
+                        "{}: {}",
+                        self.delegate.col_name(col_ix),
+                        predicate.summary()
+                    ))
+                    .child(
+                        div()
+                            .id("clear")
+                            .cursor_pointer()
+                            .rounded_sm()
+                            .hover(|this| this.bg(cx.theme().secondary_hover))
+                            .on_click(
+                                cx.listener(move |table, _, cx| table.set_filter(col_ix, None, cx)),
+                            )
+                            .child(Icon::new(IconName::Close).size_3()),
+                    )
+            })
+            .collect::<Vec<_>>();
+
+        Some(
+            h_flex()
+                .flex_wrap()
+                .gap_1()
+                .p_1()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .children(chips)
+                .child(
+                    Button::new("clear-filters", cx)
+                        .ghost()
+                        .xsmall()
+                        .label(t!("Table.Clear all filters"))
+                        .on_click(cx.listener(|table, _, cx| table.clear_filters(cx))),
+                ),
+        )
+    }
+
     /// Render the column header.
     /// The children must be one by one items.
     /// Because the horizontal scroll handle will use the child_item_bounds to
@@ -626,7 +1643,12 @@ where
                             .justify_between()
                             .items_center()
                             .child(self.delegate.render_th(col_ix, cx))
-                            .children(self.render_sort_icon(col_ix, cx)),
+                            .child(
+                                h_flex()
+                                    .items_center()
+                                    .children(self.render_filter_button(col_ix, cx))
+                                    .children(self.render_sort_icon(col_ix, cx)),
+                            ),
                     )
                     .when(self.delegate.can_move_col(col_ix), |this| {
                         this.on_drag(
@@ -679,7 +1701,7 @@ where
             return;
         }
 
-        let row_count = self.delegate.rows_count();
+        let row_count = self.display_rows_count();
         let load_more_count = self.delegate.load_more_threshold();
 
         // Securely handle subtract logic to prevent attempt to subtract with overflow
@@ -688,7 +1710,7 @@ where
                 cx.spawn(|view, mut cx| async move {
                     cx.update(|cx| {
                         view.update(cx, |view, cx| {
-                            view.delegate.load_more(cx);
+                            view.delegate_mut().load_more(cx);
                         })
                     })
                 })
@@ -697,6 +1719,120 @@ where
         }
     }
 
+    /// Dispatch delegate's `load_rows` method with the range of the visible rows that are not loaded.
+    fn load_rows(&mut self, visible_range: Range<usize>, cx: &mut ViewContext<Self>) {
+        let unloaded = visible_range
+            .filter_map(|ix| match self.display_row(ix) {
+                DisplayRow::Row(row_ix) => Some(row_ix),
+                _ => None,
+            })
+            .filter(|row_ix| !self.delegate.is_row_loaded(*row_ix))
+            .fold(None, |range: Option<Range<usize>>, row_ix| match range {
+                Some(range) => Some(range.start.min(row_ix)..range.end.max(row_ix + 1)),
+                None => Some(row_ix..row_ix + 1),
+            });
+
+        if let Some(range) = unloaded {
+            cx.defer(move |view, cx| view.delegate.load_rows(range, cx));
+        }
+    }
+
+    /// Aggregate the values of the columns, `None` if no column has an aggregate.
+    fn aggregates(&self) -> Option<Aggregates> {
+        let rows_count = self.delegate.rows_count();
+        let aggregates = (0..self.delegate.cols_count())
+            .map(|col_ix| {
+                self.delegate.col_aggregate(col_ix).map(|aggregate| {
+                    let values = (0..rows_count)
+                        .filter_map(|row_ix| self.delegate.col_value(row_ix, col_ix));
+                    (aggregate, aggregate.compute(values))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if aggregates.iter().any(Option::is_some) {
+            Some(aggregates)
+        } else {
+            None
+        }
+    }
+
+    /// Render the sticky footer row with the aggregates of the columns.
+    fn render_footer(
+        &self,
+        aggregates: Aggregates,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let offset_x = self.horizontal_scroll_handle.offset().x;
+        let cells = aggregates
+            .into_iter()
+            .enumerate()
+            .map(|(col_ix, aggregate)| {
+                let cell = self.render_cell(col_ix, cx).flex_shrink_0();
+                let cell = match aggregate {
+                    Some((aggregate, value)) => {
+                        cell.child(self.delegate.render_tf(col_ix, aggregate, value, cx))
+                    }
+                    None => cell,
+                };
+                self.col_wrap(col_ix, cx).left(offset_x).child(cell)
+            })
+            .collect::<Vec<_>>();
+
+        h_flex()
+            .id("table-foot")
+            .flex_shrink_0()
+            .w_full()
+            .h_10()
+            .overflow_hidden()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().table_head)
+            .children(cells)
+    }
+
+    /// Render the header row of a group, click to collapse or expand the group.
+    fn render_group_row(
+        &self,
+        ix: usize,
+        key: Option<SharedString>,
+        count: usize,
+        collapsed: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let label = key
+            .clone()
+            .unwrap_or_else(|| t!("Table.Ungrouped").to_string().into());
+        let icon = if collapsed {
+            IconName::ChevronRight
+        } else {
+            IconName::ChevronDown
+        };
+
+        h_flex()
+            .id(("table-group", ix))
+            .w_full()
+            .gap_1()
+            .py_1()
+            .px_2()
+            .font_semibold()
+            .bg(cx.theme().table_head)
+            .when(ix > 0, |this| {
+                this.border_t_1().border_color(cx.theme().border)
+            })
+            .cursor_pointer()
+            .child(
+                Icon::new(icon)
+                    .size_4()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(self.delegate.render_group(&label, count, cx))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _, cx| this.toggle_group_key(key.clone(), cx)),
+            )
+    }
+
     fn move_col(&mut self, col_ix: usize, to_ix: usize, cx: &mut ViewContext<Self>) {
         if col_ix == to_ix {
             return;
@@ -705,9 +1841,150 @@ where
         self.delegate.move_col(col_ix, to_ix);
         let col_group = self.col_groups.remove(col_ix);
         self.col_groups.insert(to_ix, col_group);
+        for filter in self.filters.iter_mut() {
+            filter.set_col_ix(moved_ix(filter.col_ix(), col_ix, to_ix));
+        }
+        self.filters.sort_by_key(|filter| filter.col_ix());
+        self.selected_col = self.selected_col.map(|ix| moved_ix(ix, col_ix, to_ix));
 
         cx.notify();
     }
+
+    fn move_row(&mut self, row_ix: usize, to_ix: usize, cx: &mut ViewContext<Self>) {
+        if row_ix == to_ix {
+            return;
+        }
+
+        self.delegate_mut().move_row(row_ix, to_ix);
+        self.selected_row = self.selected_row.map(|ix| moved_ix(ix, row_ix, to_ix));
+        self.expanded_rows = self
+            .expanded_rows
+            .iter()
+            .map(|ix| moved_ix(*ix, row_ix, to_ix))
+            .collect();
+        self.collapsed_rows = self
+            .collapsed_rows
+            .iter()
+            .map(|ix| moved_ix(*ix, row_ix, to_ix))
+            .collect();
+        cx.emit(TableEvent::RowMoved(row_ix, to_ix));
+        cx.notify();
+    }
+}
+
+/// Return the index to move the row at `from` to, to insert it before the row at `before`,
+/// the rows after `from` are shifted up by its removal.
+fn insert_before_ix(from: usize, before: usize) -> usize {
+    if from < before {
+        before - 1
+    } else {
+        before
+    }
+}
+
+/// Return the new index of the row or column at `ix`, after the one at `from` is moved to `to`.
+fn moved_ix(ix: usize, from: usize, to: usize) -> usize {
+    if ix == from {
+        to
+    } else if from < ix && ix <= to {
+        ix - 1
+    } else if to <= ix && ix < from {
+        ix + 1
+    } else {
+        ix
+    }
+}
+
+/// Move the `pos` by the `delta`, clamped in the `len`.
+fn step_pos(pos: usize, delta: isize, len: usize) -> usize {
+    (pos as isize + delta).clamp(0, len as isize - 1) as usize
+}
+
+/// Join the cells of the `rows` into the tab-separated text to paste into the spreadsheets,
+/// the tabs and the line breaks in the cells are replaced by the spaces.
+fn to_tsv(rows: &[Vec<SharedString>]) -> String {
+    rows.iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|cell| cell.replace(['\t', '\r', '\n'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Cluster the rows by their group `keys` in the order of the first rows of the groups,
+/// the rows without key are in the last group, and the rows of the `collapsed` groups are hidden.
+fn group_rows(
+    keys: Vec<Option<SharedString>>,
+    collapsed: &HashSet<Option<SharedString>>,
+) -> Vec<DisplayRow> {
+    let mut groups: Vec<(Option<SharedString>, Vec<usize>)> = vec![];
+    let mut group_ixs: HashMap<Option<SharedString>, usize> = HashMap::new();
+    for (row_ix, key) in keys.into_iter().enumerate() {
+        let ix = *group_ixs.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, vec![]));
+            groups.len() - 1
+        });
+        groups[ix].1.push(row_ix);
+    }
+    // The ungrouped rows are the last group, it is stable to sort by `is_none`.
+    groups.sort_by_key(|(key, _)| key.is_none());
+
+    let mut rows = vec![];
+    for (key, row_ixs) in groups {
+        let is_collapsed = collapsed.contains(&key);
+        rows.push(DisplayRow::Group {
+            key,
+            count: row_ixs.len(),
+            collapsed: is_collapsed,
+        });
+
+        if !is_collapsed {
+            rows.extend(row_ixs.into_iter().map(DisplayRow::Row));
+        }
+    }
+    rows
+}
+
+/// Return the rows of the tree of the `depths` in the depth-first order,
+/// without the descendants of the `collapsed` rows.
+fn tree_rows(depths: &[usize], collapsed: &HashSet<usize>) -> Vec<DisplayRow> {
+    let mut rows = vec![];
+    // The depth of the collapsed row that hides the following deeper rows.
+    let mut hidden_depth = None;
+    for (row_ix, depth) in depths.iter().copied().enumerate() {
+        if let Some(hidden_depth) = hidden_depth {
+            if depth > hidden_depth {
+                continue;
+            }
+        }
+
+        rows.push(DisplayRow::Row(row_ix));
+        hidden_depth = collapsed.contains(&row_ix).then_some(depth);
+    }
+    rows
+}
+
+/// Insert the `n` detail slots after each row, where `n` is returned by `detail_rows` of the row.
+fn insert_detail_rows(
+    rows: Vec<DisplayRow>,
+    detail_rows: impl Fn(usize) -> usize,
+) -> Vec<DisplayRow> {
+    let mut result = Vec::with_capacity(rows.len());
+    for row in rows {
+        let detail = match row {
+            DisplayRow::Row(row_ix) => Some((row_ix, detail_rows(row_ix))),
+            _ => None,
+        };
+        result.push(row);
+        if let Some((row_ix, count)) = detail {
+            result.extend((0..count).map(|slot| DisplayRow::Detail { row_ix, slot }));
+        }
+    }
+    result
 }
 
 impl<D> FocusableView for Table<D>
@@ -731,6 +2008,15 @@ where
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let cols_count: usize = self.delegate.cols_count();
         let rows_count = self.delegate.rows_count();
+        if self.rows_changed || self.prepared_rows_count != rows_count {
+            self.refresh_rows();
+            self.prepare_rows();
+        }
+        let display_rows_count = self.display_rows_count();
+        if self.aggregates.is_none() {
+            self.aggregates = Some(self.aggregates());
+        }
+        let aggregates = self.aggregates.clone().flatten();
 
         fn last_empty_col(_: &mut WindowContext) -> Div {
             h_flex().w(px(100.)).h_full().flex_shrink_0()
@@ -749,8 +2035,16 @@ where
             .on_action(cx.listener(Self::action_select_prev))
             .on_action(cx.listener(Self::action_select_next_col))
             .on_action(cx.listener(Self::action_select_prev_col))
+            .on_action(cx.listener(Self::action_select_first))
+            .on_action(cx.listener(Self::action_select_last))
+            .on_action(cx.listener(Self::action_select_page_up))
+            .on_action(cx.listener(Self::action_select_page_down))
+            .on_action(cx.listener(Self::action_confirm))
+            .on_action(cx.listener(Self::action_toggle_row_detail))
+            .on_action(cx.listener(Self::action_copy))
             .size_full()
             .overflow_hidden()
+            .children(self.render_filter_chips(cx))
             .child(
                 v_flex()
                     .flex_grow()
@@ -790,23 +2084,45 @@ where
                 } else {
                     this.child(
                         h_flex().id("table-body").flex_grow().size_full().child(
-                            uniform_list(view, "table-uniform-list", rows_count, {
+                            uniform_list(view, "table-uniform-list", display_rows_count, {
                                 let horizontal_scroll_handle = horizontal_scroll_handle.clone();
                                 move |table, visible_range, cx| {
+                                    table.visible_range = visible_range.clone();
                                     table.load_more(visible_range.clone(), cx);
+                                    table.load_rows(visible_range.clone(), cx);
 
                                     visible_range
-                                        .map(|row_ix| {
+                                        .map(|ix| {
+                                            let row_ix = match table.display_row(ix) {
+                                                DisplayRow::Row(row_ix) => row_ix,
+                                                DisplayRow::Detail { row_ix, slot } => {
+                                                    return table
+                                                        .render_detail_row(row_ix, slot, cx)
+                                                        .into_any_element();
+                                                }
+                                                DisplayRow::Group {
+                                                    key,
+                                                    count,
+                                                    collapsed,
+                                                } => {
+                                                    return table
+                                                        .render_group_row(
+                                                            ix, key, count, collapsed, cx,
+                                                        )
+                                                        .into_any_element();
+                                                }
+                                            };
+
                                             table
                                                 .delegate
                                                 .render_tr(row_ix, cx)
                                                 .id(("table-row", row_ix))
                                                 .w_full()
-                                                .when(row_ix > 0, |this| {
+                                                .when(ix > 0, |this| {
                                                     this.border_t_1()
                                                         .border_color(cx.theme().border)
                                                 })
-                                                .when(table.stripe && row_ix % 2 != 0, |this| {
+                                                .when(table.stripe && ix % 2 != 0, |this| {
                                                     this.bg(cx.theme().table_even)
                                                 })
                                                 .hover(|this| {
@@ -821,14 +2137,9 @@ where
                                                         .col_wrap(col_ix, cx) // Make the row scroll sync with the horizontal_scroll_handle to support horizontal scrolling.
                                                         .left(horizontal_scroll_handle.offset().x)
                                                         .child(
-                                                            table
-                                                                .render_cell(col_ix, cx)
-                                                                .flex_shrink_0()
-                                                                .child(
-                                                                    table.delegate.render_td(
-                                                                        row_ix, col_ix, cx,
-                                                                    ),
-                                                                ),
+                                                            table.render_body_cell(
+                                                                row_ix, col_ix, cx,
+                                                            ),
                                                         )
                                                 }))
                                                 .child(last_empty_col(cx))
@@ -850,6 +2161,10 @@ where
                                                         this.on_row_click(row_ix, cx);
                                                     }),
                                                 )
+                                                .when(table.delegate.can_move_row(row_ix), |this| {
+                                                    table.render_row_drag(this, row_ix, cx)
+                                                })
+                                                .into_any_element()
                                         })
                                         .collect::<Vec<_>>()
                                 }
@@ -862,6 +2177,12 @@ where
                         ),
                     )
                 }
+            })
+            .when(rows_count > 0 && cx.has_active_drag(), |this| {
+                this.child(self.render_rows_end_drop(rows_count, cx))
+            })
+            .when_some(aggregates, |this, aggregates| {
+                this.child(self.render_footer(aggregates, cx))
             });
 
         let view = cx.view().clone();
@@ -886,3 +2207,170 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        group_rows, insert_before_ix, insert_detail_rows, moved_ix, step_pos, to_tsv, tree_rows,
+        ColAggregate, DisplayRow,
+    };
+
+    #[test]
+    fn test_step_pos() {
+        assert_eq!(step_pos(5, 3, 10), 8);
+        assert_eq!(step_pos(8, 3, 10), 9);
+        assert_eq!(step_pos(2, -3, 10), 0);
+        assert_eq!(step_pos(0, 1, 1), 0);
+    }
+
+    #[test]
+    fn test_moved_ix() {
+        assert_eq!(moved_ix(1, 1, 3), 3);
+        assert_eq!(moved_ix(2, 1, 3), 1);
+        assert_eq!(moved_ix(3, 1, 3), 2);
+        assert_eq!(moved_ix(4, 1, 3), 4);
+        assert_eq!(moved_ix(3, 3, 1), 1);
+        assert_eq!(moved_ix(1, 3, 1), 2);
+        assert_eq!(moved_ix(0, 3, 1), 0);
+    }
+
+    #[test]
+    fn test_insert_before_ix() {
+        // Drag down the row 1 onto the row 3, it is inserted between the rows 2 and 3.
+        assert_eq!(insert_before_ix(1, 3), 2);
+        assert_eq!(moved_ix(1, 1, insert_before_ix(1, 3)), 2);
+        assert_eq!(moved_ix(2, 1, insert_before_ix(1, 3)), 1);
+        assert_eq!(moved_ix(3, 1, insert_before_ix(1, 3)), 3);
+        // Drop on the row after itself, it stays.
+        assert_eq!(insert_before_ix(2, 3), 2);
+        // Drop on the end of 5 rows.
+        assert_eq!(insert_before_ix(1, 5), 4);
+
+        // Drag up the row 3 onto the row 1, it is inserted between the rows 0 and 1.
+        assert_eq!(insert_before_ix(3, 1), 1);
+        assert_eq!(moved_ix(3, 3, insert_before_ix(3, 1)), 1);
+        assert_eq!(moved_ix(1, 3, insert_before_ix(3, 1)), 2);
+        assert_eq!(insert_before_ix(3, 3), 3);
+    }
+
+    #[test]
+    fn test_to_tsv() {
+        assert_eq!(to_tsv(&[]), "");
+        assert_eq!(
+            to_tsv(&[
+                vec!["Name".into(), "Age".into()],
+                vec!["Jason\tLee".into(), "30".into()],
+                vec!["Multi\nline".into(), "".into()],
+            ]),
+            "Name\tAge\nJason Lee\t30\nMulti line\t"
+        );
+    }
+
+    #[test]
+    fn test_col_aggregate() {
+        let values = [1., 2., 6.];
+        assert_eq!(ColAggregate::Sum.compute(values), Some(9.));
+        assert_eq!(ColAggregate::Avg.compute(values), Some(3.));
+        assert_eq!(ColAggregate::Count.compute(values), Some(3.));
+        assert_eq!(ColAggregate::Sum.compute([]), Some(0.));
+        assert_eq!(ColAggregate::Avg.compute([]), None);
+        assert_eq!(ColAggregate::Count.compute([]), Some(0.));
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let keys = vec![Some("b".into()), None, Some("a".into()), Some("b".into())];
+        assert_eq!(
+            group_rows(keys.clone(), &HashSet::new()),
+            vec![
+                DisplayRow::Group {
+                    key: Some("b".into()),
+                    count: 2,
+                    collapsed: false,
+                },
+                DisplayRow::Row(0),
+                DisplayRow::Row(3),
+                DisplayRow::Group {
+                    key: Some("a".into()),
+                    count: 1,
+                    collapsed: false,
+                },
+                DisplayRow::Row(2),
+                DisplayRow::Group {
+                    key: None,
+                    count: 1,
+                    collapsed: false,
+                },
+                DisplayRow::Row(1),
+            ]
+        );
+
+        let collapsed = HashSet::from([Some("b".into()), None]);
+        assert_eq!(
+            group_rows(keys, &collapsed),
+            vec![
+                DisplayRow::Group {
+                    key: Some("b".into()),
+                    count: 2,
+                    collapsed: true,
+                },
+                DisplayRow::Group {
+                    key: Some("a".into()),
+                    count: 1,
+                    collapsed: false,
+                },
+                DisplayRow::Row(2),
+                DisplayRow::Group {
+                    key: None,
+                    count: 1,
+                    collapsed: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_detail_rows() {
+        let rows = vec![
+            DisplayRow::Group {
+                key: Some("a".into()),
+                count: 2,
+                collapsed: false,
+            },
+            DisplayRow::Row(0),
+            DisplayRow::Row(1),
+        ];
+        assert_eq!(
+            insert_detail_rows(rows, |row_ix| if row_ix == 0 { 2 } else { 0 }),
+            vec![
+                DisplayRow::Group {
+                    key: Some("a".into()),
+                    count: 2,
+                    collapsed: false,
+                },
+                DisplayRow::Row(0),
+                DisplayRow::Detail { row_ix: 0, slot: 0 },
+                DisplayRow::Detail { row_ix: 0, slot: 1 },
+                DisplayRow::Row(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tree_rows() {
+        let depths = [0, 1, 2, 1, 0, 1];
+        let rows = |ixs: &[usize]| ixs.iter().copied().map(DisplayRow::Row).collect::<Vec<_>>();
+        assert_eq!(
+            tree_rows(&depths, &HashSet::new()),
+            rows(&[0, 1, 2, 3, 4, 5])
+        );
+        assert_eq!(
+            tree_rows(&depths, &HashSet::from([1])),
+            rows(&[0, 1, 3, 4, 5])
+        );
+        assert_eq!(tree_rows(&depths, &HashSet::from([0])), rows(&[0, 4, 5]));
+        assert_eq!(tree_rows(&depths, &HashSet::from([0, 4])), rows(&[0, 4]));
+    }
+}
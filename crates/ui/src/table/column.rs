@@ -0,0 +1,258 @@
+use std::{fmt, rc::Rc};
+
+use gpui::{
+    div, px, relative, AnyElement, ElementId, IntoElement, ParentElement as _, Pixels,
+    SharedString, Styled as _, WindowContext,
+};
+
+use crate::{
+    button::Button,
+    h_flex,
+    progress::Progress,
+    theme::{ActiveTheme as _, Colorize as _},
+    Sizable as _,
+};
+
+use super::{ColFilter, ColSort};
+
+/// The height of the sparkline in a cell.
+const SPARKLINE_HEIGHT: Pixels = px(16.);
+const SPARKLINE_BAR_WIDTH: Pixels = px(3.);
+
+/// The value of a table cell, it is formatted and rendered by its [`TableColumn`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    Text(SharedString),
+    Number(f64),
+    Bool(bool),
+    /// A series of the numbers, e.g.: to render a sparkline.
+    Series(Vec<f64>),
+}
+
+impl CellValue {
+    /// Return the number of the value, the bool is `1` or `0`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Bool(value) => Some(if *value { 1. } else { 0. }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => Ok(()),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Number(value) => write!(f, "{}", value),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Series(values) => {
+                for (ix, value) in values.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<&'static str> for CellValue {
+    fn from(value: &'static str) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<SharedString> for CellValue {
+    fn from(value: SharedString) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<usize> for CellValue {
+    fn from(value: usize) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Vec<f64>> for CellValue {
+    fn from(values: Vec<f64>) -> Self {
+        Self::Series(values)
+    }
+}
+
+/// How a [`TableColumn`] renders its cells.
+#[derive(Clone, Default)]
+pub enum CellRenderer {
+    /// The formatted value as the plain text.
+    #[default]
+    Text,
+    /// The formatted value in a badge.
+    Badge,
+    /// A progress bar of the number in `0..=100`.
+    Progress,
+    /// The bars of the series.
+    Sparkline,
+    /// A button labeled by the formatted value, the handler is called with the row index.
+    Button(Rc<dyn Fn(usize, &mut WindowContext)>),
+    /// Render the cell by the row index and the value.
+    Custom(Rc<dyn Fn(usize, &CellValue, &mut WindowContext) -> AnyElement>),
+}
+
+/// The definition of a column of the [`super::Table`], to render the cells of the column
+/// from their [`CellValue`], see [`super::TableDelegate::column`].
+///
+/// ```ignore
+/// TableColumn::new("progress", "Progress")
+///     .width(px(120.))
+///     .renderer(CellRenderer::Progress);
+/// TableColumn::new("price", "Price")
+///     .sort(ColSort::Default)
+///     .formatter(|value| format!("${:.2}", value.as_f64().unwrap_or_default()).into());
+/// ```
+#[derive(Clone)]
+pub struct TableColumn {
+    pub id: SharedString,
+    pub name: SharedString,
+    pub width: Option<Pixels>,
+    pub sort: Option<ColSort>,
+    pub filter: Option<ColFilter>,
+    renderer: CellRenderer,
+    formatter: Option<Rc<dyn Fn(&CellValue) -> SharedString>>,
+}
+
+impl TableColumn {
+    pub fn new(id: impl Into<SharedString>, name: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            width: None,
+            sort: None,
+            filter: None,
+            renderer: CellRenderer::default(),
+            formatter: None,
+        }
+    }
+
+    /// Set the width of the column, default is the auto width.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Set the initial sort state to make the column sortable.
+    pub fn sort(mut self, sort: ColSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the filter of the column to filter it by its header.
+    pub fn filter(mut self, filter: ColFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Set the renderer of the cells, default is [`CellRenderer::Text`].
+    pub fn renderer(mut self, renderer: CellRenderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    /// Set the formatter of the values, default is the [`fmt::Display`] of the value.
+    pub fn formatter(mut self, formatter: impl Fn(&CellValue) -> SharedString + 'static) -> Self {
+        self.formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    /// Format the `value` by the formatter of the column.
+    pub fn format(&self, value: &CellValue) -> SharedString {
+        match &self.formatter {
+            Some(formatter) => formatter(value),
+            None => value.to_string().into(),
+        }
+    }
+
+    /// Render the cell of the `value` at the row index.
+    pub fn render_cell(
+        &self,
+        row_ix: usize,
+        value: CellValue,
+        cx: &mut WindowContext,
+    ) -> AnyElement {
+        match &self.renderer {
+            CellRenderer::Text => self.format(&value).into_any_element(),
+            CellRenderer::Badge => div()
+                .px_1p5()
+                .rounded_md()
+                .text_xs()
+                .bg(cx.theme().secondary)
+                .text_color(cx.theme().secondary_foreground)
+                .child(self.format(&value))
+                .into_any_element(),
+            CellRenderer::Progress => h_flex()
+                .size_full()
+                .items_center()
+                .child(
+                    div()
+                        .w_full()
+                        .child(Progress::new().value(value.as_f64().unwrap_or_default() as f32)),
+                )
+                .into_any_element(),
+            CellRenderer::Sparkline => {
+                let values = match value {
+                    CellValue::Series(values) => values,
+                    _ => vec![],
+                };
+                let max = values.iter().copied().fold(0., f64::max);
+                let color = cx.theme().progress_bar;
+
+                h_flex()
+                    .h(SPARKLINE_HEIGHT)
+                    .items_end()
+                    .gap(px(1.))
+                    .children(values.into_iter().map(|value| {
+                        let ratio = if max > 0. { value.max(0.) / max } else { 0. };
+                        div()
+                            .w(SPARKLINE_BAR_WIDTH)
+                            .h(relative(ratio as f32))
+                            .min_h(px(1.))
+                            .rounded_t(px(1.))
+                            .bg(color.opacity(0.4 + 0.6 * ratio as f32))
+                    }))
+                    .into_any_element()
+            }
+            CellRenderer::Button(on_click) => {
+                let on_click = on_click.clone();
+                Button::new(ElementId::NamedInteger(self.id.clone(), row_ix), cx)
+                    .xsmall()
+                    .label(self.format(&value))
+                    .on_click(move |_, cx| on_click(row_ix, cx))
+                    .into_any_element()
+            }
+            CellRenderer::Custom(render) => render(row_ix, &value, cx),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use std::{collections::HashSet, rc::Rc};
+
+use gpui::{
+    prelude::FluentBuilder as _, px, AppContext, DismissEvent, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button,
+    checkbox::Checkbox,
+    h_flex,
+    input::{InputEvent, MaskPattern, TextInput},
+    v_flex, Sizable as _,
+};
+
+use super::CellValue;
+
+/// The kind of the filter of a column, to filter it by the popover of its header,
+/// see [`super::TableDelegate::col_filter`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColFilter {
+    /// Match the cells that contain the text, case-insensitive.
+    Text,
+    /// Match the cells that are one of the values.
+    Set(Vec<SharedString>),
+    /// Match the number cells in the range.
+    NumberRange,
+}
+
+/// The filter of a column, the predicates of the columns are combined to call
+/// [`super::TableDelegate::filter`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterPredicate {
+    Text {
+        col_ix: usize,
+        query: SharedString,
+    },
+    Set {
+        col_ix: usize,
+        values: Vec<SharedString>,
+    },
+    NumberRange {
+        col_ix: usize,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+}
+
+impl FilterPredicate {
+    /// Return the column index of the predicate.
+    pub fn col_ix(&self) -> usize {
+        match self {
+            Self::Text { col_ix, .. }
+            | Self::Set { col_ix, .. }
+            | Self::NumberRange { col_ix, .. } => *col_ix,
+        }
+    }
+
+    /// Set the column index of the predicate, when its column is moved.
+    pub(super) fn set_col_ix(&mut self, ix: usize) {
+        match self {
+            Self::Text { col_ix, .. }
+            | Self::Set { col_ix, .. }
+            | Self::NumberRange { col_ix, .. } => *col_ix = ix,
+        }
+    }
+
+    /// Return true if the cell is matched, the `text` is the formatted `value` of the cell,
+    /// see [`super::TableDelegate::cell_text`].
+    pub fn matches(&self, text: &str, value: &CellValue) -> bool {
+        match self {
+            Self::Text { query, .. } => text.to_lowercase().contains(&query.to_lowercase()),
+            Self::Set { values, .. } => values.iter().any(|value| value.as_ref() == text),
+            Self::NumberRange { min, max, .. } => value.as_f64().map_or(false, |value| {
+                min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+            }),
+        }
+    }
+
+    /// Return the summary of the predicate to show in the filter chip, e.g.: `18 - 30`.
+    pub(super) fn summary(&self) -> String {
+        match self {
+            Self::Text { query, .. } => format!("\"{}\"", query),
+            Self::Set { values, .. } => values.join(", "),
+            Self::NumberRange { min, max, .. } => match (min, max) {
+                (Some(min), Some(max)) => format!("{} - {}", min, max),
+                (Some(min), None) => format!(">= {}", min),
+                (None, Some(max)) => format!("<= {}", max),
+                (None, None) => String::new(),
+            },
+        }
+    }
+}
+
+/// The content of the filter popover of a column header.
+pub(super) struct ColFilterPanel {
+    focus_handle: FocusHandle,
+    col_ix: usize,
+    filter: ColFilter,
+    /// The query input of the `Text` filter, or the min input of the `NumberRange` filter.
+    input: View<TextInput>,
+    max_input: View<TextInput>,
+    /// The checked values of the `Set` filter.
+    checked: HashSet<SharedString>,
+    on_apply: Rc<dyn Fn(Option<FilterPredicate>, &mut WindowContext)>,
+}
+
+impl ColFilterPanel {
+    /// Create the panel of the `filter` with the current `predicate` of the column,
+    /// the `on_apply` is called with the new predicate, `None` to clear the filter.
+    pub(super) fn new(
+        col_ix: usize,
+        filter: ColFilter,
+        predicate: Option<FilterPredicate>,
+        on_apply: impl Fn(Option<FilterPredicate>, &mut WindowContext) + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let is_number = filter == ColFilter::NumberRange;
+        let new_input = |placeholder: SharedString, cx: &mut ViewContext<Self>| {
+            cx.new_view(|cx| {
+                let input = TextInput::new(cx).small().placeholder(placeholder);
+                if is_number {
+                    input.mask_pattern(MaskPattern::Number {
+                        separator: None,
+                        fraction: None,
+                        prefix: None,
+                    })
+                } else {
+                    input
+                }
+            })
+        };
+        let (input_placeholder, max_placeholder) = if is_number {
+            (t!("Table.Min").into(), t!("Table.Max").into())
+        } else {
+            (t!("Table.Contains").into(), SharedString::default())
+        };
+        let input = new_input(input_placeholder, cx);
+        let max_input = new_input(max_placeholder, cx);
+        cx.subscribe(&input, Self::on_input_event).detach();
+        cx.subscribe(&max_input, Self::on_input_event).detach();
+
+        let mut checked = HashSet::new();
+        match predicate {
+            Some(FilterPredicate::Text { query, .. }) => {
+                input.update(cx, |input, cx| input.set_text(query, cx));
+            }
+            Some(FilterPredicate::Set { values, .. }) => checked.extend(values),
+            Some(FilterPredicate::NumberRange { min, max, .. }) => {
+                if let Some(min) = min {
+                    input.update(cx, |input, cx| input.set_text(min.to_string(), cx));
+                }
+                if let Some(max) = max {
+                    max_input.update(cx, |input, cx| input.set_text(max.to_string(), cx));
+                }
+            }
+            None => {}
+        }
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            col_ix,
+            filter,
+            input,
+            max_input,
+            checked,
+            on_apply: Rc::new(on_apply),
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let InputEvent::PressEnter = event {
+            self.apply(cx);
+        }
+    }
+
+    /// Return the predicate of the inputs, `None` if nothing is filtered.
+    fn predicate(&self, cx: &AppContext) -> Option<FilterPredicate> {
+        let col_ix = self.col_ix;
+        match &self.filter {
+            ColFilter::Text => {
+                let query = self.input.read(cx).text();
+                let query = query.trim();
+                (!query.is_empty()).then(|| FilterPredicate::Text {
+                    col_ix,
+                    query: query.to_string().into(),
+                })
+            }
+            ColFilter::Set(values) => {
+                // Keep the order of the values.
+                let values = values
+                    .iter()
+                    .filter(|value| self.checked.contains(*value))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!values.is_empty()).then_some(FilterPredicate::Set { col_ix, values })
+            }
+            ColFilter::NumberRange => {
+                let parse = |input: &View<TextInput>| input.read(cx).unmask_text().parse().ok();
+                let (min, max) = (parse(&self.input), parse(&self.max_input));
+                (min.is_some() || max.is_some()).then_some(FilterPredicate::NumberRange {
+                    col_ix,
+                    min,
+                    max,
+                })
+            }
+        }
+    }
+
+    fn apply(&mut self, cx: &mut ViewContext<Self>) {
+        let predicate = self.predicate(cx);
+        (self.on_apply)(predicate, cx);
+        cx.emit(DismissEvent);
+    }
+
+    fn clear(&mut self, cx: &mut ViewContext<Self>) {
+        (self.on_apply)(None, cx);
+        cx.emit(DismissEvent);
+    }
+
+    fn render_set(&self, values: &[SharedString], cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .id("filter-values")
+            .gap_2()
+            .max_h(px(240.))
+            .overflow_y_scroll()
+            .children(values.iter().enumerate().map(|(ix, value)| {
+                let value = value.clone();
+                Checkbox::new(("filter-value", ix))
+                    .label(value.clone())
+                    .checked(self.checked.contains(&value))
+                    .on_click(cx.listener(move |this, checked: &bool, cx| {
+                        if *checked {
+                            this.checked.insert(value.clone());
+                        } else {
+                            this.checked.remove(&value);
+                        }
+                        cx.notify();
+                    }))
+            }))
+    }
+}
+
+impl EventEmitter<DismissEvent> for ColFilterPanel {}
+
+impl FocusableView for ColFilterPanel {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        match self.filter {
+            ColFilter::Set(_) => self.focus_handle.clone(),
+            _ => self.input.focus_handle(cx),
+        }
+    }
+}
+
+impl Render for ColFilterPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let filter = self.filter.clone();
+
+        v_flex()
+            .track_focus(&self.focus_handle)
+            .w(px(220.))
+            .gap_3()
+            .map(|this| match &filter {
+                ColFilter::Text => this.child(self.input.clone()),
+                ColFilter::Set(values) => this.child(self.render_set(values, cx)),
+                ColFilter::NumberRange => this.child(
+                    h_flex()
+                        .gap_2()
+                        .child(self.input.clone())
+                        .child("-")
+                        .child(self.max_input.clone()),
+                ),
+            })
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        Button::new("filter-clear", cx)
+                            .small()
+                            .ghost()
+                            .label(t!("Table.Clear"))
+                            .on_click(cx.listener(|this, _, cx| this.clear(cx))),
+                    )
+                    .child(
+                        Button::new("filter-apply", cx)
+                            .small()
+                            .primary()
+                            .label(t!("Table.Apply"))
+                            .on_click(cx.listener(|this, _, cx| this.apply(cx))),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CellValue, FilterPredicate};
+
+    #[test]
+    fn test_filter_predicate_matches() {
+        let text = FilterPredicate::Text {
+            col_ix: 0,
+            query: "lon".into(),
+        };
+        assert!(text.matches("London", &CellValue::Empty));
+        assert!(!text.matches("Paris", &CellValue::Empty));
+
+        let set = FilterPredicate::Set {
+            col_ix: 0,
+            values: vec!["Yes".into()],
+        };
+        assert!(set.matches("Yes", &CellValue::Bool(true)));
+        assert!(!set.matches("No", &CellValue::Bool(false)));
+
+        let range = FilterPredicate::NumberRange {
+            col_ix: 0,
+            min: Some(18.),
+            max: None,
+        };
+        assert!(range.matches("18", &CellValue::Number(18.)));
+        assert!(!range.matches("17", &CellValue::Number(17.)));
+        assert!(!range.matches("", &CellValue::Empty));
+        assert_eq!(range.summary(), ">= 18");
+    }
+}
@@ -0,0 +1,288 @@
+//! Load the rows of a [`Table`] by the pages as they are scrolled into view.
+//!
+//! ```ignore
+//! struct OrderDelegate {
+//!     rows: PagedRows<OrderSource>,
+//! }
+//!
+//! impl TableDelegate for OrderDelegate {
+//!     fn rows_count(&self) -> usize {
+//!         self.rows.rows_count()
+//!     }
+//!
+//!     fn is_row_loaded(&self, row_ix: usize) -> bool {
+//!         self.rows.is_loaded(row_ix)
+//!     }
+//!
+//!     fn load_rows(&mut self, range: Range<usize>, cx: &mut ViewContext<Table<Self>>) {
+//!         self.rows.load(range, |this| &mut this.rows, cx);
+//!     }
+//! }
+//! ```
+use std::{
+    collections::HashMap,
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use gpui::{Task, ViewContext, WindowContext};
+
+use super::{Table, TableDelegate};
+
+/// The max number of the loaded pages to keep by default, see [`PagedRows::max_pages`].
+const DEFAULT_MAX_PAGES: usize = 20;
+/// The delay to request a failed page again, doubled by each failure up to [`MAX_RETRY_DELAY`].
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// A source of the rows that are loaded by the pages, e.g.: from a database or a remote API.
+pub trait PagedSource: 'static {
+    type Row: 'static;
+
+    /// Return the total number of the rows, including the rows that are not loaded.
+    fn rows_count(&self) -> usize;
+
+    /// Return the number of the rows to load in a request.
+    ///
+    /// Default: 100
+    fn page_size(&self) -> usize {
+        100
+    }
+
+    /// Load the rows in the `range`, the responses may arrive in any order.
+    fn load_page(
+        &self,
+        range: Range<usize>,
+        cx: &mut WindowContext,
+    ) -> Task<Result<Vec<Self::Row>>>;
+}
+
+enum Page<R> {
+    /// The page is requested, its rows are shown as the skeletons.
+    Pending,
+    Loaded(Vec<R>),
+    /// The request is failed, the page is requested again after the `retry_at`, or by [`PagedRows::retry`].
+    Failed {
+        failures: u32,
+        retry_at: Instant,
+    },
+}
+
+/// The rows of a [`PagedSource`], to keep in a [`TableDelegate`] to load its rows by [`TableDelegate::load_rows`].
+pub struct PagedRows<S: PagedSource> {
+    source: S,
+    pages: HashMap<usize, Page<S::Row>>,
+    /// Increased by [`PagedRows::reset`], to drop the responses of the previous requests.
+    generation: usize,
+    max_pages: usize,
+}
+
+impl<S: PagedSource> PagedRows<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            pages: HashMap::new(),
+            generation: 0,
+            max_pages: DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// Set the max number of the loaded pages to keep, the pages farthest from the shown rows
+    /// are dropped first, and loaded again when they are shown.
+    ///
+    /// Default: 20
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Return the source to change it, e.g.: the sort or the filter, then call [`PagedRows::reset`].
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    pub fn rows_count(&self) -> usize {
+        self.source.rows_count()
+    }
+
+    /// Return the row at the given index, `None` if its page is not loaded.
+    pub fn row(&self, row_ix: usize) -> Option<&S::Row> {
+        let page_size = self.source.page_size().max(1);
+        match self.pages.get(&(row_ix / page_size))? {
+            Page::Loaded(rows) => rows.get(row_ix % page_size),
+            Page::Pending | Page::Failed { .. } => None,
+        }
+    }
+
+    pub fn is_loaded(&self, row_ix: usize) -> bool {
+        self.row(row_ix).is_some()
+    }
+
+    /// Drop the loaded pages and the pending requests, the rows are loaded again when they are shown.
+    pub fn reset(&mut self) {
+        self.pages.clear();
+        self.generation += 1;
+    }
+
+    /// Request the failed pages again when they are shown, without waiting for their retry delay.
+    pub fn retry(&mut self) {
+        self.pages
+            .retain(|_, page| !matches!(page, Page::Failed { .. }));
+    }
+
+    /// Request the pages of the `range` that are not loaded or pending,
+    /// the `rows` returns these rows from the delegate to store the loaded pages.
+    ///
+    /// The failed pages are requested again after a delay, that is doubled by each failure.
+    pub fn load<D: TableDelegate>(
+        &mut self,
+        range: Range<usize>,
+        rows: fn(&mut D) -> &mut Self,
+        cx: &mut ViewContext<Table<D>>,
+    ) {
+        let page_size = self.source.page_size().max(1);
+        let rows_count = self.source.rows_count();
+        let generation = self.generation;
+        let pages = page_range(range, page_size, rows_count);
+
+        for page_ix in pages.clone() {
+            let failures = match self.pages.get(&page_ix) {
+                None => 0,
+                Some(Page::Failed { failures, retry_at }) if *retry_at <= Instant::now() => {
+                    *failures
+                }
+                Some(_) => continue,
+            };
+
+            let start = page_ix * page_size;
+            let task = self
+                .source
+                .load_page(start..(start + page_size).min(rows_count), cx);
+            self.pages.insert(page_ix, Page::Pending);
+
+            cx.spawn(|view, mut cx| async move {
+                let result = task.await;
+                let retry_delay = view
+                    .update(&mut cx, |view, cx| {
+                        let this = rows(view.delegate_mut());
+                        if this.generation != generation {
+                            return None;
+                        }
+
+                        let retry_delay = match result {
+                            Ok(rows) => {
+                                this.pages.insert(page_ix, Page::Loaded(rows));
+                                None
+                            }
+                            Err(err) => {
+                                eprintln!("failed to load the page {}: {:?}", page_ix, err);
+                                let delay = retry_delay(failures);
+                                this.pages.insert(
+                                    page_ix,
+                                    Page::Failed {
+                                        failures: failures + 1,
+                                        retry_at: Instant::now() + delay,
+                                    },
+                                );
+                                Some(delay)
+                            }
+                        };
+                        cx.notify();
+                        retry_delay
+                    })
+                    .ok()
+                    .flatten();
+
+                // Render the rows again after the delay, to request the failed page if it is still shown.
+                if let Some(delay) = retry_delay {
+                    cx.background_executor().timer(delay).await;
+                    _ = view.update(&mut cx, |_, cx| cx.notify());
+                }
+            })
+            .detach();
+        }
+
+        let loaded = self
+            .pages
+            .iter()
+            .filter(|(_, page)| !matches!(page, Page::Pending))
+            .map(|(page_ix, _)| *page_ix)
+            .collect::<Vec<_>>();
+        for page_ix in evicted_pages(loaded, pages, self.max_pages) {
+            self.pages.remove(&page_ix);
+        }
+    }
+}
+
+/// Return the delay to request a page again after its `failures` (before this one).
+fn retry_delay(failures: u32) -> Duration {
+    RETRY_DELAY
+        .saturating_mul(1 << failures.min(5))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Return the pages to drop to keep `max_pages` of the `loaded` pages,
+/// the pages farthest from the `shown` pages are dropped first.
+fn evicted_pages(mut loaded: Vec<usize>, shown: Range<usize>, max_pages: usize) -> Vec<usize> {
+    if loaded.len() <= max_pages {
+        return vec![];
+    }
+
+    let distance = |page_ix: usize| {
+        if page_ix < shown.start {
+            shown.start - page_ix
+        } else {
+            page_ix.saturating_sub(shown.end.saturating_sub(1))
+        }
+    };
+    loaded.sort_by_key(|page_ix| std::cmp::Reverse(distance(*page_ix)));
+    loaded.truncate(loaded.len() - max_pages);
+    loaded
+}
+
+/// Return the indices of the pages of the `page_size` that cover the `range` of the rows.
+fn page_range(range: Range<usize>, page_size: usize, rows_count: usize) -> Range<usize> {
+    let end = range.end.min(rows_count);
+    if range.start >= end {
+        return 0..0;
+    }
+
+    range.start / page_size..(end - 1) / page_size + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{evicted_pages, page_range, retry_delay};
+
+    #[test]
+    fn test_page_range() {
+        assert_eq!(page_range(0..10, 100, 1000), 0..1);
+        assert_eq!(page_range(95..105, 100, 1000), 0..2);
+        assert_eq!(page_range(100..200, 100, 1000), 1..2);
+        assert_eq!(page_range(950..1100, 100, 1000), 9..10);
+        assert_eq!(page_range(10..10, 100, 1000), 0..0);
+        assert_eq!(page_range(0..10, 100, 0), 0..0);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_secs(1));
+        assert_eq!(retry_delay(1), Duration::from_secs(2));
+        assert_eq!(retry_delay(3), Duration::from_secs(8));
+        assert_eq!(retry_delay(10), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_evicted_pages() {
+        assert_eq!(evicted_pages(vec![0, 1, 2], 1..2, 3), Vec::<usize>::new());
+        assert_eq!(evicted_pages(vec![0, 1, 2, 3, 9], 2..3, 3), vec![9, 0]);
+        assert_eq!(evicted_pages(vec![5, 6, 7, 8], 0..1, 2), vec![8, 7]);
+    }
+}
@@ -0,0 +1,376 @@
+//! The list of the running background tasks, rendered by the [`TasksPanel`] and the [`TaskStatus`].
+//!
+//! ```ignore
+//! let task = TaskList::add(TaskItem::new("Indexing").on_cancel(|cx| { ... }), cx);
+//! task.set_progress(50., cx);
+//! task.finish(cx);
+//! ```
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, AppContext, Context as _, EventEmitter,
+    FocusHandle, FocusableView, Global, InteractiveElement as _, IntoElement, Model, ModelContext,
+    ParentElement, Render, SharedString, Styled, ViewContext, WeakModel, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button,
+    dock::{Panel, PanelEvent},
+    h_flex,
+    indicator::Indicator,
+    progress::Progress,
+    theme::ActiveTheme as _,
+    v_flex, IconName, Sizable as _,
+};
+
+pub fn init(cx: &mut AppContext) {
+    let list = cx.new_model(|_| TaskList::default());
+    cx.set_global(GlobalTaskList(list));
+}
+
+struct GlobalTaskList(Model<TaskList>);
+
+impl Global for GlobalTaskList {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskId(usize);
+
+/// A background task registered to the [`TaskList`].
+pub struct TaskItem {
+    id: TaskId,
+    title: SharedString,
+    message: Option<SharedString>,
+    /// The progress in 0..100, `None` means the progress is indeterminate.
+    progress: Option<f32>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl TaskItem {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            id: TaskId(0),
+            title: title.into(),
+            message: None,
+            progress: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Set the handler to cancel the task, the cancel button is only shown when it is set.
+    pub fn on_cancel(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// The handle of a registered task, to update its progress and remove it when it is finished.
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: TaskId,
+    list: WeakModel<TaskList>,
+}
+
+impl TaskHandle {
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Set the progress in 0..100.
+    pub fn set_progress(&self, progress: f32, cx: &mut AppContext) {
+        self.update_task(cx, |task| task.progress = Some(progress.max(0.).min(100.)));
+    }
+
+    pub fn set_message(&self, message: impl Into<SharedString>, cx: &mut AppContext) {
+        let message = message.into();
+        self.update_task(cx, |task| task.message = Some(message));
+    }
+
+    /// Remove the task from the list.
+    pub fn finish(&self, cx: &mut AppContext) {
+        _ = self.list.update(cx, |list, cx| list.remove(self.id, cx));
+    }
+
+    fn update_task(&self, cx: &mut AppContext, f: impl FnOnce(&mut TaskItem)) {
+        _ = self.list.update(cx, |list, cx| {
+            if let Some(task) = list.tasks.iter_mut().find(|task| task.id == self.id) {
+                f(task);
+                cx.notify();
+            }
+        });
+    }
+}
+
+#[derive(Default)]
+pub struct TaskList {
+    next_id: usize,
+    tasks: Vec<TaskItem>,
+}
+
+impl TaskList {
+    pub fn global(cx: &AppContext) -> Model<Self> {
+        cx.global::<GlobalTaskList>().0.clone()
+    }
+
+    /// Register a task to the global task list.
+    pub fn add(mut task: TaskItem, cx: &mut AppContext) -> TaskHandle {
+        let list = Self::global(cx);
+        let id = list.update(cx, |list, cx| {
+            list.next_id += 1;
+            task.id = TaskId(list.next_id);
+            list.tasks.push(task);
+            cx.notify();
+            TaskId(list.next_id)
+        });
+
+        TaskHandle {
+            id,
+            list: list.downgrade(),
+        }
+    }
+
+    pub fn tasks(&self) -> &[TaskItem] {
+        &self.tasks
+    }
+
+    fn remove(&mut self, id: TaskId, cx: &mut ModelContext<Self>) {
+        self.tasks.retain(|task| task.id != id);
+        cx.notify();
+    }
+
+    /// Remove the task and call its cancel handler.
+    fn cancel(id: TaskId, cx: &mut WindowContext) {
+        let list = Self::global(cx);
+        let on_cancel = list.update(cx, |list, cx| {
+            let on_cancel = list
+                .tasks
+                .iter()
+                .find(|task| task.id == id)
+                .and_then(|task| task.on_cancel.clone());
+            list.remove(id, cx);
+            on_cancel
+        });
+
+        if let Some(on_cancel) = on_cancel {
+            on_cancel(cx);
+        }
+    }
+
+    /// Cancel all the cancelable tasks.
+    fn cancel_all(cx: &mut WindowContext) {
+        let ids = Self::global(cx)
+            .read(cx)
+            .tasks
+            .iter()
+            .filter(|task| task.on_cancel.is_some())
+            .map(|task| task.id)
+            .collect::<Vec<_>>();
+        for id in ids {
+            Self::cancel(id, cx);
+        }
+    }
+}
+
+/// Return the average progress of the tasks, the indeterminate tasks are ignored.
+fn overall_progress(tasks: &[TaskItem]) -> Option<f32> {
+    let values = tasks
+        .iter()
+        .filter_map(|task| task.progress)
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values.iter().sum::<f32>() / values.len() as f32)
+}
+
+/// A dockable panel to show all the running tasks.
+pub struct TasksPanel {
+    focus_handle: FocusHandle,
+    list: Model<TaskList>,
+}
+
+impl TasksPanel {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let list = TaskList::global(cx);
+        cx.observe(&list, |_, _, cx| cx.notify()).detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            list,
+        }
+    }
+}
+
+impl Panel for TasksPanel {
+    fn title(&self, _: &WindowContext) -> SharedString {
+        t!("TaskList.Tasks").into()
+    }
+
+    fn panel_key(&self, _: &WindowContext) -> Option<SharedString> {
+        Some("TasksPanel".into())
+    }
+
+    fn icon(&self, _: &WindowContext) -> Option<IconName> {
+        Some(IconName::LoaderCircle)
+    }
+
+    fn busy(&self, cx: &WindowContext) -> bool {
+        !self.list.read(cx).tasks().is_empty()
+    }
+
+    fn toolbar_buttons(&mut self, cx: &mut ViewContext<Self>) -> Vec<AnyElement> {
+        let cancelable = self
+            .list
+            .read(cx)
+            .tasks()
+            .iter()
+            .any(|task| task.on_cancel.is_some());
+        if !cancelable {
+            return vec![];
+        }
+
+        vec![Button::new("cancel-all", cx)
+            .ghost()
+            .xsmall()
+            .icon(IconName::CircleX)
+            .tooltip(t!("TaskList.Cancel All"))
+            .on_click(|_, cx| TaskList::cancel_all(cx))
+            .into_any_element()]
+    }
+}
+
+impl EventEmitter<PanelEvent> for TasksPanel {}
+
+impl FocusableView for TasksPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TasksPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Collect the tasks first, the list is borrowed from the `cx` that is needed to build the buttons.
+        let tasks = self
+            .list
+            .read(cx)
+            .tasks()
+            .iter()
+            .map(|task| {
+                (
+                    task.id,
+                    task.title.clone(),
+                    task.message.clone(),
+                    task.progress,
+                    task.on_cancel.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .id("tasks-panel")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .p_3()
+            .gap_3()
+            .overflow_y_scroll()
+            .when(tasks.is_empty(), |this| {
+                this.items_center().justify_center().child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("TaskList.No running tasks").to_string()),
+                )
+            })
+            .children(
+                tasks
+                    .into_iter()
+                    .map(|(id, title, message, progress, cancelable)| {
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_2()
+                                    .justify_between()
+                                    .child(div().text_sm().child(title))
+                                    .when(cancelable, |this| {
+                                        this.child(
+                                            Button::new(("cancel", id.0), cx)
+                                                .ghost()
+                                                .xsmall()
+                                                .icon(IconName::Close)
+                                                .on_click(move |_, cx| TaskList::cancel(id, cx)),
+                                        )
+                                    }),
+                            )
+                            .map(|this| match progress {
+                                Some(value) => this.child(Progress::new().value(value)),
+                                None => this.child(Indicator::new().xsmall()),
+                            })
+                            .when_some(message, |this, message| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(message),
+                                )
+                            })
+                    }),
+            )
+    }
+}
+
+/// The summary of the running tasks to show in the status bar, it renders nothing if no task is running.
+pub struct TaskStatus {
+    list: Model<TaskList>,
+}
+
+impl TaskStatus {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let list = TaskList::global(cx);
+        cx.observe(&list, |_, _, cx| cx.notify()).detach();
+
+        Self { list }
+    }
+}
+
+impl Render for TaskStatus {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let tasks = self.list.read(cx).tasks();
+
+        h_flex()
+            .gap_2()
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .when(!tasks.is_empty(), |this| {
+                let label = match tasks {
+                    [task] => task.title.to_string(),
+                    _ => t!("TaskList.Running", count = tasks.len()).to_string(),
+                };
+
+                this.child(Indicator::new().xsmall())
+                    .child(label)
+                    .when_some(overall_progress(tasks), |this, value| {
+                        this.child(div().w_20().child(Progress::new().value(value)))
+                    })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overall_progress, TaskItem};
+
+    #[test]
+    fn test_overall_progress() {
+        let mut task1 = TaskItem::new("Task 1");
+        let mut task2 = TaskItem::new("Task 2");
+        let task3 = TaskItem::new("Task 3");
+        assert_eq!(overall_progress(&[]), None);
+        assert_eq!(overall_progress(&[TaskItem::new("Task")]), None);
+
+        task1.progress = Some(20.);
+        task2.progress = Some(60.);
+        assert_eq!(overall_progress(&[task1, task2, task3]), Some(40.));
+    }
+}
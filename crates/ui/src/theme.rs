@@ -1,9 +1,13 @@
 use std::ops::Deref;
 
+use anyhow::{anyhow, Result};
 use gpui::{
-    hsla, point, AppContext, BoxShadow, Global, Hsla, ModelContext, Pixels, SharedString,
+    hsla, point, AppContext, BoxShadow, Global, Hsla, ModelContext, Pixels, Rgba, SharedString,
     ViewContext, WindowAppearance, WindowContext,
 };
+use serde_json::json;
+
+use crate::colors::ColorExt as _;
 
 pub trait ActiveTheme {
     fn theme(&self) -> &Theme;
@@ -281,12 +285,24 @@ pub struct Theme {
     pub border: Hsla,
     pub input: Hsla,
     pub ring: Hsla,
+    /// The ring color to show on the element focused by keyboard.
+    pub focus_ring: Hsla,
     pub selection: Hsla,
     pub scrollbar: Hsla,
     pub scrollbar_thumb: Hsla,
     pub panel: Hsla,
     pub drag_border: Hsla,
     pub drop_target: Hsla,
+    /// The color of the resize handle between the resizable panels.
+    pub resize_handle: Hsla,
+    pub resize_handle_hover: Hsla,
+    /// The thickness of the resize handle, in pixels.
+    pub resize_handle_size: f32,
+    /// Whether to show the grip dots in the middle of the resize handle.
+    pub resize_handle_grip: bool,
+    /// Use the larger hit targets of the resize handles and the tabs for the touch and pen input,
+    /// default is true when a touch screen is detected.
+    pub touch_mode: bool,
     pub radius: f32,
     pub tab_bar: Hsla,
     pub tab: Hsla,
@@ -320,6 +336,150 @@ impl Theme {
     }
 }
 
+macro_rules! color_tokens {
+    ($($name:ident),+ $(,)?) => {
+        impl Theme {
+            /// Return the names and the values of all the color tokens.
+            pub fn colors(&self) -> Vec<(&'static str, Hsla)> {
+                vec![$((stringify!($name), self.$name)),+]
+            }
+
+            /// Set the color token by the name, return false if the name is unknown.
+            pub fn set_color(&mut self, name: &str, color: Hsla) -> bool {
+                match name {
+                    $(stringify!($name) => self.$name = color,)+
+                    _ => return false,
+                }
+                true
+            }
+        }
+    };
+}
+
+color_tokens!(
+    title_bar_background,
+    background,
+    foreground,
+    card,
+    card_foreground,
+    popover,
+    popover_foreground,
+    primary,
+    primary_hover,
+    primary_active,
+    primary_foreground,
+    secondary,
+    secondary_hover,
+    secondary_active,
+    secondary_foreground,
+    destructive,
+    destructive_hover,
+    destructive_active,
+    destructive_foreground,
+    muted,
+    muted_foreground,
+    accent,
+    accent_foreground,
+    border,
+    input,
+    ring,
+    focus_ring,
+    selection,
+    scrollbar,
+    scrollbar_thumb,
+    panel,
+    drag_border,
+    drop_target,
+    resize_handle,
+    resize_handle_hover,
+    tab_bar,
+    tab,
+    tab_active,
+    tab_foreground,
+    tab_active_foreground,
+    progress_bar,
+    slider_bar,
+    slider_thumb,
+    list,
+    list_even,
+    list_head,
+    list_active,
+    list_hover,
+    table,
+    table_even,
+    table_head,
+    table_active,
+    table_hover,
+    link,
+    link_hover,
+    link_active,
+    skeleton,
+);
+
+impl Theme {
+    /// Export the theme as JSON, the colors are in hex, load it by [`Theme::load_json`].
+    ///
+    /// ```json
+    /// { "mode": "dark", "colors": { "background": "#0F0F0F", ... } }
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        let colors = self
+            .colors()
+            .into_iter()
+            .map(|(name, color)| (name.to_string(), json!(color.to_hex_string())))
+            .collect::<serde_json::Map<_, _>>();
+
+        json!({
+            "mode": if self.mode.is_dark() { "dark" } else { "light" },
+            "colors": colors,
+        })
+    }
+
+    /// Apply the colors in the JSON that exported by [`Theme::to_json`] to this theme,
+    /// the missing colors are kept.
+    pub fn apply_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value.get("mode").and_then(|mode| mode.as_str()) {
+            Some("light") => self.mode = ThemeMode::Light,
+            Some("dark") => self.mode = ThemeMode::Dark,
+            _ => {}
+        }
+
+        let Some(colors) = value.get("colors").and_then(|colors| colors.as_object()) else {
+            return Ok(());
+        };
+
+        for (name, color) in colors {
+            let hex = color
+                .as_str()
+                .ok_or_else(|| anyhow!("the color `{}` is not a string", name))?;
+            let color: Hsla = Rgba::try_from(hex)
+                .map_err(|_| anyhow!("invalid color `{}` of `{}`", hex, name))?
+                .into();
+            if !self.set_color(name, color) {
+                return Err(anyhow!("unknown color token `{}`", name));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load the theme JSON that exported by [`Theme::to_json`] over the default theme of its mode.
+    pub fn load_json(json: &str, cx: &mut AppContext) -> Result<()> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let colors = match value.get("mode").and_then(|mode| mode.as_str()) {
+            Some("light") => Colors::light(),
+            _ => Colors::dark(),
+        };
+
+        let mut theme = Theme::from(colors);
+        theme.apply_json(&value)?;
+
+        cx.set_global(theme);
+        cx.refresh();
+        Ok(())
+    }
+}
+
 impl From<Colors> for Theme {
     fn from(colors: Colors) -> Self {
         Theme {
@@ -360,12 +520,18 @@ impl From<Colors> for Theme {
             border: colors.border,
             input: colors.input,
             ring: colors.ring,
+            focus_ring: crate::blue_500(),
             scrollbar: colors.scrollbar,
             scrollbar_thumb: colors.scrollbar_thumb,
             panel: colors.panel,
             selection: colors.selection,
             drag_border: crate::blue_500(),
             drop_target: hsl(220.0, 65., 44.0).opacity(0.15),
+            resize_handle: colors.border,
+            resize_handle_hover: crate::blue_500(),
+            resize_handle_size: 1.0,
+            resize_handle_grip: false,
+            touch_mode: is_touch_device(),
             tab_bar: colors.tab_bar,
             tab: gpui::transparent_black(),
             tab_active: colors.background,
@@ -392,6 +558,25 @@ impl From<Colors> for Theme {
     }
 }
 
+/// Return true if there is a touch screen, the touch and pen input are delivered as the mouse events.
+#[cfg(target_os = "windows")]
+fn is_touch_device() -> bool {
+    /// The max number of the contacts of the touch digitizers, 0 if there is no touch screen.
+    const SM_MAXIMUMTOUCHES: i32 = 95;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetSystemMetrics(index: i32) -> i32;
+    }
+
+    unsafe { GetSystemMetrics(SM_MAXIMUMTOUCHES) > 0 }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_touch_device() -> bool {
+    false
+}
+
 #[derive(Debug, PartialEq, PartialOrd, Eq)]
 pub enum ThemeMode {
     Light,
@@ -439,3 +624,25 @@ impl Theme {
         cx.refresh();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Colors, Theme, ThemeMode};
+
+    #[test]
+    fn test_theme_json() {
+        let mut theme = Theme::from(Colors::light());
+        theme.mode = ThemeMode::Light;
+        let json = theme.to_json();
+        assert_eq!(json["mode"], "light");
+        assert_eq!(json["colors"]["background"], "#FFFFFF");
+
+        let mut loaded = Theme::from(Colors::dark());
+        loaded.apply_json(&json).unwrap();
+        assert_eq!(loaded.mode, ThemeMode::Light);
+        assert_eq!(loaded.background.l, 1.);
+
+        let invalid = serde_json::json!({ "colors": { "unknown": "#FFFFFF" } });
+        assert!(loaded.apply_json(&invalid).is_err());
+    }
+}
@@ -1,14 +1,15 @@
 use gpui::{
-    anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
-    FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length, MouseButton,
-    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _, View,
-    ViewContext, VisualContext as _,
+    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, AppContext, Bounds,
+    ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, KeyBinding,
+    Length, ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _,
+    Styled as _, View, ViewContext, VisualContext as _,
 };
 use rust_i18n::t;
 
 use crate::{
-    dropdown::Escape, h_flex, input::ClearButton, theme::ActiveTheme as _, Icon, IconName, Sizable,
-    Size, StyleSized as _, StyledExt as _,
+    dropdown::Escape, h_flex, input::ClearButton, overlay::OverlayExt as _, popover::PopupWidth,
+    theme::ActiveTheme as _, tooltip::Tooltip, Disableable, Icon, IconName, Sizable, Size,
+    StyleSized as _, StyledExt as _,
 };
 
 use super::calendar::{Calendar, CalendarEvent, Date};
@@ -32,9 +33,15 @@ pub struct DatePicker {
     open: bool,
     size: Size,
     width: Length,
+    /// The width of the calendar popup, default is the width of the months.
+    popup_width: Option<PopupWidth>,
+    /// The bounds of the input, to match the width of the popup.
+    bounds: Bounds<Pixels>,
     date_format: SharedString,
     calendar: View<Calendar>,
     number_of_months: usize,
+    disabled: bool,
+    disabled_reason: Option<SharedString>,
 }
 
 impl DatePicker {
@@ -56,10 +63,14 @@ impl DatePicker {
             open: false,
             size: Size::default(),
             width: Length::Auto,
+            popup_width: None,
+            bounds: Bounds::default(),
             date_format: "%Y/%m/%d".into(),
             cleanable: false,
             number_of_months: 1,
             placeholder: None,
+            disabled: false,
+            disabled_reason: None,
         }
     }
 
@@ -87,12 +98,24 @@ impl DatePicker {
         self
     }
 
+    /// Set the width of the calendar popup, default is the width to fit the months.
+    pub fn popup_width(mut self, width: impl Into<PopupWidth>) -> Self {
+        self.popup_width = Some(width.into());
+        self
+    }
+
     /// Set the number of months calendar view to display, default is 1.
     pub fn number_of_months(mut self, number_of_months: usize) -> Self {
         self.number_of_months = number_of_months;
         self
     }
 
+    /// Set the reason to show as tooltip when the date picker is disabled.
+    pub fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+
     /// Get the date of the date picker.
     pub fn date(&self) -> Date {
         self.date
@@ -119,10 +142,18 @@ impl DatePicker {
     }
 
     fn clean(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
         self.update_date(Date::Single(None), cx);
     }
 
     fn toggle_calendar(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
         self.open = !self.open;
         cx.notify();
     }
@@ -135,6 +166,12 @@ impl Sizable for DatePicker {
         self
     }
 }
+impl Disableable for DatePicker {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
 impl FocusableView for DatePicker {
     fn focus_handle(&self, _: &AppContext) -> FocusHandle {
         self.focus_handle.clone()
@@ -144,7 +181,7 @@ impl FocusableView for DatePicker {
 impl Render for DatePicker {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
         let is_focused = self.focus_handle.is_focused(cx);
-        let show_clean = self.cleanable && self.date.is_some();
+        let show_clean = self.cleanable && self.date.is_some() && !self.disabled;
         let placeholder = self
             .placeholder
             .clone()
@@ -160,6 +197,10 @@ impl Render for DatePicker {
 
         let popover_width =
             285.0 * self.number_of_months as f32 + (self.number_of_months - 1) as f32 * 16.0;
+        let popup_width = self
+            .popup_width
+            .unwrap_or(PopupWidth::Fixed(px(popover_width).into()));
+        let view = cx.view().clone();
 
         div()
             .id(self.id.clone())
@@ -185,12 +226,24 @@ impl Render for DatePicker {
                     .border_color(cx.theme().input)
                     .rounded(px(cx.theme().radius))
                     .shadow_sm()
-                    .cursor_pointer()
+                    .map(|this| {
+                        if self.disabled {
+                            this.cursor_not_allowed()
+                                .bg(cx.theme().muted)
+                                .text_color(cx.theme().muted_foreground)
+                        } else {
+                            this.cursor_pointer()
+                        }
+                    })
+                    .when_some(
+                        self.disabled_reason.clone().filter(|_| self.disabled),
+                        |this, reason| this.tooltip(move |cx| Tooltip::new(reason.clone(), cx)),
+                    )
                     .overflow_hidden()
                     .input_text_size(self.size)
-                    .when(is_focused, |this| this.outline(cx))
+                    .when(is_focused && !self.disabled, |this| this.outline(cx))
                     .input_size(self.size)
-                    .when(!self.open, |this| {
+                    .when(!self.open && !self.disabled, |this| {
                         this.on_click(cx.listener(Self::toggle_calendar))
                     })
                     .child(
@@ -209,6 +262,14 @@ impl Render for DatePicker {
                                         .text_color(cx.theme().muted_foreground),
                                 )
                             }),
+                    )
+                    .child(
+                        canvas(
+                            move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
                     ),
             )
             .when(self.open, |this| {
@@ -223,16 +284,13 @@ impl Render for DatePicker {
                                 .overflow_hidden()
                                 .rounded_lg()
                                 .p_3()
-                                .w(px(popover_width))
+                                .map(|this| popup_width.apply(this, self.bounds.size.width))
                                 .border_1()
                                 .border_color(cx.theme().border)
                                 .shadow_lg()
                                 .rounded_lg()
                                 .bg(cx.theme().background)
-                                .on_mouse_up_out(
-                                    MouseButton::Left,
-                                    cx.listener(|view, _, cx| view.escape(&Escape, cx)),
-                                )
+                                .on_dismiss(cx.listener(|view, _, cx| view.escape(&Escape, cx)))
                                 .child(self.calendar.clone()),
                         ),
                     )
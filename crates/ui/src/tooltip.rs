@@ -1,17 +1,42 @@
 use gpui::{
-    div, px, AnyView, IntoElement, ParentElement, Render, SharedString, Styled, ViewContext,
-    VisualContext, WindowContext,
+    div, prelude::FluentBuilder as _, px, Action, AnyView, IntoElement, KeyBinding, ParentElement,
+    Render, SharedString, Styled, ViewContext, VisualContext, WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{h_flex, kbd::Kbd, theme::ActiveTheme, v_flex};
 
 pub struct Tooltip {
     text: SharedString,
+    meta: Option<SharedString>,
+    key_binding: Option<KeyBinding>,
 }
 
 impl Tooltip {
     pub fn new(text: impl Into<SharedString>, cx: &mut WindowContext) -> AnyView {
-        cx.new_view(|_| Self { text: text.into() }).into()
+        cx.new_view(|_| Self {
+            text: text.into(),
+            meta: None,
+            key_binding: None,
+        })
+        .into()
+    }
+
+    /// Create a rich tooltip with a `meta` description below the text,
+    /// and the keybinding of the `action` if it is bound.
+    pub fn with_meta(
+        text: impl Into<SharedString>,
+        meta: Option<SharedString>,
+        action: Option<&dyn Action>,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        let key_binding = action.and_then(|action| cx.bindings_for_action(action).pop());
+
+        cx.new_view(|_| Self {
+            text: text.into(),
+            meta,
+            key_binding,
+        })
+        .into()
     }
 }
 
@@ -31,7 +56,33 @@ impl Render for Tooltip {
                 .py_0p5()
                 .px_2()
                 .text_sm()
-                .child(self.text.clone()),
+                .map(|this| {
+                    if self.meta.is_none() && self.key_binding.is_none() {
+                        return this.child(self.text.clone());
+                    }
+
+                    this.py_1().max_w(px(320.)).child(
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .gap_3()
+                                    .justify_between()
+                                    .child(self.text.clone())
+                                    .when_some(self.key_binding.as_ref(), |this, binding| {
+                                        this.child(Kbd::binding(binding))
+                                    }),
+                            )
+                            .when_some(self.meta.clone(), |this, meta| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(meta),
+                                )
+                            }),
+                    )
+                }),
         )
     }
 }
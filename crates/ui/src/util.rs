@@ -0,0 +1,150 @@
+//! The helpers to coalesce the frequent callbacks of a view, e.g.: the search of the input changes,
+//! the persistence of the resizing.
+//!
+//! ```ignore
+//! struct MyView {
+//!     search: Debounce<Self, String>,
+//! }
+//!
+//! let search = debounce(Duration::from_millis(150), |this: &mut MyView, query, cx| {
+//!     this.perform_search(query, cx)
+//! });
+//!
+//! // In the input change event:
+//! self.search.call(text, cx);
+//! ```
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{Task, ViewContext};
+use smol::Timer;
+
+type Callback<V, T> = Rc<dyn Fn(&mut V, T, &mut ViewContext<V>)>;
+
+/// Create a [`Debounce`] that calls the `callback` after the calls are stopped for the `delay`.
+pub fn debounce<V: 'static, T: 'static>(
+    delay: Duration,
+    callback: impl Fn(&mut V, T, &mut ViewContext<V>) + 'static,
+) -> Debounce<V, T> {
+    Debounce {
+        delay,
+        callback: Rc::new(callback),
+        task: None,
+    }
+}
+
+/// Create a [`Throttle`] that calls the `callback` at most once per the `interval`.
+pub fn throttle<V: 'static, T: 'static>(
+    interval: Duration,
+    callback: impl Fn(&mut V, T, &mut ViewContext<V>) + 'static,
+) -> Throttle<V, T> {
+    Throttle {
+        interval,
+        callback: Rc::new(callback),
+        last_call: None,
+        pending: Rc::new(RefCell::new(None)),
+        task: None,
+    }
+}
+
+/// Only the last call is performed, after the calls are stopped for the delay.
+pub struct Debounce<V, T> {
+    delay: Duration,
+    callback: Callback<V, T>,
+    task: Option<Task<()>>,
+}
+
+impl<V: 'static, T: 'static> Debounce<V, T> {
+    /// Call the callback with the `value` after the delay, the pending call is replaced.
+    pub fn call(&mut self, value: T, cx: &mut ViewContext<V>) {
+        let delay = self.delay;
+        let callback = self.callback.clone();
+
+        // Replace the task to drop (cancel) the pending call.
+        self.task = Some(cx.spawn(|this, mut cx| async move {
+            Timer::after(delay).await;
+            _ = this.update(&mut cx, |this, cx| callback(this, value, cx));
+        }));
+    }
+
+    /// Cancel the pending call.
+    pub fn cancel(&mut self) {
+        self.task = None;
+    }
+}
+
+/// The first call is performed on the next tick, then the calls in the interval
+/// are coalesced to a trailing call with the last value.
+pub struct Throttle<V, T> {
+    interval: Duration,
+    callback: Callback<V, T>,
+    /// The time of the last performed (or scheduled) call.
+    last_call: Option<Instant>,
+    /// The value of the scheduled call.
+    pending: Rc<RefCell<Option<T>>>,
+    task: Option<Task<()>>,
+}
+
+impl<V: 'static, T: 'static> Throttle<V, T> {
+    pub fn call(&mut self, value: T, cx: &mut ViewContext<V>) {
+        // A call is scheduled, only replace its value.
+        if self.pending.borrow_mut().replace(value).is_some() {
+            return;
+        }
+
+        let now = Instant::now();
+        let wait = throttle_wait(self.last_call, self.interval, now);
+        self.last_call = Some(now + wait);
+
+        let pending = self.pending.clone();
+        let callback = self.callback.clone();
+        self.task = Some(cx.spawn(|this, mut cx| async move {
+            if !wait.is_zero() {
+                Timer::after(wait).await;
+            }
+
+            let Some(value) = pending.borrow_mut().take() else {
+                return;
+            };
+            _ = this.update(&mut cx, |this, cx| callback(this, value, cx));
+        }));
+    }
+
+    /// Cancel the scheduled call.
+    pub fn cancel(&mut self) {
+        self.pending.borrow_mut().take();
+        self.task = None;
+    }
+}
+
+/// Return the duration to wait before the next call, to keep the `interval` from the `last_call`.
+fn throttle_wait(last_call: Option<Instant>, interval: Duration, now: Instant) -> Duration {
+    last_call.map_or(Duration::ZERO, |last| {
+        (last + interval).saturating_duration_since(now)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::throttle_wait;
+
+    #[test]
+    fn test_throttle_wait() {
+        let now = Instant::now();
+        let interval = Duration::from_millis(100);
+        assert_eq!(throttle_wait(None, interval, now), Duration::ZERO);
+        assert_eq!(
+            throttle_wait(Some(now - Duration::from_millis(30)), interval, now),
+            Duration::from_millis(70)
+        );
+        assert_eq!(
+            throttle_wait(Some(now - Duration::from_millis(300)), interval, now),
+            Duration::ZERO
+        );
+    }
+}
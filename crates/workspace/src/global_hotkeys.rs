@@ -0,0 +1,236 @@
+//! The system-wide (OS-level) shortcuts, they work even the app is not focused.
+//!
+//! ```ignore
+//! GlobalHotkeys::init(cx)?;
+//! // Keep the registration alive, the hotkey will be unregistered when it dropped.
+//! let registration = GlobalHotkeys::register("cmd-shift-space", |cx| cx.activate(true), cx)?;
+//! ```
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+    str::FromStr as _,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+use gpui::{AppContext, Global, SharedString};
+
+/// The interval to poll the hotkey events.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+struct Registration {
+    hotkey: HotKey,
+    keystroke: SharedString,
+    handler: Rc<dyn Fn(&mut AppContext)>,
+}
+
+struct Registry {
+    manager: GlobalHotKeyManager,
+    hotkeys: HashMap<u32, Registration>,
+}
+
+pub struct GlobalHotkeys(Rc<RefCell<Registry>>);
+
+impl Global for GlobalHotkeys {}
+
+/// The handle of a registered hotkey, the hotkey is unregistered when it dropped.
+#[must_use]
+pub struct HotkeyRegistration {
+    id: u32,
+    registry: Weak<RefCell<Registry>>,
+}
+
+impl Drop for HotkeyRegistration {
+    fn drop(&mut self) {
+        let Some(registry) = self.registry.upgrade() else {
+            return;
+        };
+
+        let mut registry = registry.borrow_mut();
+        if let Some(registration) = registry.hotkeys.remove(&self.id) {
+            if let Err(err) = registry.manager.unregister(registration.hotkey) {
+                log::error!(
+                    "failed to unregister global hotkey {}: {}",
+                    registration.keystroke,
+                    err
+                );
+            }
+        }
+    }
+}
+
+impl GlobalHotkeys {
+    /// Initialize the global hotkeys manager, it must be called on the main thread.
+    pub fn init(cx: &mut AppContext) -> Result<()> {
+        let manager = GlobalHotKeyManager::new().context("failed to create hotkey manager")?;
+        let registry = Rc::new(RefCell::new(Registry {
+            manager,
+            hotkeys: HashMap::new(),
+        }));
+        cx.set_global(GlobalHotkeys(registry));
+
+        cx.spawn(|cx| async move {
+            loop {
+                cx.background_executor().timer(POLL_INTERVAL).await;
+                if cx.update(Self::poll_events).is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+        Ok(())
+    }
+
+    /// Register a system-wide shortcut, the `keystroke` is in the same format as the KeyBinding,
+    /// e.g.: `cmd-shift-space`, `ctrl-alt-p`.
+    ///
+    /// Returns error if the keystroke is invalid, or it is already registered by this app or others.
+    pub fn register(
+        keystroke: &str,
+        handler: impl Fn(&mut AppContext) + 'static,
+        cx: &mut AppContext,
+    ) -> Result<HotkeyRegistration> {
+        let registry = cx
+            .try_global::<GlobalHotkeys>()
+            .map(|this| this.0.clone())
+            .ok_or_else(|| anyhow!("GlobalHotkeys is not initialized"))?;
+
+        let hotkey = HotKey::from_str(&to_hotkey_string(keystroke)?)
+            .map_err(|err| anyhow!("invalid hotkey `{}`: {}", keystroke, err))?;
+
+        let mut this = registry.borrow_mut();
+        if let Some(registration) = this.hotkeys.get(&hotkey.id()) {
+            return Err(anyhow!(
+                "hotkey `{}` is conflicted with the registered `{}`",
+                keystroke,
+                registration.keystroke
+            ));
+        }
+
+        // The OS will reject the hotkey that is registered by other apps.
+        this.manager
+            .register(hotkey)
+            .map_err(|err| anyhow!("failed to register hotkey `{}`: {}", keystroke, err))?;
+        this.hotkeys.insert(
+            hotkey.id(),
+            Registration {
+                hotkey,
+                keystroke: SharedString::from(keystroke.to_string()),
+                handler: Rc::new(handler),
+            },
+        );
+
+        Ok(HotkeyRegistration {
+            id: hotkey.id(),
+            registry: Rc::downgrade(&registry),
+        })
+    }
+
+    /// Return true if the keystroke is already registered by this app.
+    pub fn is_registered(keystroke: &str, cx: &AppContext) -> bool {
+        let Some(this) = cx.try_global::<GlobalHotkeys>() else {
+            return false;
+        };
+
+        to_hotkey_string(keystroke)
+            .ok()
+            .and_then(|s| HotKey::from_str(&s).ok())
+            .map_or(false, |hotkey| {
+                this.0.borrow().hotkeys.contains_key(&hotkey.id())
+            })
+    }
+
+    fn poll_events(cx: &mut AppContext) {
+        let Some(registry) = cx.try_global::<GlobalHotkeys>().map(|this| this.0.clone()) else {
+            return;
+        };
+
+        while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+            if event.state != HotKeyState::Pressed {
+                continue;
+            }
+
+            let handler = registry
+                .borrow()
+                .hotkeys
+                .get(&event.id)
+                .map(|registration| registration.handler.clone());
+            if let Some(handler) = handler {
+                handler(cx);
+            }
+        }
+    }
+}
+
+/// Convert the KeyBinding style keystroke (e.g.: `cmd-shift-k`) to the hotkey format (`super+shift+KeyK`).
+fn to_hotkey_string(keystroke: &str) -> Result<String> {
+    let mut parts = vec![];
+    let mut components = keystroke.split('-').peekable();
+    while let Some(component) = components.next() {
+        let is_key = components.peek().is_none();
+        let part = if is_key {
+            match component {
+                "" => return Err(anyhow!("missing key in `{}`", keystroke)),
+                key if key.len() == 1 && key.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    format!("Key{}", key.to_ascii_uppercase())
+                }
+                key if key.len() == 1 && key.chars().all(|c| c.is_ascii_digit()) => {
+                    format!("Digit{}", key)
+                }
+                "space" => "Space".into(),
+                "enter" => "Enter".into(),
+                "escape" => "Escape".into(),
+                "tab" => "Tab".into(),
+                "backspace" => "Backspace".into(),
+                "delete" => "Delete".into(),
+                "up" => "ArrowUp".into(),
+                "down" => "ArrowDown".into(),
+                "left" => "ArrowLeft".into(),
+                "right" => "ArrowRight".into(),
+                key if key.starts_with('f') && key[1..].parse::<u8>().is_ok() => {
+                    key.to_ascii_uppercase()
+                }
+                key => return Err(anyhow!("unsupported key `{}` in `{}`", key, keystroke)),
+            }
+        } else {
+            match component {
+                "cmd" | "super" | "win" => "super".into(),
+                "ctrl" => "control".into(),
+                "alt" => "alt".into(),
+                "shift" => "shift".into(),
+                modifier => {
+                    return Err(anyhow!(
+                        "unsupported modifier `{}` in `{}`",
+                        modifier,
+                        keystroke
+                    ))
+                }
+            }
+        };
+        parts.push(part);
+    }
+
+    Ok(parts.join("+"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_hotkey_string;
+
+    #[test]
+    fn test_to_hotkey_string() {
+        assert_eq!(to_hotkey_string("cmd-shift-k").unwrap(), "super+shift+KeyK");
+        assert_eq!(
+            to_hotkey_string("ctrl-alt-1").unwrap(),
+            "control+alt+Digit1"
+        );
+        assert_eq!(to_hotkey_string("alt-space").unwrap(), "alt+Space");
+        assert_eq!(to_hotkey_string("f12").unwrap(), "F12");
+        assert!(to_hotkey_string("hyper-k").is_err());
+        assert!(to_hotkey_string("cmd-").is_err());
+        assert!(to_hotkey_string("cmd-pageup").is_err());
+    }
+}
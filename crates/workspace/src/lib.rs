@@ -1,4 +1,5 @@
 pub mod dock;
+pub mod global_hotkeys;
 pub mod item;
 pub mod pane;
 pub mod pane_group;